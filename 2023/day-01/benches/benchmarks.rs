@@ -0,0 +1,69 @@
+use aoc2023_day_01::*;
+use aoc_common::isa::Isa;
+
+fn main() {
+    // Runs all benchmarks
+    divan::main();
+}
+
+#[divan::bench]
+fn part1() {
+    part1::process(divan::black_box(include_str!("../input1.txt"))).unwrap();
+}
+
+#[divan::bench]
+fn part2() {
+    part2::process(divan::black_box(include_str!("../input2.txt"))).unwrap();
+}
+
+#[divan::bench]
+fn part2_biscardi() {
+    part2_biscardi::process(divan::black_box(include_str!("../input2.txt"))).unwrap();
+}
+
+#[divan::bench]
+fn part2_aho_corasick() {
+    part2_aho_corasick::process(divan::black_box(include_str!("../input2.txt"))).unwrap();
+}
+
+/// Without building the automaton on every run
+#[divan::bench]
+fn part2_aho_corasick_prebuilt(bencher: divan::Bencher) {
+    let dictionary = part2_aho_corasick::Dictionary::english();
+    let input = include_str!("../input2.txt");
+    bencher.bench(|| {
+        part2_aho_corasick::process_with_dictionary(divan::black_box(input), &dictionary).unwrap()
+    });
+}
+
+#[divan::bench(args = Isa::supported())]
+fn part1_simd(isa: Isa) {
+    simd::calibration_sum(isa, divan::black_box(include_bytes!("../input1.txt")));
+}
+
+#[divan::bench]
+fn part1_stream() {
+    stream::calibration_sum(divan::black_box(&include_bytes!("../input1.txt")[..])).unwrap();
+}
+
+#[cfg(feature = "parallel")]
+#[divan::bench(args = [1, 2, 4, 8])]
+fn part1_parallel(bencher: divan::Bencher, threads: usize) {
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(threads)
+        .build()
+        .unwrap();
+    let input = include_str!("../input1.txt");
+    bencher.bench(|| pool.install(|| part1::process_parallel(divan::black_box(input)).unwrap()));
+}
+
+#[cfg(feature = "parallel")]
+#[divan::bench(args = [1, 2, 4, 8])]
+fn part2_parallel(bencher: divan::Bencher, threads: usize) {
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(threads)
+        .build()
+        .unwrap();
+    let input = include_str!("../input2.txt");
+    bencher.bench(|| pool.install(|| part2::process_parallel(divan::black_box(input)).unwrap()));
+}
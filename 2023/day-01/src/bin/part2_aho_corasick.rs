@@ -0,0 +1,16 @@
+use aoc2023_day_01::part2_aho_corasick::process;
+use miette::Context;
+
+#[cfg(feature = "dhat-heap")]
+#[global_allocator]
+static ALLOC: dhat::Alloc = dhat::Alloc;
+
+fn main() -> miette::Result<()> {
+    #[cfg(feature = "dhat-heap")]
+    let _profiler = dhat::Profiler::new_heap();
+
+    let file = include_str!("../../input1.txt");
+    let result = process(file).context("process part 2 aho-corasick")?;
+    println!("{}", result);
+    Ok(())
+}
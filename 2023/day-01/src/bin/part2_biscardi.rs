@@ -1,4 +1,4 @@
-use day_01::part2_biscardi::process;
+use aoc2023_day_01::part2_biscardi::process;
 use miette::Context;
 
 #[cfg(feature = "dhat-heap")]
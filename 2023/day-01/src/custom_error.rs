@@ -0,0 +1,25 @@
+use miette::Diagnostic;
+use thiserror::Error;
+
+#[derive(Error, Diagnostic, Debug)]
+pub enum AocError {
+    #[error(transparent)]
+    #[diagnostic(code(aoc::io_error))]
+    IoError(#[from] std::io::Error),
+
+    #[error(transparent)]
+    #[diagnostic(transparent)]
+    Partial(#[from] aoc_common::lenient::Partial),
+
+    #[error(transparent)]
+    #[diagnostic(code(aoc::dictionary))]
+    Dictionary(#[from] aho_corasick::BuildError),
+
+    #[error("the dictionary has an empty word")]
+    #[diagnostic(code(aoc::dictionary))]
+    EmptyWord,
+
+    #[error(transparent)]
+    #[diagnostic(transparent)]
+    InvalidInput(#[from] aoc_common::schema::InvalidInput),
+}
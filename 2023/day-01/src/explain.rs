@@ -0,0 +1,76 @@
+//! Intermediates for `aoc run --explain`: the calibration value of every line.
+
+use aoc_common::explain::Explanation;
+
+use crate::custom_error::AocError;
+use crate::{part1, part2};
+
+pub fn part1(input: &str) -> miette::Result<Explanation, AocError> {
+    let mut explanation = Explanation::new();
+    for (number, line) in input.split('\n').enumerate() {
+        let [first, last] = part1::calibration_digits(line);
+        line_record(&mut explanation, number, line, first as usize, last as usize);
+    }
+    Ok(explanation)
+}
+
+pub fn part2(input: &str) -> miette::Result<Explanation, AocError> {
+    let mut explanation = Explanation::new();
+    for (number, line) in input.lines().enumerate() {
+        let [first, last] = part2::calibration_digits(line);
+        line_record(&mut explanation, number, line, first, last);
+    }
+    Ok(explanation)
+}
+
+fn line_record(
+    explanation: &mut Explanation,
+    number: usize,
+    line: &str,
+    first: usize,
+    last: usize,
+) {
+    explanation
+        .record(format!("line {}", number + 1))
+        .field("text", line)
+        .field("first", first)
+        .field("last", last)
+        .field("value", first * 10 + last);
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::explain::Value;
+
+    use super::*;
+
+    #[test]
+    fn test_explain() -> miette::Result<()> {
+        let explanation = part2("two1nine\nxtwone3four\n7pqrstsixteen")?;
+        let values = explanation
+            .records
+            .iter()
+            .map(|record| record.get("value").unwrap().clone())
+            .collect::<Vec<_>>();
+        assert_eq!(vec![Value::Int(29), Value::Int(24), Value::Int(76)], values);
+        assert_eq!("line 2", explanation.records[1].label);
+
+        assert_eq!(Some(&Value::Int(77)), part1("treb7uchet")?.records[0].get("value"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_snapshots() -> miette::Result<()> {
+        insta::assert_snapshot!("part1", part1("1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet")?);
+        let input = "\
+two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen";
+        insta::assert_snapshot!("part2", part2(input)?);
+        Ok(())
+    }
+}
@@ -0,0 +1,10 @@
+pub mod custom_error;
+
+pub mod explain;
+pub mod part1;
+pub mod part2;
+pub mod part2_aho_corasick;
+pub mod part2_biscardi;
+pub mod schema;
+pub mod simd;
+pub mod stream;
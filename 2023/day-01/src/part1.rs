@@ -0,0 +1,100 @@
+use aoc_common::isa::Isa;
+use aoc_common::lenient::Lenient;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use crate::custom_error::AocError;
+use crate::schema;
+use crate::simd;
+use crate::stream;
+
+pub fn process(
+    input: &str
+) -> miette::Result<String, AocError> {
+    schema::check_digits(input)?;
+    // Iterate line by line and accumulate result
+    let result = input.lines().fold(0, |acc, word| {
+        let [first, last] = calibration_digits(word);
+        acc + first * 10 + last
+    });
+    Ok(result.to_string())
+}
+
+/// First and last digit of a line
+pub(crate) fn calibration_digits(word: &str) -> [i32; 2] {
+    let digits = word.matches(char::is_numeric).collect::<Vec<_>>();
+    [
+        digits.first().unwrap().parse::<i32>().unwrap(),
+        digits.last().unwrap().parse::<i32>().unwrap(),
+    ]
+}
+
+/// Scans for digits with the widest SIMD instructions the CPU has, see [`crate::simd`]
+pub fn process_simd(input: &str) -> miette::Result<String, AocError> {
+    schema::check_digits(input)?;
+    Ok(simd::calibration_sum(Isa::selected(), input.as_bytes()).to_string())
+}
+
+/// Reads the input through [`stream::calibration_sum`], as if it came from a file
+pub fn process_stream(input: &str) -> miette::Result<String, AocError> {
+    schema::check_digits(input)?;
+    Ok(stream::calibration_sum(input.as_bytes())?.to_string())
+}
+
+/// Same as [`process`] with the lines spread over rayon's thread pool
+#[cfg(feature = "parallel")]
+pub fn process_parallel(input: &str) -> miette::Result<String, AocError> {
+    schema::check_digits(input)?;
+    let result = input
+        .par_lines()
+        .map(|word| {
+            let [first, last] = calibration_digits(word);
+            first * 10 + last
+        })
+        .sum::<i32>();
+    Ok(result.to_string())
+}
+
+/// Same as [`process`] but skips the lines that don't have part 1's shape, which needs a digit on
+/// each, see [`schema::check_digits`], returning the answer over the other lines in
+/// [`AocError::Partial`]
+pub fn process_lenient(input: &str) -> miette::Result<String, AocError> {
+    let lines = Lenient::lines(input, schema::check_digits);
+    let answer = lines.answer(process)?;
+    Ok(lines.finish(answer)?)
+}
+
+#[cfg(test)]
+mod tests { 
+    use super::*;
+    
+    #[test]
+    fn test_process() -> miette::Result<()> {
+        let input = "1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet";
+        assert_eq!("142", process(input)?);
+        assert_eq!("142", process_simd(input)?);
+        assert_eq!("142", process_stream(input)?);
+        #[cfg(feature = "parallel")]
+        assert_eq!("142", process_parallel(input)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_process_lenient() {
+        let input = "1abc2\npqr3stu8vwx\nno digits\na1b2c3d4e5f\nonetwo\ntreb7uchet";
+        let Err(AocError::Partial(partial)) = process_lenient(input) else {
+            panic!("lines 3 and 5 should be skipped");
+        };
+        assert_eq!("142", partial.answer());
+        assert_eq!(2, partial.skipped().len());
+
+        // Nothing left to solve
+        let Err(AocError::Partial(partial)) = process_lenient("no digits\nabc") else {
+            panic!("every line should be skipped");
+        };
+        assert_eq!("0", partial.answer());
+    }
+}
\ No newline at end of file
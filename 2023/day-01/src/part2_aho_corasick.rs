@@ -0,0 +1,134 @@
+//! Part 2 with every word of the line found in a single pass by an Aho–Corasick automaton, instead
+//! of one search per word. The words are configurable, see [`Dictionary`].
+
+use std::cmp::Reverse;
+
+use aho_corasick::AhoCorasick;
+
+use crate::custom_error::AocError;
+use crate::schema;
+
+pub fn process(input: &str) -> miette::Result<String, AocError> {
+    schema::check(input)?;
+    process_with_dictionary(input, &Dictionary::english())
+}
+
+/// Same as [`process`] with the words of `dictionary` instead of the English digits
+pub fn process_with_dictionary(
+    input: &str,
+    dictionary: &Dictionary,
+) -> miette::Result<String, AocError> {
+    let result = input
+        .lines()
+        .filter_map(|line| dictionary.calibration_value(line))
+        .sum::<u64>();
+    Ok(result.to_string())
+}
+
+/// Words to look for in the calibration lines, each standing for a value
+#[derive(Debug, Clone)]
+pub struct Dictionary {
+    automaton: AhoCorasick,
+    /// Indexed by the automaton's pattern IDs
+    values: Vec<u32>,
+}
+
+impl Dictionary {
+    /// Builds the automaton for `words`, e.g. `[("un", 1), ("deux", 2)]` or `[("ten", 10)]`
+    pub fn new<W: AsRef<[u8]>>(
+        words: impl IntoIterator<Item = (W, u32)>,
+    ) -> Result<Dictionary, AocError> {
+        let (words, values): (Vec<W>, Vec<u32>) = words.into_iter().unzip();
+        // It would match everywhere
+        if words.iter().any(|word| word.as_ref().is_empty()) {
+            return Err(AocError::EmptyWord);
+        }
+        Ok(Dictionary {
+            automaton: AhoCorasick::new(&words)?,
+            values,
+        })
+    }
+
+    /// The puzzle's words: the digits, as digits and spelled out in English
+    pub fn english() -> Dictionary {
+        let spelled = [
+            "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+        ];
+        let digits = (0..10).map(|value| (value.to_string(), value));
+        let spelled = spelled
+            .into_iter()
+            .zip(0..)
+            .map(|(word, value)| (word.to_string(), value));
+        Dictionary::new(digits.chain(spelled)).unwrap()
+    }
+
+    /// Value of the first word of `line` times 10 plus the value of the last, `None` if it has no
+    /// words.
+    ///
+    /// Words may overlap, so "twone" is 2 then 1. Of the words starting at the same byte, e.g.
+    /// "eight" and "eighteen" in a dictionary with both, the longest counts.
+    pub fn calibration_value(&self, line: &str) -> Option<u64> {
+        let mut matches = self.automaton.find_overlapping_iter(line);
+        let found = matches.next()?;
+        let (mut first, mut last) = (found, found);
+        for found in matches {
+            if (found.start(), Reverse(found.len())) < (first.start(), Reverse(first.len())) {
+                first = found;
+            }
+            if (found.start(), found.len()) > (last.start(), last.len()) {
+                last = found;
+            }
+        }
+        let value = |found: aho_corasick::Match| u64::from(self.values[found.pattern()]);
+        Some(value(first) * 10 + value(last))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("two1nine", Some(29))]
+    #[case("eightwothree", Some(83))]
+    #[case("xtwone3four", Some(24))]
+    #[case("7pqrstsixteen", Some(76))]
+    #[case("twone", Some(21))]
+    #[case("fivezg8jmf6hrxnhgxxttwoneg", Some(51))]
+    #[case("abc", None)]
+    fn test_calibration_value(#[case] line: &str, #[case] expected: Option<u64>) {
+        assert_eq!(expected, Dictionary::english().calibration_value(line));
+    }
+
+    #[test]
+    fn test_process() -> miette::Result<()> {
+        let input = "two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen";
+        assert_eq!("281", process(input)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_custom_dictionary() -> miette::Result<()> {
+        let french = Dictionary::new([("un", 1), ("deux", 2), ("trois", 3), ("huit", 8)])?;
+        assert_eq!("12", process_with_dictionary("undeux", &french)?);
+        assert_eq!("83", process_with_dictionary("xhuitrois\ntwo", &french)?);
+
+        let teens = Dictionary::new([("eight", 8), ("eighteen", 18), ("ten", 10)])?;
+        assert_eq!(Some(18 * 10 + 10), teens.calibration_value("eighteen-ten"));
+        assert_eq!(Some(8 * 10 + 8), teens.calibration_value("xeightx"));
+
+        assert!(matches!(
+            Dictionary::new([("one", 1), ("", 0)]),
+            Err(AocError::EmptyWord)
+        ));
+        Ok(())
+    }
+}
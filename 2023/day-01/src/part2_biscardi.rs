@@ -1,9 +1,11 @@
 // https://github.com/ChristopherBiscardi/advent-of-code/blob/main/2023/rust/day-01/src/part2.rs
 use crate::custom_error::AocError;
+use crate::schema;
 
 pub fn process(
     input: &str,
 ) -> miette::Result<String, AocError> {
+    schema::check(input)?;
     let output =
         input.lines().map(process_line).sum::<u32>();
 
@@ -0,0 +1,60 @@
+//! Shape of the calibration document
+
+use aoc_common::schema::{Checker, InvalidInput};
+
+pub const SHAPE: &str =
+    "lines of lowercase letters and digits, each with a digit or a spelled out digit";
+
+const SPELLED_DIGITS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+/// Part 1's shape, where spelled out digits don't count
+pub const DIGITS_SHAPE: &str = "lines of lowercase letters and digits, each with a digit";
+
+pub fn check(input: &str) -> Result<(), InvalidInput> {
+    check_lines(input, SHAPE, true)
+}
+
+/// Checks for [`DIGITS_SHAPE`], for part 1
+pub fn check_digits(input: &str) -> Result<(), InvalidInput> {
+    check_lines(input, DIGITS_SHAPE, false)
+}
+
+fn check_lines(input: &str, shape: &'static str, spelled: bool) -> Result<(), InvalidInput> {
+    let mut checker = Checker::new(input, shape);
+    for line in checker.lines() {
+        let allowed = |c: char| c.is_ascii_lowercase() || c.is_ascii_digit();
+        checker.chars(line, allowed, "should be a lowercase letter or a digit");
+        let has_digit = line.contains(|c: char| c.is_ascii_digit())
+            || spelled && SPELLED_DIGITS.iter().any(|digit| line.contains(digit));
+        checker.expect(has_digit, line, "every line should have a digit");
+    }
+    checker.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check() {
+        assert!(check("1abc2\ntwo1nine\nxtwone3four").is_ok());
+        let invalid = check("1abc2\nNo digits\n").unwrap_err();
+        assert_eq!(
+            [
+                (6..7, "should be a lowercase letter or a digit".to_string()),
+                (8..9, "should be a lowercase letter or a digit".to_string()),
+                (6..15, "every line should have a digit".to_string()),
+            ],
+            invalid.violations()
+        );
+
+        assert!(check_digits("1abc2\nxtwone3four").is_ok());
+        let invalid = check_digits("1abc2\ntwo").unwrap_err();
+        assert_eq!(
+            [(6..9, "every line should have a digit".to_string())],
+            invalid.violations()
+        );
+    }
+}
@@ -0,0 +1,106 @@
+//! Part 1's digit scanning over 64-byte blocks: each block becomes a bitmask of its digits and one
+//! of its newlines, which compile to a few vector compares, and only the set bits are visited.
+
+const BLOCK: usize = 64;
+
+aoc_common::multiversion! {
+    /// Sum of the calibration values, the first and last digit of each line
+    pub fn calibration_sum(input: &[u8]) -> u64 = calibration_sum_kernel;
+}
+
+aoc_common::multiversion! {
+    /// Calibration values of the lines ending in `chunk`, the first of which started with `line`.
+    /// `line` is left with the digits of the unfinished line at the end of `chunk`.
+    pub(crate) fn scan_chunk(line: &mut LineDigits, chunk: &[u8]) -> u64 = scan_chunk_kernel;
+}
+
+#[inline(always)]
+fn calibration_sum_kernel(input: &[u8]) -> u64 {
+    let mut line = LineDigits::default();
+    scan_chunk_kernel(&mut line, input) + u64::from(line.end())
+}
+
+#[inline(always)]
+fn scan_chunk_kernel(line: &mut LineDigits, chunk: &[u8]) -> u64 {
+    // A block's lines add up to at most 64 * 99, but a whole input's can overflow a u32
+    let mut sum = 0;
+    let mut blocks = chunk.chunks_exact(BLOCK);
+    for block in &mut blocks {
+        sum += u64::from(line.scan(block.try_into().unwrap()));
+    }
+    // Zeros are neither digits nor newlines
+    let mut last_block = [0; BLOCK];
+    last_block[..blocks.remainder().len()].copy_from_slice(blocks.remainder());
+    sum + u64::from(line.scan(&last_block))
+}
+
+/// First and last digit of the current line so far
+#[derive(Default)]
+pub(crate) struct LineDigits {
+    first: Option<u32>,
+    last: u32,
+}
+
+impl LineDigits {
+    /// Calibration values of the lines ending in `block`
+    #[inline(always)]
+    fn scan(&mut self, block: &[u8; BLOCK]) -> u32 {
+        let (digits, newlines) = masks(block);
+        let mut sum = 0;
+        let mut events = digits | newlines;
+        while events != 0 {
+            let index = events.trailing_zeros() as usize;
+            events &= events - 1;
+            if newlines >> index & 1 == 1 {
+                sum += self.end();
+            } else {
+                let digit = u32::from(block[index] - b'0');
+                self.first.get_or_insert(digit);
+                self.last = digit;
+            }
+        }
+        sum
+    }
+
+    /// Calibration value of the line that just ended, 0 if it had no digits
+    #[inline(always)]
+    pub(crate) fn end(&mut self) -> u32 {
+        self.first.take().map_or(0, |first| first * 10 + self.last)
+    }
+}
+
+/// Bit `i` of the first mask is set if `block[i]` is a digit, of the second if it's a newline
+#[inline(always)]
+fn masks(block: &[u8; BLOCK]) -> (u64, u64) {
+    let mut digits = 0;
+    let mut newlines = 0;
+    for (index, &byte) in block.iter().enumerate() {
+        digits |= u64::from(byte.wrapping_sub(b'0') < 10) << index;
+        newlines |= u64::from(byte == b'\n') << index;
+    }
+    (digits, newlines)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use aoc_common::isa::Isa;
+
+    #[test]
+    fn test_calibration_sum() {
+        let input = "1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet\n";
+        // Lines spanning blocks, and a last line without a newline
+        let long = format!(
+            "{}7{}\n{}9x",
+            "a".repeat(70),
+            "b".repeat(100),
+            "c".repeat(63)
+        );
+        for isa in Isa::supported() {
+            assert_eq!(142, calibration_sum(isa, input.as_bytes()), "{isa}");
+            assert_eq!(77 + 99, calibration_sum(isa, long.as_bytes()), "{isa}");
+            assert_eq!(0, calibration_sum(isa, b""), "{isa}");
+        }
+    }
+}
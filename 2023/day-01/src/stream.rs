@@ -0,0 +1,115 @@
+//! Part 1 over any reader, one chunk at a time, for calibration files too big to load whole. The
+//! chunks go through [`crate::simd`]'s scanner, which carries the digits of a line split between
+//! two chunks over to the next one, so nothing is allocated per line.
+
+use std::io::{BufRead, ErrorKind, Read};
+
+use aoc_common::isa::Isa;
+
+use crate::custom_error::AocError;
+use crate::simd::{self, LineDigits};
+
+/// Bytes [`calibration_sum`] reads at a time
+const CHUNK: usize = 64 * 1024;
+
+/// Sum of the calibration values of the lines read from `reader`, 0 for the lines without digits.
+/// Reads through a fixed buffer on the stack.
+pub fn calibration_sum(mut reader: impl Read) -> Result<u64, AocError> {
+    let mut buffer = [0; CHUNK];
+    let mut sum = CalibrationSum::default();
+    loop {
+        match reader.read(&mut buffer) {
+            Ok(0) => return Ok(sum.finish()),
+            Ok(read) => sum.scan(&buffer[..read]),
+            Err(error) if error.kind() == ErrorKind::Interrupted => {}
+            Err(error) => return Err(error.into()),
+        }
+    }
+}
+
+/// Same as [`calibration_sum`], scanning the reader's own buffer instead of copying out of it
+pub fn calibration_sum_buffered(mut reader: impl BufRead) -> Result<u64, AocError> {
+    let mut sum = CalibrationSum::default();
+    loop {
+        let chunk = match reader.fill_buf() {
+            Ok([]) => return Ok(sum.finish()),
+            Ok(chunk) => chunk,
+            Err(error) if error.kind() == ErrorKind::Interrupted => continue,
+            Err(error) => return Err(error.into()),
+        };
+        sum.scan(chunk);
+        let scanned = chunk.len();
+        reader.consume(scanned);
+    }
+}
+
+/// Sum so far, and the digits of the line the last chunk ended in
+struct CalibrationSum {
+    isa: Isa,
+    line: LineDigits,
+    sum: u64,
+}
+
+impl Default for CalibrationSum {
+    fn default() -> Self {
+        CalibrationSum {
+            isa: Isa::selected(),
+            line: LineDigits::default(),
+            sum: 0,
+        }
+    }
+}
+
+impl CalibrationSum {
+    fn scan(&mut self, chunk: &[u8]) {
+        // Readers may hand out chunks of any size, keep the sum of each piece within a u32
+        for piece in chunk.chunks(CHUNK) {
+            self.sum += simd::scan_chunk(self.isa, &mut self.line, piece);
+        }
+    }
+
+    /// Adds the last line, which may not end with a newline
+    fn finish(mut self) -> u64 {
+        self.sum + u64::from(self.line.end())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::io::BufReader;
+
+    /// Hands out at most `size` bytes per read, so lines get split between reads
+    struct Trickle<'a> {
+        input: &'a [u8],
+        size: usize,
+    }
+
+    impl Read for Trickle<'_> {
+        fn read(&mut self, buffer: &mut [u8]) -> std::io::Result<usize> {
+            let size = self.size.min(buffer.len()).min(self.input.len());
+            buffer[..size].copy_from_slice(&self.input[..size]);
+            self.input = &self.input[size..];
+            Ok(size)
+        }
+    }
+
+    #[test]
+    fn test_calibration_sum() -> miette::Result<()> {
+        let input = "1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet";
+        assert_eq!(142, calibration_sum(input.as_bytes())?);
+        assert_eq!(142, calibration_sum_buffered(input.as_bytes())?);
+        for size in [1, 2, 3, 7] {
+            let trickle = Trickle {
+                input: input.as_bytes(),
+                size,
+            };
+            assert_eq!(142, calibration_sum(trickle)?, "{size}");
+            let buffered = BufReader::with_capacity(size, input.as_bytes());
+            assert_eq!(142, calibration_sum_buffered(buffered)?, "{size}");
+        }
+        assert_eq!(0, calibration_sum("".as_bytes())?);
+        Ok(())
+    }
+}
@@ -0,0 +1,75 @@
+//! Intermediates for `aoc run --explain`: the cubes shown in every game.
+
+use aoc_common::explain::{Explanation, Record};
+use aoc_common::params::Params;
+
+use crate::custom_error::AocError;
+use crate::{part1, part2};
+
+/// Whether each game is possible with the default cube limits
+pub fn part1(input: &str) -> miette::Result<Explanation, AocError> {
+    let limits = part1::limits(&Params::defaults(part1::PARAMS));
+    let mut explanation = Explanation::new();
+    for line in input.lines() {
+        let value = part1::process_line(line, limits);
+        game_record(&mut explanation, line)
+            .field("possible", value != 0)
+            .field("value", value);
+    }
+    Ok(explanation)
+}
+
+pub fn part2(input: &str) -> miette::Result<Explanation, AocError> {
+    let mut explanation = Explanation::new();
+    for line in input.lines() {
+        let [red, green, blue] = part2::fewest_cubes(line);
+        game_record(&mut explanation, line).field("power", red * green * blue);
+    }
+    Ok(explanation)
+}
+
+/// Record labelled with the game's number, with the most cubes of each colour shown at once
+fn game_record<'a>(explanation: &'a mut Explanation, line: &str) -> &'a mut Record {
+    let game = line.split_once(':').map_or(line, |(game, _)| game);
+    let [red, green, blue] = part2::fewest_cubes(line);
+    explanation
+        .record(game)
+        .field("red", red)
+        .field("green", green)
+        .field("blue", blue)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::explain::Value;
+
+    use super::*;
+
+    #[test]
+    fn test_explain() -> miette::Result<()> {
+        let input = "\
+Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red";
+        let explanation = part1(input)?;
+        assert_eq!("Game 3", explanation.records[1].label);
+        assert_eq!(Some(&Value::Int(20)), explanation.records[1].get("red"));
+        assert_eq!(Some(&Value::Bool(false)), explanation.records[1].get("possible"));
+        assert_eq!(Some(&Value::Int(1)), explanation.records[0].get("value"));
+
+        assert_eq!(Some(&Value::Int(48)), part2(input)?.records[0].get("power"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_snapshots() -> miette::Result<()> {
+        let input = "\
+Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+        insta::assert_snapshot!("part1", part1(input)?);
+        insta::assert_snapshot!("part2", part2(input)?);
+        Ok(())
+    }
+}
@@ -0,0 +1,141 @@
+use aoc_common::lenient::Lenient;
+use aoc_common::params::{ParamSpec, Params};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use crate::custom_error::AocError;
+use crate::schema;
+
+const fn cube_limit(name: &'static str, help: &'static str, default: u64) -> ParamSpec {
+    ParamSpec {
+        name,
+        help,
+        default,
+        min: 0,
+        max: i32::MAX as u64,
+    }
+}
+
+// limits defined in problem
+pub const PARAMS: &[ParamSpec] = &[
+    cube_limit("red", "Number of red cubes in the bag", 12),
+    cube_limit("green", "Number of green cubes in the bag", 13),
+    cube_limit("blue", "Number of blue cubes in the bag", 14),
+];
+
+// 12 red cubes, 13 green cubes, and 14 blue cubes
+pub fn process(input: &str) -> miette::Result<String, AocError> {
+    process_with_params(input, &Params::defaults(PARAMS))
+}
+
+pub fn process_with_params(input: &str, params: &Params) -> miette::Result<String, AocError> {
+    schema::check(input)?;
+    let limits = limits(params);
+    let result = input
+        .lines()
+        .map(|line| process_line(line, limits))
+        .sum::<i32>();
+    Ok(result.to_string())
+}
+
+/// Same as [`process`] with the games spread over rayon's thread pool
+#[cfg(feature = "parallel")]
+pub fn process_parallel(input: &str) -> miette::Result<String, AocError> {
+    process_parallel_with_params(input, &Params::defaults(PARAMS))
+}
+
+#[cfg(feature = "parallel")]
+pub fn process_parallel_with_params(
+    input: &str,
+    params: &Params,
+) -> miette::Result<String, AocError> {
+    schema::check(input)?;
+    let limits = limits(params);
+    let result = input
+        .par_lines()
+        .map(|line| process_line(line, limits))
+        .sum::<i32>();
+    Ok(result.to_string())
+}
+
+pub(crate) fn limits(params: &Params) -> [i32; 3] {
+    [params.get("red"), params.get("green"), params.get("blue")]
+}
+
+/// The game's number if it's possible with the given cube limits, otherwise 0
+pub(crate) fn process_line(line: &str, [red_limit, green_limit, blue_limit]: [i32; 3]) -> i32 {
+    let mut curr_count = 0;
+
+    let mut token_iter = line.split_whitespace().skip(1);
+    let game_num = token_iter.next().unwrap().split(':').next().unwrap();
+
+    for token in token_iter {
+        match token.parse::<i32>() {
+            Ok(num) => curr_count = num,
+            _ => {
+                if match &token[..1] {
+                    "r" => curr_count > red_limit,
+                    "g" => curr_count > green_limit,
+                    "b" => curr_count > blue_limit,
+                    c => panic!("unexpected token {c}"),
+                } {
+                    return 0;
+                }
+            }
+        }
+    }
+    game_num.parse::<i32>().unwrap()
+}
+
+/// Same as [`process`] but skips the lines that don't have the input's shape, see
+/// [`crate::schema`], returning the answer over the other lines in [`AocError::Partial`]
+pub fn process_lenient(input: &str) -> miette::Result<String, AocError> {
+    let lines = Lenient::lines(input, schema::check);
+    let answer = lines.answer(process)?;
+    Ok(lines.finish(answer)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_process() -> miette::Result<()> {
+        let input = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+        assert_eq!("8", process(input)?);
+        #[cfg(feature = "parallel")]
+        assert_eq!("8", process_parallel(input)?);
+
+        // Game 3 is possible with 20 red cubes
+        let overrides = [("red".to_string(), "20".to_string())];
+        let params = Params::resolve(PARAMS, &overrides)?;
+        assert_eq!("11", process_with_params(input, &params)?);
+        #[cfg(feature = "parallel")]
+        assert_eq!("11", process_parallel_with_params(input, &params)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_process_lenient() {
+        let input = "\
+Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 purple, 20 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+        let Err(AocError::Partial(partial)) = process_lenient(input) else {
+            panic!("line 3 should be skipped");
+        };
+        assert_eq!("8", partial.answer());
+        assert_eq!(1, partial.skipped().len());
+
+        // Nothing left to solve
+        let Err(AocError::Partial(partial)) = process_lenient("Game 3: 8 green, 6 purple, 20 red") else {
+            panic!("every line should be skipped");
+        };
+        assert_eq!("0", partial.answer());
+    }
+}
@@ -1,11 +1,31 @@
+use aoc_common::lenient::Lenient;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
 use crate::custom_error::AocError;
+use crate::schema;
 
 pub fn process(input: &str) -> miette::Result<String, AocError> {
+    schema::check(input)?;
     let result = input.lines().map(process_line).sum::<i32>();
     Ok(result.to_string())
 }
 
+/// Same as [`process`] with the games spread over rayon's thread pool
+#[cfg(feature = "parallel")]
+pub fn process_parallel(input: &str) -> miette::Result<String, AocError> {
+    schema::check(input)?;
+    let result = input.par_lines().map(process_line).sum::<i32>();
+    Ok(result.to_string())
+}
+
 fn process_line(line: &str) -> i32 {
+    let [red_max, green_max, blue_max] = fewest_cubes(line);
+    red_max * green_max * blue_max
+}
+
+/// Largest number of red, green and blue cubes shown at once
+pub(crate) fn fewest_cubes(line: &str) -> [i32; 3] {
     let mut curr_count = 0;
 
     let mut red_max = 0;
@@ -25,7 +45,15 @@ fn process_line(line: &str) -> i32 {
             }
         }
     }
-    red_max * green_max * blue_max
+    [red_max, green_max, blue_max]
+}
+
+/// Same as [`process`] but skips the lines that don't have the input's shape, see
+/// [`crate::schema`], returning the answer over the other lines in [`AocError::Partial`]
+pub fn process_lenient(input: &str) -> miette::Result<String, AocError> {
+    let lines = Lenient::lines(input, schema::check);
+    let answer = lines.answer(process)?;
+    Ok(lines.finish(answer)?)
 }
 
 #[cfg(test)]
@@ -40,6 +68,8 @@ Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
 Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
 Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
         assert_eq!("2286", process(input)?);
+        #[cfg(feature = "parallel")]
+        assert_eq!("2286", process_parallel(input)?);
         Ok(())
     }
 }
@@ -0,0 +1,63 @@
+//! Shape of the game records
+
+use aoc_common::schema::{Checker, InvalidInput};
+
+pub const SHAPE: &str = "lines like `Game 1: 3 blue, 4 red; 2 green`, the cube counts of a set \
+                         separated by `, ` and the sets by `; `";
+
+pub fn check(input: &str) -> Result<(), InvalidInput> {
+    let mut checker = Checker::new(input, SHAPE);
+    for line in checker.lines() {
+        let Some(game) = checker.prefixed(line, "Game ") else {
+            continue;
+        };
+        let Some((id, sets)) = checker.split(game, ": ") else {
+            continue;
+        };
+        checker.bounded(id, "the game id", i32::MAX as u64);
+        for cubes in sets.split("; ").flat_map(|set| set.split(", ")) {
+            let Some((count, color)) = checker.split(cubes, " ") else {
+                continue;
+            };
+            checker.bounded(count, "the cube count", i32::MAX as u64);
+            checker.expect(
+                matches!(color, "red" | "green" | "blue"),
+                color,
+                "the color should be `red`, `green` or `blue`",
+            );
+        }
+    }
+    checker.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check() {
+        let input = "\
+Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue";
+        assert!(check(input).is_ok());
+        let invalid = check("Game 1: 3 blue, 4 purple; two green").unwrap_err();
+        assert_eq!(
+            [
+                (
+                    18..24,
+                    "the color should be `red`, `green` or `blue`".to_string()
+                ),
+                (26..29, "the cube count should be a number".to_string()),
+            ],
+            invalid.violations()
+        );
+        let invalid = check("Game 1: 266666666660 red").unwrap_err();
+        assert_eq!(
+            [(
+                8..20,
+                "the cube count should be at most 2147483647".to_string()
+            )],
+            invalid.violations()
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use day_07::*;
+use aoc2023_day_03::*;
 
 fn main() {
     // Run registered benchmarks.
@@ -1,4 +1,4 @@
-use day_03::part2::process;
+use aoc2023_day_03::part2::process;
 use miette::Context;
 
 #[cfg(feature = "dhat-heap")]
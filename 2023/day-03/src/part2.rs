@@ -1,6 +1,8 @@
 use crate::custom_error::AocError;
+use crate::schema;
 
 pub fn process(input: &str) -> miette::Result<String, AocError> {
+    schema::check(input)?;
     // row length is 10 for test case
     let row_length = input.find('\n').unwrap() + 1;
     let mut total = 0;
@@ -90,7 +92,7 @@ pub fn process(input: &str) -> miette::Result<String, AocError> {
             }
         }
         // check bottom starting with bottom left
-        if symbol_index + row_length > input.len(){
+        if symbol_index + row_length >= input.len(){
             continue;
         }
         'outer: for (index_left, c) in input[..symbol_index + row_length].chars().rev().enumerate() {
@@ -166,6 +168,8 @@ mod tests {
 ...$.*....
 .664.598..";
         assert_eq!("467835", process(input)?);
+        // A gear on the last row, with nothing below it
+        assert_eq!("6", process("2.\n*3\n")?);
         Ok(())
     }
 }
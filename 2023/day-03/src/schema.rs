@@ -0,0 +1,35 @@
+//! Shape of the engine schematic
+
+use aoc_common::schema::{Checker, InvalidInput};
+
+pub const SHAPE: &str = "a rectangular grid of digits, `.` and the symbols `*#+$/@=%-&`";
+
+pub fn check(input: &str) -> Result<(), InvalidInput> {
+    let mut checker = Checker::new(input, SHAPE);
+    checker.grid(input, "0123456789.*#+$/@=%-&");
+    checker.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check() {
+        assert!(check("467..114..\n...*......\n..35..633.\n").is_ok());
+        let invalid = check("467..\n..!..\n..35").unwrap_err();
+        assert_eq!(
+            [
+                (
+                    8..9,
+                    "cells should be one of `0123456789.*#+$/@=%-&`".to_string()
+                ),
+                (
+                    12..16,
+                    "every row should be 5 cells wide like the first".to_string()
+                ),
+            ],
+            invalid.violations()
+        );
+    }
+}
@@ -0,0 +1,44 @@
+use aoc2023_day_04::*;
+
+fn main() {
+    // Run registered benchmarks.
+    divan::main();
+}
+
+#[divan::bench]
+fn part1() {
+    part1::process(divan::black_box(include_str!(
+        "../input1.txt",
+    )))
+    .unwrap();
+}
+
+#[divan::bench]
+fn part2() {
+    part2::process(divan::black_box(include_str!(
+        "../input2.txt",
+    )))
+    .unwrap();
+}
+
+#[cfg(feature = "parallel")]
+#[divan::bench(args = [1, 2, 4, 8])]
+fn part1_parallel(bencher: divan::Bencher, threads: usize) {
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(threads)
+        .build()
+        .unwrap();
+    let input = include_str!("../input1.txt");
+    bencher.bench(|| pool.install(|| part1::process_parallel(divan::black_box(input)).unwrap()));
+}
+
+#[cfg(feature = "parallel")]
+#[divan::bench(args = [1, 2, 4, 8])]
+fn part2_parallel(bencher: divan::Bencher, threads: usize) {
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(threads)
+        .build()
+        .unwrap();
+    let input = include_str!("../input2.txt");
+    bencher.bench(|| pool.install(|| part2::process_parallel(divan::black_box(input)).unwrap()));
+}
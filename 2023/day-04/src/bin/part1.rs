@@ -1,4 +1,4 @@
-use day_04::part1::process;
+use aoc2023_day_04::part1::process;
 use miette::Context;
 
 #[cfg(feature = "dhat-heap")]
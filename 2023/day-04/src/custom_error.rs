@@ -0,0 +1,17 @@
+use miette::Diagnostic;
+use thiserror::Error;
+
+#[derive(Error, Diagnostic, Debug)]
+pub enum AocError {
+    #[error(transparent)]
+    #[diagnostic(code(aoc::io_error))]
+    IoError(#[from] std::io::Error),
+
+    #[error(transparent)]
+    #[diagnostic(transparent)]
+    Partial(#[from] aoc_common::lenient::Partial),
+
+    #[error(transparent)]
+    #[diagnostic(transparent)]
+    InvalidInput(#[from] aoc_common::schema::InvalidInput),
+}
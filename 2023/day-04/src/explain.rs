@@ -0,0 +1,81 @@
+//! Intermediates for `aoc run --explain`: the matches of every card.
+
+use aoc_common::explain::Explanation;
+
+use crate::custom_error::AocError;
+use crate::part1::{count_matches, points};
+
+pub fn part1(input: &str) -> miette::Result<Explanation, AocError> {
+    let mut explanation = Explanation::new();
+    for line in input.lines() {
+        let matches = count_matches(line);
+        explanation
+            .record(card(line))
+            .field("matches", matches)
+            .field("points", points(matches));
+    }
+    Ok(explanation)
+}
+
+/// How many copies of each card are won, including the original
+pub fn part2(input: &str) -> miette::Result<Explanation, AocError> {
+    let mut num_copies = vec![1_u32; input.lines().count()];
+    let mut explanation = Explanation::new();
+    for (game_index, line) in input.lines().enumerate() {
+        let matches = count_matches(line);
+        let instances = num_copies[game_index];
+        for i in 1..=matches {
+            num_copies[game_index + i] += instances;
+        }
+        explanation
+            .record(card(line))
+            .field("matches", matches)
+            .field("instances", instances);
+    }
+    Ok(explanation)
+}
+
+fn card(line: &str) -> String {
+    let card = line.split_once(':').map_or(line, |(card, _)| card);
+    card.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::explain::Value;
+
+    use super::*;
+
+    #[test]
+    fn test_explain() -> miette::Result<()> {
+        let input = "\
+Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+        let explanation = part1(input)?;
+        assert_eq!("Card 1", explanation.records[0].label);
+        assert_eq!(Some(&Value::Int(4)), explanation.records[0].get("matches"));
+        assert_eq!(Some(&Value::Int(8)), explanation.records[0].get("points"));
+
+        let explanation = part2(input)?;
+        assert_eq!(Some(&Value::Int(4)), explanation.records[2].get("instances"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_snapshots() -> miette::Result<()> {
+        let input = "\
+Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+        insta::assert_snapshot!("part1", part1(input)?);
+        insta::assert_snapshot!("part2", part2(input)?);
+        Ok(())
+    }
+}
@@ -0,0 +1,102 @@
+use aoc_common::lenient::Lenient;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use crate::custom_error::AocError;
+use crate::schema;
+use itertools::Itertools;
+
+pub fn process(input: &str) -> miette::Result<String, AocError> {
+    schema::check(input)?;
+    // Accumulate the result of each line
+    let result = input
+        .lines()
+        .fold(0, |total, line| total + points(count_matches(line)));
+
+    Ok(result.to_string())
+}
+
+/// Same as [`process`] with the cards spread over rayon's thread pool
+#[cfg(feature = "parallel")]
+pub fn process_parallel(input: &str) -> miette::Result<String, AocError> {
+    schema::check(input)?;
+    let result = input
+        .par_lines()
+        .map(|line| points(count_matches(line)))
+        .sum::<u32>();
+
+    Ok(result.to_string())
+}
+
+/// How many of the card's winning numbers are in "my numbers"
+pub(crate) fn count_matches(line: &str) -> usize {
+    // Parse and collect each line into two iterators. One for each number list.
+    let (winners, my_numbers) = line
+        .split_once(':')
+        .unwrap()
+        .1
+        .split('|')
+        .map(|list| list.split_whitespace())
+        .collect_tuple()
+        .unwrap();
+
+    // Count how many winning numbers appear in "my numbers"
+    winners
+        .filter(|winner| my_numbers.clone().contains(winner))
+        .count()
+}
+
+/// The first match is worth one point, every other one doubles the points
+pub(crate) fn points(matches: usize) -> u32 {
+    match matches {
+        0 => 0,
+        c => 2_u32.pow(c as u32 - 1),
+    }
+}
+
+/// Same as [`process`] but skips the lines that don't have the input's shape, see
+/// [`crate::schema`], returning the answer over the other lines in [`AocError::Partial`]
+pub fn process_lenient(input: &str) -> miette::Result<String, AocError> {
+    let lines = Lenient::lines(input, schema::check);
+    let answer = lines.answer(process)?;
+    Ok(lines.finish(answer)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_process() -> miette::Result<()> {
+        let input = "\
+Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+        assert_eq!("13", process(input)?);
+        #[cfg(feature = "parallel")]
+        assert_eq!("13", process_parallel(input)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_process_lenient() {
+        let input = "\
+Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61   61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1";
+        let Err(AocError::Partial(partial)) = process_lenient(input) else {
+            panic!("line 2 should be skipped");
+        };
+        assert_eq!("10", partial.answer());
+        assert_eq!(1, partial.skipped().len());
+
+        // Nothing left to solve
+        let Err(AocError::Partial(partial)) = process_lenient("Card 2: 13 32 20 16 61   61 30 68 82 17 32 24 19") else {
+            panic!("every line should be skipped");
+        };
+        assert_eq!("0", partial.answer());
+    }
+}
@@ -0,0 +1,94 @@
+use aoc_common::lenient::Lenient;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use crate::custom_error::AocError;
+use crate::part1::count_matches;
+use crate::schema;
+
+pub fn process(input: &str) -> miette::Result<String, AocError> {
+    schema::check(input)?;
+    // Using a fixed-size array instead of a vec somehow makes the performance worse.
+    let num_games = input.lines().count();
+    let mut num_copies = vec![1_u32; num_games];
+
+    let mut result = 0;
+
+    for (game_index, line) in input.lines().enumerate() {
+        let matches_count = count_matches(line);
+
+        // Get the final count of game instances and add to result
+        let instances = num_copies[game_index];
+        result += instances;
+
+        // Update instance counts for subsequent games, none past the last one
+        let last = (game_index + matches_count).min(num_copies.len() - 1);
+        for copies in &mut num_copies[game_index + 1..=last] {
+            *copies += instances;
+        }
+    };
+  
+    Ok(result.to_string())
+}
+
+/// Same as [`process`] but counts every card's matches on rayon's thread pool first. Only the
+/// copies, which depend on the cards before, are added up in order.
+#[cfg(feature = "parallel")]
+pub fn process_parallel(input: &str) -> miette::Result<String, AocError> {
+    schema::check(input)?;
+    let matches = input.par_lines().map(count_matches).collect::<Vec<_>>();
+    let mut num_copies = vec![1_u32; matches.len()];
+
+    let mut result = 0;
+
+    for (game_index, &matches_count) in matches.iter().enumerate() {
+        let instances = num_copies[game_index];
+        result += instances;
+
+        let last = (game_index + matches_count).min(num_copies.len() - 1);
+        for copies in &mut num_copies[game_index + 1..=last] {
+            *copies += instances;
+        }
+    }
+
+    Ok(result.to_string())
+}
+
+/// Same as [`process`] but skips the lines that don't have the input's shape, see
+/// [`crate::schema`], returning the answer over the other lines in [`AocError::Partial`]
+pub fn process_lenient(input: &str) -> miette::Result<String, AocError> {
+    let lines = Lenient::lines(input, schema::check);
+    let answer = lines.answer(process)?;
+    Ok(lines.finish(answer)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_process() -> miette::Result<()> {
+        let input = "\
+Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+        assert_eq!("30", process(input)?);
+        #[cfg(feature = "parallel")]
+        assert_eq!("30", process_parallel(input)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_process_lenient() {
+        // Card 1 wins copies of card 2, which is skipped, and of a card 3 that doesn't exist
+        let Err(AocError::Partial(partial)) = process_lenient("Card 1: 1 2 | 1 2\nCard 2: x | 3\n")
+        else {
+            panic!("card 2 should be skipped");
+        };
+        assert_eq!("1", partial.answer());
+        assert_eq!(1, partial.skipped().len());
+    }
+}
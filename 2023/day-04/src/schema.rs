@@ -0,0 +1,48 @@
+//! Shape of the scratchcards
+
+use aoc_common::schema::{Checker, InvalidInput};
+
+pub const SHAPE: &str = "lines like `Card 1: 41 48 83 | 83 86 6 31`, the winning numbers before \
+                         the `|` and the numbers you have after it";
+
+pub fn check(input: &str) -> Result<(), InvalidInput> {
+    let mut checker = Checker::new(input, SHAPE);
+    for line in checker.lines() {
+        let Some(card) = checker.prefixed(line, "Card") else {
+            continue;
+        };
+        let Some((id, numbers)) = checker.split(card, ":") else {
+            continue;
+        };
+        checker.number(id.trim_start(), "the card number");
+        let Some((winning, yours)) = checker.split(numbers, "|") else {
+            continue;
+        };
+        for number in winning.split_whitespace().chain(yours.split_whitespace()) {
+            let digits = number.bytes().all(|b| b.is_ascii_digit());
+            checker.expect(digits, number, "should be a number");
+        }
+    }
+    checker.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check() {
+        let input = "\
+Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19";
+        assert!(check(input).is_ok());
+        let invalid = check("Card 1: 41 48 83 86 17  83 86  6 31\nCard 2: 1 x | 2").unwrap_err();
+        assert_eq!(
+            [
+                (7..35, "should contain `|`".to_string()),
+                (46..47, "should be a number".to_string()),
+            ],
+            invalid.violations()
+        );
+    }
+}
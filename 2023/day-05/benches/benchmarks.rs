@@ -0,0 +1,64 @@
+use aoc2023_day_05::*;
+
+fn main() {
+    // Run registered benchmarks.
+    divan::main();
+}
+
+#[divan::bench]
+fn part1() {
+    part1::process(divan::black_box(include_str!(
+        "../input1.txt",
+    )))
+    .unwrap();
+}
+
+#[divan::bench]
+fn part1_optimized() {
+    part1::process_optimized(divan::black_box(include_str!(
+        "../input1.txt",
+    )))
+    .unwrap();
+}
+
+#[divan::bench]
+fn part2_optimized() {
+    part2::process_optimized(divan::black_box(include_str!(
+        "../input2.txt",
+    )))
+    .unwrap();
+}
+
+// VERY SLOW ~1.5 min
+// #[divan::bench(sample_size = 1, sample_count = 1)]
+// fn part2() {
+//     part2::process(divan::black_box(include_str!(
+//         "../input2.txt",
+//     )))
+//     .unwrap();
+// }
+/// The almanac's mapping lines, read like the solvers did before `aoc_common::parse`
+#[divan::bench]
+fn parse_str() -> i64 {
+    mapping_lines(divan::black_box(include_str!("../input1.txt")))
+        .flat_map(|line| {
+            line.split_ascii_whitespace()
+                .map(|value| value.parse::<i64>().unwrap())
+        })
+        .sum()
+}
+
+/// The same lines read by the shared parser the solvers use now
+#[divan::bench]
+fn parse_shared() -> i64 {
+    mapping_lines(divan::black_box(include_str!("../input1.txt")))
+        .flat_map(|line| aoc_common::parse::numbers::<i64>(line).map(Result::unwrap))
+        .sum()
+}
+
+fn mapping_lines(input: &str) -> impl Iterator<Item = &str> {
+    input
+        .lines()
+        .skip(1)
+        .filter(|line| line.starts_with(|c: char| c.is_ascii_digit()))
+}
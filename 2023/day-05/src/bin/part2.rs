@@ -1,4 +1,4 @@
-use day_05::part2::*;
+use aoc2023_day_05::part2::*;
 use miette::Context;
 
 #[cfg(feature = "dhat-heap")]
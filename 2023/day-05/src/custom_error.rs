@@ -0,0 +1,21 @@
+use miette::Diagnostic;
+use thiserror::Error;
+
+#[derive(Error, Diagnostic, Debug)]
+pub enum AocError {
+    #[error(transparent)]
+    #[diagnostic(code(aoc::io_error))]
+    IoError(#[from] std::io::Error),
+
+    #[error(transparent)]
+    #[diagnostic(transparent)]
+    Cancelled(#[from] aoc_common::cancel::Cancelled),
+
+    #[error(transparent)]
+    #[diagnostic(transparent)]
+    InvalidInput(#[from] aoc_common::schema::InvalidInput),
+
+    #[error("invalid number in the input: {0}")]
+    #[diagnostic(code(aoc::input::number))]
+    ParseInt(#[from] aoc_common::parse::ParseIntError),
+}
@@ -0,0 +1,181 @@
+use aoc_common::parse::{self, ParseIntError};
+
+use crate::custom_error::AocError;
+use crate::schema;
+
+pub fn process(input: &str) -> miette::Result<String, AocError> {
+    schema::check(input)?;
+    // Map each seed to its final "location" number then find the minimum
+    let seeds = input.lines().next().unwrap_or_default();
+    let mut result = i64::MAX;
+    for seed in seeds.split_ascii_whitespace().skip(1) {
+        // `mapped_value` is reused for each mapping step
+        let mut mapped_value = parse::decimal::<i64>(seed)?;
+
+        // Start at first mapping line
+        let mut lines = input.lines().skip(3).peekable();
+
+        while let Some(line) = lines.next() {
+            // Skip to next mapping line if empty
+            if line.is_empty() {
+                lines.next();
+                continue;
+            }
+            // Grab values from mapping line
+            let [dest_start, source_start, range_length] = parse_map_line(line)?;
+
+            // Determine if input value is found in mapping range of current line
+            if (source_start..source_start + range_length).contains(&mapped_value) {
+                mapped_value = dest_start + mapped_value - source_start;
+
+                // Value has been updated, skip to the next map
+                while let Some(useless_line) = lines.peek() {
+                    if useless_line.is_empty() {
+                        break;
+                    }
+                    lines.next();
+                }
+            }
+        }
+        result = result.min(mapped_value);
+    }
+
+    Ok(result.to_string())
+}
+
+/// The destination start, source start and range length of a map line
+pub(crate) fn parse_map_line(line: &str) -> Result<[i64; 3], ParseIntError> {
+    let mut values = parse::numbers::<i64>(line);
+    let mut next = || values.next().unwrap_or(Err(ParseIntError::Empty));
+    Ok([next()?, next()?, next()?])
+}
+
+/// One line of an almanac map, sending `source_range` to the range starting at `dest_start`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mapping {
+    source_range: std::ops::Range<i64>,
+    offset: i64,
+}
+
+impl Mapping {
+    pub fn new(dest_start: i64, source_start: i64, range_length: i64) -> Mapping {
+        Mapping {
+            source_range: (source_start..source_start + range_length),
+            offset: dest_start - source_start,
+        }
+    }
+
+    /// Maps `x` in place if it falls in the source range, returning whether it did
+    pub fn map(&self, x: &mut i64) -> bool {
+        if self.source_range.contains(x) {
+            *x += self.offset;
+            return true;
+        }
+        false
+    }
+}
+
+pub fn process_optimized(input: &str) -> miette::Result<String, AocError> {
+    schema::check(input)?;
+    // There are 7 mapping steps. For each step I will store the list of mappings that may apply.
+    let mut mappings_collection: [Vec<Mapping>; 7] =
+        std::array::from_fn(|_| vec![]);
+    let mut mappings_index = 0;
+
+    let mut lines = input.lines();
+
+    // Get seeds
+    let seeds = lines
+        .next()
+        .unwrap_or_default()
+        .split_ascii_whitespace()
+        .skip(1);
+
+    // Start at first mapping line
+    lines.next();
+    lines.next();
+
+    // Collect all mappings
+    while let Some(line) = lines.next() {
+        // Skip to next mapping line if empty
+        if line.is_empty() {
+            lines.next();
+            mappings_index += 1;
+            continue;
+        }
+        // Grab values from mapping line
+        let [dest_start, source_start, range_length] = parse_map_line(line)?;
+
+        // Save mapping for later use
+        mappings_collection[mappings_index].push(Mapping::new(
+            dest_start,
+            source_start,
+            range_length,
+        ));
+    }
+
+    // Map each seed to its final "location" number then find the minimum
+    let mut result = i64::MAX;
+    for seed in seeds {
+        let mut mapped_value = parse::decimal::<i64>(seed)?;
+        for mappings in mappings_collection.iter() {
+            for mapping in mappings.iter() {
+                if mapping.map(&mut mapped_value) {
+                    break;
+                }
+            }
+        }
+        result = result.min(mapped_value);
+    }
+
+    Ok(result.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_process() -> miette::Result<()> {
+        //79 14 55 13
+        let input = "\
+seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4";
+        assert_eq!("35", process(input)?);
+        let invalid = input.replacen("52 50 48", "52 5O 48", 1);
+        assert!(matches!(process(&invalid), Err(AocError::InvalidInput(_))));
+        assert!(parse_map_line("52 5O 48").is_err());
+        Ok(())
+    }
+}
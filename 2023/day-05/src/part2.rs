@@ -1,4 +1,9 @@
+use aoc_common::cancel::CancelToken;
+use aoc_common::parse::{self, ParseIntError};
+
 use crate::custom_error::AocError;
+use crate::part1::{parse_map_line, Mapping};
+use crate::schema;
 
 #[derive(Debug)]
 struct Rule {
@@ -17,25 +22,12 @@ impl Rule {
     }
 }
 
-pub fn process(input: &str) -> miette::Result<String, AocError> {
+/// Collects the rules of the 7 maps, starting at the first line of the first map
+fn parse_rules(mut lines: std::str::Lines) -> Result<[Vec<Rule>; 7], AocError> {
     // There are 7 mapping steps. For each step I will store the list of mappings that may apply.
     let mut mappings: [Vec<Rule>; 7] = std::array::from_fn(|_| vec![]);
     let mut mapping_index = 0;
 
-    let mut lines = input.lines();
-
-    // Get values that define seed ranges
-    let mut seed_line_values = lines
-        .next()
-        .unwrap()
-        .split_ascii_whitespace()
-        .skip(1)
-        .peekable();
-
-    // Start at first mapping line
-    lines.next();
-    lines.next();
-
     // Collect all mappings
     while let Some(line) = lines.next() {
         // Skip to next mapping line if empty
@@ -45,13 +37,7 @@ pub fn process(input: &str) -> miette::Result<String, AocError> {
             continue;
         }
         // Grab values from mapping line
-        let mut value_iter = line
-            .split_ascii_whitespace()
-            .map(|value| value.parse::<i64>().unwrap());
-
-        let dest_start = value_iter.next().unwrap();
-        let source_start = value_iter.next().unwrap();
-        let range_length = value_iter.next().unwrap();
+        let [dest_start, source_start, range_length] = parse_map_line(line)?;
 
         // Save mapping for later use
         mappings[mapping_index].push(Rule {
@@ -61,15 +47,77 @@ pub fn process(input: &str) -> miette::Result<String, AocError> {
         });
     }
 
+    Ok(mappings)
+}
+
+/// The seeds, and the mappings of each almanac map in order
+pub fn parse(input: &str) -> Result<(Vec<i64>, Vec<Vec<Mapping>>), AocError> {
+    schema::check(input)?;
+    let mut sections = input.trim_end().split("\n\n");
+    let seeds = sections.next().unwrap_or_default().split(' ').skip(1);
+    let seeds = seeds.map(parse::decimal::<i64>).collect::<Result<_, _>>()?;
+    let maps = sections
+        .map(|section| {
+            section
+                .lines()
+                .skip(1)
+                .map(|line| {
+                    let [dest_start, source_start, range_length] = parse_map_line(line)?;
+                    Ok(Mapping::new(dest_start, source_start, range_length))
+                })
+                .collect::<Result<_, ParseIntError>>()
+        })
+        .collect::<Result<_, _>>()?;
+    Ok((seeds, maps))
+}
+
+pub fn process(input: &str) -> miette::Result<String, AocError> {
+    process_cancellable(input, &CancelToken::new())
+}
+
+/// Same as [`process`] but gives up with [`AocError::Cancelled`] once `token` is cancelled
+pub fn process_cancellable(
+    input: &str,
+    token: &CancelToken,
+) -> miette::Result<String, AocError> {
+    schema::check(input)?;
+    let mut lines = input.lines();
+
+    // Get values that define seed ranges, as start and length pairs
+    let seed_line_values = lines
+        .next()
+        .unwrap_or_default()
+        .split_ascii_whitespace()
+        .skip(1)
+        .map(parse::decimal::<i64>)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // Start at first mapping line
+    lines.next();
+    lines.next();
+
+    let mappings = parse_rules(lines)?;
+
     let mut min = i64::MAX;
 
+    // Seeds to map, and mapped so far in the previous ranges, for the progress
+    let total_seeds = seed_line_values
+        .chunks(2)
+        .map(|range| range[1] as u64)
+        .sum();
+    let mut done_seeds = 0;
+
     // Iterate over seed ranges
-    while seed_line_values.peek().is_some() {
-        let range_start = seed_line_values.next().unwrap().parse::<i64>().unwrap();
-        let range_length = seed_line_values.next().unwrap().parse::<i64>().unwrap();
+    for range in seed_line_values.chunks(2) {
+        let (range_start, range_length) = (range[0], range[1]);
 
         // Maps one seed at a time...
         for mut mapped_value in range_start..range_start + range_length {
+            if CancelToken::should_poll(mapped_value as u64) {
+                token.check()?;
+                let done = done_seeds + (mapped_value - range_start) as u64;
+                token.progress(done, total_seeds);
+            }
             for mapping in mappings.iter() {
                 for rule in mapping.iter() {
                     if rule.map(&mut mapped_value) {
@@ -82,6 +130,7 @@ pub fn process(input: &str) -> miette::Result<String, AocError> {
                 min = mapped_value;
             }
         }
+        done_seeds += range_length as u64;
     }
 
     Ok(min.to_string())
@@ -89,56 +138,29 @@ pub fn process(input: &str) -> miette::Result<String, AocError> {
 
 /// The idea here is to process per seed range instead of per seed
 pub fn process_optimized(input: &str) -> miette::Result<String, AocError> {
-    // There are 7 mapping steps. For each step I will store the list of mappings that may apply.
-    let mut mappings: [Vec<Rule>; 7] = std::array::from_fn(|_| vec![]);
-    let mut mappings_index = 0;
-
+    schema::check(input)?;
     let mut lines = input.lines();
 
     // Get values that define seed ranges
-    let mut seed_line_values = lines
+    let seed_line_values = lines
         .next()
-        .unwrap()
+        .unwrap_or_default()
         .split_ascii_whitespace()
         .skip(1)
-        .map(|s| s.parse::<i64>().unwrap())
-        .peekable();
+        .map(parse::decimal::<i64>)
+        .collect::<Result<Vec<_>, _>>()?;
 
     // Start at first mapping line
     lines.next();
     lines.next();
 
-    // Collect all mappings
-    while let Some(line) = lines.next() {
-        // Skip to next mapping line if empty
-        if line.is_empty() {
-            lines.next();
-            mappings_index += 1;
-            continue;
-        }
-        // Grab values from mapping line
-        let mut value_iter = line
-            .split_ascii_whitespace()
-            .map(|value| value.parse::<i64>().unwrap());
-
-        let dest_start = value_iter.next().unwrap();
-        let source_start = value_iter.next().unwrap();
-        let range_length = value_iter.next().unwrap();
-
-        // Save mapping for later use
-        mappings[mappings_index].push(Rule {
-            start: source_start,
-            end: source_start + range_length,
-            offset: dest_start - source_start,
-        });
-    }
+    let mappings = parse_rules(lines)?;
 
     let mut ranges = vec![];
 
     // Iterate over seed ranges and save in `ranges`
-    while seed_line_values.peek().is_some() {
-        let range_start = seed_line_values.next().unwrap();
-        let range_length = seed_line_values.next().unwrap();
+    for range in seed_line_values.chunks(2) {
+        let (range_start, range_length) = (range[0], range[1]);
         ranges.push(range_start..range_start + range_length);
     }
 
@@ -259,6 +281,20 @@ humidity-to-location map:
 60 56 37
 56 93 4";
         assert_eq!("46", process(input)?);
+
+        // Skip the seeds to the first map's first line
+        let mut lines = input.lines();
+        lines.nth(2);
+        insta::assert_debug_snapshot!("rules", parse_rules(lines)?);
         Ok(())
     }
+
+    #[test]
+    fn test_process_cancelled() {
+        let token = CancelToken::new();
+        token.cancel();
+        let input = format!("seeds: 0 100000{}", "\n\nseed-to-soil map:".repeat(7));
+        let result = process_cancellable(&input, &token);
+        assert!(matches!(result, Err(AocError::Cancelled(_))));
+    }
 }
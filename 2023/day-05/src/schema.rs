@@ -0,0 +1,93 @@
+//! Shape of the almanac
+
+use aoc_common::schema::{Checker, InvalidInput};
+
+pub const SHAPE: &str = "a `seeds:` line with an even number of seeds, then 7 maps separated by \
+                         blank lines, each a line like `seed-to-soil map:` followed by lines of \
+                         3 numbers";
+
+pub fn check(input: &str) -> Result<(), InvalidInput> {
+    let mut checker = Checker::new(input, SHAPE);
+    let mut sections = input.trim_end().split("\n\n");
+    let seeds_line = sections.next().unwrap_or_default();
+    if let Some(seeds) = checker.prefixed(seeds_line, "seeds: ") {
+        let seeds = seeds.split(' ').collect::<Vec<_>>();
+        for seed in &seeds {
+            checker.bounded(seed, "the seed", i64::MAX as u64);
+        }
+        checker.expect(
+            seeds.len() % 2 == 0,
+            seeds_line,
+            "there should be an even number of seeds, part 2 reads them as pairs",
+        );
+    }
+
+    let maps = sections.collect::<Vec<_>>();
+    let count = maps.len();
+    checker.expect(
+        count == 7,
+        input,
+        format!("there should be 7 maps, found {count}"),
+    );
+    for map in maps {
+        let mut lines = map.lines();
+        let header = lines.next().unwrap_or(map);
+        checker.expect(
+            header.ends_with(" map:"),
+            header,
+            "a map should start with a line like `seed-to-soil map:`",
+        );
+        for line in lines {
+            let values = line.split(' ').collect::<Vec<_>>();
+            if checker.expect(values.len() == 3, line, "map lines should have 3 numbers") {
+                for value in values {
+                    checker.bounded(value, "the range value", i64::MAX as u64);
+                }
+            }
+        }
+    }
+    checker.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check() {
+        let mut input = "seeds: 79 14 55 13\n".to_string();
+        for map in [
+            "seed-to-soil",
+            "a-to-b",
+            "b-to-c",
+            "c-to-d",
+            "d-to-e",
+            "e-to-f",
+            "f-to-g",
+        ] {
+            input += &format!("\n{map} map:\n50 98 2\n52 50 48\n");
+        }
+        assert!(check(&input).is_ok());
+        let invalid = check("seeds: 79 14 55\n\nseed-to-soil map:\n50 98\n").unwrap_err();
+        assert_eq!(
+            [
+                (
+                    0..15,
+                    "there should be an even number of seeds, part 2 reads them as pairs"
+                ),
+                (0..41, "there should be 7 maps, found 1"),
+                (35..40, "map lines should have 3 numbers"),
+            ]
+            .map(|(span, message)| (span, message.to_string())),
+            invalid.violations()
+        );
+        let invalid = check("seeds: 1 9223372036854775808").unwrap_err();
+        assert_eq!(
+            (
+                9..28,
+                "the seed should be at most 9223372036854775807".to_string()
+            ),
+            invalid.violations()[0]
+        );
+    }
+}
@@ -0,0 +1,43 @@
+use aoc2023_day_06::*;
+
+fn main() {
+    // Run registered benchmarks.
+    divan::main();
+}
+
+#[divan::bench]
+fn part1() {
+    part1::process(divan::black_box(include_str!(
+        "../input1.txt",
+    )))
+    .unwrap();
+}
+
+#[divan::bench]
+fn part2() {
+    part2::process(divan::black_box(include_str!(
+        "../input2.txt",
+    )))
+    .unwrap();
+}
+/// The races' numbers, read like part 1 did before `aoc_common::parse`
+#[divan::bench]
+fn parse_str() -> u32 {
+    records(divan::black_box(include_str!("../input1.txt")))
+        .map(|n| n.parse::<u32>().unwrap())
+        .sum()
+}
+
+/// The same numbers read by the shared parser
+#[divan::bench]
+fn parse_shared() -> u32 {
+    records(divan::black_box(include_str!("../input1.txt")))
+        .map(|n| aoc_common::parse::decimal::<u32>(n).unwrap())
+        .sum()
+}
+
+fn records(input: &str) -> impl Iterator<Item = &str> {
+    input
+        .lines()
+        .flat_map(|line| line.split_once(':').unwrap().1.split_ascii_whitespace())
+}
@@ -0,0 +1,187 @@
+use crate::custom_error::AocError;
+use crate::schema;
+use aoc_common::lenient::Lenient;
+use aoc_common::parse;
+use itertools::Itertools;
+/*
+Distance can be calculated as
+    d = (t - b) * b
+where t = time and b = how long button is held
+
+Replacing distance (d) with distance record (r) we have the following inequality
+    r < (t - b) * b
+where we want to find the range of (b) where this holds.
+
+Rearrange into a quadratic equation
+    0 > b^2 - tb + r
+Then applying quadratic formula we get
+    b < 0.5 * (t + sqrt(t^2 - 4r))
+    b > 0.5 * (t - sqrt(t^2 - 4r))
+
+So each race becomes a simple constant time calculation
+*/
+pub fn process(input: &str) -> miette::Result<String, AocError> {
+    schema::check(input)?;
+    // Get iterators over times and distances
+    let (times, distances) = input
+        .lines()
+        .map(|line| {
+            line.split_once(':')
+                .unwrap()
+                .1
+                .split_ascii_whitespace()
+                .map(parse::decimal::<u32>)
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .collect_tuple()
+        .unwrap();
+
+    let races = times?.into_iter().zip(distances?);
+    let result = races.fold(1, |acc, (time, distance)| {
+        // Find range and collect result in `acc`
+        let discriminant = f32::sqrt((time * time - 4 * distance) as f32);
+        let range_start = (0.5 * (time as f32 - discriminant)).floor() as u32 + 1;
+        let range_end = (0.5 * (time as f32 + discriminant)).ceil() as u32;
+        acc * (range_end - range_start)
+    });
+
+    Ok(result.to_string())
+}
+
+/// Same as [`process`] but skips the races whose time or distance isn't a number, returning the
+/// answer over the other races in [`AocError::Partial`]
+pub fn process_lenient(input: &str) -> miette::Result<String, AocError> {
+    let (races, lenient) = valid_races(input);
+    let answer = match races {
+        Some(races) => process(&races)?,
+        None => "0".to_string(),
+    };
+    Ok(lenient.finish(answer)?)
+}
+
+/// The input without the races that have a malformed or missing time or distance, `None` if that
+/// leaves no race
+pub(crate) fn valid_races(input: &str) -> (Option<String>, Lenient<'_>) {
+    let mut lenient = Lenient::new(input);
+    let mut lines = input.lines();
+    let mut columns = [vec![], vec![]];
+    for (index, prefix) in ["Time:", "Distance:"].into_iter().enumerate() {
+        // A missing line is labelled at the end of the input
+        let line = lines.next().unwrap_or(&input[input.len()..]);
+        match line.strip_prefix(prefix) {
+            Some(values) => columns[index].extend(values.split_ascii_whitespace()),
+            None => {
+                let violation = (lenient.span(line), format!("should start with `{prefix}`"));
+                lenient.skip(format!("skipped line {}", index + 1), vec![violation]);
+            }
+        }
+    }
+
+    let [times, distances] = columns;
+    let mut valid = [String::from("Time:"), String::from("Distance:")];
+    // Of the races kept so far, part 2 joins them into one number
+    let mut digits = [0, 0];
+    for race in 0..times.len().max(distances.len()) {
+        let values = [times.get(race).copied(), distances.get(race).copied()];
+        let mut violations = vec![];
+        for (index, what) in ["time", "distance"].into_iter().enumerate() {
+            match values[index] {
+                Some(value) if parse::decimal::<u32>(value).is_err() => {
+                    let message = format!("the {what} should be a number of at most 32 bits");
+                    violations.push((lenient.span(value), message));
+                }
+                Some(value) if digits[index] + value.len() > schema::JOINED_DIGITS => {
+                    let message = format!(
+                        "joined to the races before, the {what} should have at most {} digits",
+                        schema::JOINED_DIGITS
+                    );
+                    violations.push((lenient.span(value), message));
+                }
+                Some(_) => {}
+                None => {
+                    let other = values[1 - index].unwrap();
+                    violations.push((lenient.span(other), format!("this race has no {what}")));
+                }
+            }
+        }
+        if violations.is_empty() {
+            for ((line, digits), value) in valid.iter_mut().zip(&mut digits).zip(values) {
+                *line += " ";
+                *line += value.unwrap();
+                *digits += value.unwrap().len();
+            }
+        } else {
+            lenient.skip(format!("skipped race {}", race + 1), violations);
+        }
+    }
+    let has_races = valid[0] != "Time:";
+    (has_races.then(|| valid.join("\n")), lenient)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_process() -> miette::Result<()> {
+        let input = "\
+Time:      7  15   30
+Distance:  9  40  200";
+        assert_eq!("288", process(input)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_process_lenient() -> miette::Result<()> {
+        let input = "\
+Time:      7  15   30  x
+Distance:  9  4O  200";
+        let Err(AocError::Partial(partial)) = process_lenient(input) else {
+            panic!("races 2 and 4 should be skipped");
+        };
+        assert_eq!("36", partial.answer());
+        let violations = partial
+            .skipped()
+            .iter()
+            .map(|skipped| skipped.violations().to_vec())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                vec![(
+                    39..41,
+                    "the distance should be a number of at most 32 bits".to_string()
+                )],
+                vec![
+                    (
+                        23..24,
+                        "the time should be a number of at most 32 bits".to_string()
+                    ),
+                    (23..24, "this race has no distance".to_string()),
+                ],
+            ],
+            violations
+        );
+        insta::assert_debug_snapshot!("valid_races", valid_races(input).0);
+        let fixed = input.replace(" x", "").replace('O', "0");
+        assert_eq!("288", process_lenient(&fixed)?);
+
+        // Nothing left to solve
+        let Err(AocError::Partial(partial)) = process_lenient("Time: x\nDistance: y") else {
+            panic!("every race should be skipped");
+        };
+        assert_eq!("0", partial.answer());
+        let Err(AocError::Partial(partial)) = process_lenient("Time: 7") else {
+            panic!("the distances should be missing");
+        };
+        assert_eq!((7..7), partial.skipped()[0].violations()[0].0);
+
+        // Part 2 would join the times into 20 digits
+        let input = "Time: 0007 0007 0007 0007 0007\nDistance: 9 9 9 9 9";
+        let Err(AocError::Partial(partial)) = process_lenient(input) else {
+            panic!("race 5 should be skipped");
+        };
+        assert_eq!("256", partial.answer());
+        assert_eq!((26..30), partial.skipped()[0].violations()[0].0);
+        Ok(())
+    }
+}
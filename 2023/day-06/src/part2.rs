@@ -1,7 +1,10 @@
 use crate::custom_error::AocError;
+use crate::part1::valid_races;
+use crate::schema;
 use itertools::Itertools;
 
 pub fn process(input: &str) -> miette::Result<String, AocError> {
+    schema::check(input)?;
     // Get time and distance
     let (time, distance) = input
         .lines()
@@ -26,6 +29,17 @@ pub fn process(input: &str) -> miette::Result<String, AocError> {
     Ok(result.to_string())
 }
 
+/// Same as [`process`] but leaves out the races whose time or distance isn't a number before
+/// joining the digits, returning that answer in [`AocError::Partial`]
+pub fn process_lenient(input: &str) -> miette::Result<String, AocError> {
+    let (races, lenient) = valid_races(input);
+    let answer = match races {
+        Some(races) => process(&races)?,
+        None => "0".to_string(),
+    };
+    Ok(lenient.finish(answer)?)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -38,4 +52,12 @@ Distance:  9  40  200";
         assert_eq!("71503", process(input)?);
         Ok(())
     }
+
+    #[test]
+    fn test_process_lenient() {
+        let Err(AocError::Partial(partial)) = process_lenient("Time: x\nDistance: y") else {
+            panic!("every race should be skipped");
+        };
+        assert_eq!("0", partial.answer());
+    }
 }
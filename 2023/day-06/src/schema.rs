@@ -0,0 +1,98 @@
+//! Shape of the race records
+
+use aoc_common::schema::{Checker, InvalidInput};
+
+pub const SHAPE: &str = "a `Time:` line and a `Distance:` line with as many numbers as each other";
+
+/// Digits a line's numbers may have in all, so that part 2 joining them always fits in a u64
+pub const JOINED_DIGITS: usize = 19;
+
+pub fn check(input: &str) -> Result<(), InvalidInput> {
+    let mut checker = Checker::new(input, SHAPE);
+    let mut lines = checker.lines();
+    let mut counts = vec![];
+    for prefix in ["Time:", "Distance:"] {
+        // A missing line is labelled at the end of the input
+        let line = lines.next().unwrap_or(&input[input.len()..]);
+        let Some(numbers) = checker.prefixed(line, prefix) else {
+            continue;
+        };
+        let numbers = numbers.split_ascii_whitespace().collect::<Vec<_>>();
+        for number in &numbers {
+            checker.bounded(number, "the race record", u32::MAX.into());
+        }
+        checker.expect(
+            !numbers.is_empty(),
+            line,
+            "there should be at least one race",
+        );
+        let digits = numbers.iter().map(|number| number.len()).sum::<usize>();
+        checker.expect(
+            digits <= JOINED_DIGITS,
+            line,
+            format!(
+                "the numbers should have at most {JOINED_DIGITS} digits in all, part 2 joins them"
+            ),
+        );
+        counts.push((line, numbers.len()));
+    }
+    if let [(_, times), (distances_line, distances)] = counts[..] {
+        checker.expect(
+            times == distances,
+            distances_line,
+            format!("there should be {times} distances, one per time"),
+        );
+    }
+    if let Some(line) = lines.next() {
+        checker.expect(false, line, "there should only be two lines");
+    }
+    checker.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check() {
+        assert!(check("Time:      7  15   30\nDistance:  9  40  200\n").is_ok());
+        let invalid = check("Time:      7  15   30\nDistance:  9  40\n").unwrap_err();
+        assert_eq!(
+            [(
+                22..38,
+                "there should be 3 distances, one per time".to_string()
+            )],
+            invalid.violations()
+        );
+        assert_eq!(
+            [(0..5, "should start with `Time:`".to_string())],
+            check("Times: 7\nDistance: 9").unwrap_err().violations()
+        );
+        assert_eq!(
+            [(7..7, "should start with `Distance:`".to_string())],
+            check("Time: 7").unwrap_err().violations()
+        );
+        assert_eq!(3, check("").unwrap_err().violations().len());
+        assert_eq!(
+            [
+                (0..5, "there should be at least one race".to_string()),
+                (6..15, "there should be at least one race".to_string())
+            ],
+            check("Time:\nDistance:").unwrap_err().violations()
+        );
+        // The solvers only split on ASCII whitespace
+        assert_eq!(
+            [(6..8, "the race record should be a number".to_string())],
+            check("Time: 7\u{b}\nDistance: 9").unwrap_err().violations()
+        );
+        assert_eq!(
+            [(
+                0..29,
+                "the numbers should have at most 19 digits in all, part 2 joins them".to_string()
+            )],
+            check("Time: 1234567890 1234567890 7\nDistance: 1 1 9")
+                .unwrap_err()
+                .violations()
+        );
+    }
+}
@@ -1,4 +1,9 @@
+use aoc_common::lenient::Lenient;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
 use crate::custom_error::AocError;
+use crate::schema;
 
 use itertools::Itertools;
 
@@ -7,7 +12,7 @@ use std::str::FromStr;
 
 /// In increasing strength order
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
-enum Category {
+pub enum Category {
     HighCard,
     OnePair,
     TwoPair,
@@ -19,12 +24,22 @@ enum Category {
 
 /// Store each hand-bid pair with corresponding category
 #[derive(Debug, Eq)]
-struct HandBid {
+pub struct HandBid {
     hand: [u8; 5],
     bid: u32,
     category: Category,
 }
 
+impl HandBid {
+    pub fn bid(&self) -> u32 {
+        self.bid
+    }
+
+    pub fn category(&self) -> &Category {
+        &self.category
+    }
+}
+
 /// Calculate and returns category based on hand
 fn get_category(hand: &[u8; 5]) -> Category {
     // Store count for each card value. (index + 2) corresponds to card value.
@@ -55,7 +70,7 @@ fn get_category(hand: &[u8; 5]) -> Category {
 }
 
 #[derive(Debug, PartialEq, Eq)]
-struct ParseHandBidError;
+pub struct ParseHandBidError;
 
 // To parse from &str to HandBid
 impl FromStr for HandBid {
@@ -136,8 +151,8 @@ impl PartialEq for HandBid {
     }
 }
 
-pub fn process(input: &'static str) -> miette::Result<String, AocError> {
-    // Parse lines and sort in order of increasing hand strength
+/// Parses lines and sorts them in order of increasing hand strength, i.e. by rank
+fn ranked_hands(input: &str) -> Vec<HandBid> {
     let mut hand_bids = input
         .lines()
         .map(|line| {
@@ -146,8 +161,26 @@ pub fn process(input: &'static str) -> miette::Result<String, AocError> {
         })
         .collect::<Vec<_>>();
     hand_bids.sort_unstable();
+    hand_bids
+}
+
+/// [`ranked_hands`] with the lines parsed and sorted on rayon's thread pool
+#[cfg(feature = "parallel")]
+fn par_ranked_hands(input: &str) -> Vec<HandBid> {
+    let mut hand_bids = input
+        .par_lines()
+        .map(|line| {
+            line.parse::<HandBid>()
+                .expect("Hand should contain 5 valid cards and bid should be a positive number")
+        })
+        .collect::<Vec<_>>();
+    hand_bids.par_sort_unstable();
+    hand_bids
+}
 
-    let result = hand_bids
+pub fn process(input: &str) -> miette::Result<String, AocError> {
+    schema::check(input)?;
+    let result = ranked_hands(input)
         .iter()
         .enumerate()
         .fold(0, |acc, (rank, handbid)| {
@@ -157,6 +190,27 @@ pub fn process(input: &'static str) -> miette::Result<String, AocError> {
     Ok(result.to_string())
 }
 
+/// Same as [`process`] with the hands parsed, sorted and scored on rayon's thread pool
+#[cfg(feature = "parallel")]
+pub fn process_parallel(input: &str) -> miette::Result<String, AocError> {
+    schema::check(input)?;
+    let result = par_ranked_hands(input)
+        .par_iter()
+        .enumerate()
+        .map(|(rank, handbid)| handbid.bid as usize * (rank + 1))
+        .sum::<usize>();
+
+    Ok(result.to_string())
+}
+
+/// Same as [`process`] but skips the lines that don't have the input's shape, see
+/// [`crate::schema`], returning the answer over the other lines in [`AocError::Partial`]
+pub fn process_lenient(input: &str) -> miette::Result<String, AocError> {
+    let lines = Lenient::lines(input, schema::check);
+    let answer = lines.answer(process)?;
+    Ok(lines.finish(answer)?)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -170,6 +224,31 @@ KK677 28
 KTJJT 220
 QQQJA 483";
         assert_eq!("6440", process(input)?);
+        #[cfg(feature = "parallel")]
+        assert_eq!("6440", process_parallel(input)?);
+        insta::assert_debug_snapshot!("ranked_hands", ranked_hands(input));
         Ok(())
     }
+
+    #[test]
+    fn test_process_lenient() {
+        let input = "\
+32T3K 765
+T55J5 684
+KK677 28
+32T3 100
+KTJJT 220
+QQQJA 483";
+        let Err(AocError::Partial(partial)) = process_lenient(input) else {
+            panic!("line 4 should be skipped");
+        };
+        assert_eq!("6440", partial.answer());
+        assert_eq!(1, partial.skipped().len());
+
+        // Nothing left to solve
+        let Err(AocError::Partial(partial)) = process_lenient("32T3 100\n32T3K") else {
+            panic!("every line should be skipped");
+        };
+        assert_eq!("0", partial.answer());
+    }
 }
@@ -1,4 +1,9 @@
+use aoc_common::lenient::Lenient;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
 use crate::custom_error::AocError;
+use crate::schema;
 
 use itertools::Itertools;
 
@@ -159,8 +164,8 @@ impl PartialEq for HandBid {
     }
 }
 
-pub fn process(input: &'static str) -> miette::Result<String, AocError> {
-    // Parse lines and sort in order of increasing hand strength
+/// Parses lines and sorts them in order of increasing hand strength, i.e. by rank
+fn ranked_hands(input: &str) -> Vec<HandBid> {
     let mut hand_bids = input
         .lines()
         .map(|line| {
@@ -169,8 +174,26 @@ pub fn process(input: &'static str) -> miette::Result<String, AocError> {
         })
         .collect::<Vec<_>>();
     hand_bids.sort_unstable();
+    hand_bids
+}
 
-    let result = hand_bids
+/// [`ranked_hands`] with the lines parsed and sorted on rayon's thread pool
+#[cfg(feature = "parallel")]
+fn par_ranked_hands(input: &str) -> Vec<HandBid> {
+    let mut hand_bids = input
+        .par_lines()
+        .map(|line| {
+            line.parse::<HandBid>()
+                .expect("Hand should contain 5 valid cards and bid should be a positive number")
+        })
+        .collect::<Vec<_>>();
+    hand_bids.par_sort_unstable();
+    hand_bids
+}
+
+pub fn process(input: &str) -> miette::Result<String, AocError> {
+    schema::check(input)?;
+    let result = ranked_hands(input)
         .iter()
         .enumerate()
         .fold(0, |acc, (rank, handbid)| {
@@ -180,6 +203,27 @@ pub fn process(input: &'static str) -> miette::Result<String, AocError> {
     Ok(result.to_string())
 }
 
+/// Same as [`process`] with the hands parsed, sorted and scored on rayon's thread pool
+#[cfg(feature = "parallel")]
+pub fn process_parallel(input: &str) -> miette::Result<String, AocError> {
+    schema::check(input)?;
+    let result = par_ranked_hands(input)
+        .par_iter()
+        .enumerate()
+        .map(|(rank, handbid)| handbid.bid as usize * (rank + 1))
+        .sum::<usize>();
+
+    Ok(result.to_string())
+}
+
+/// Same as [`process`] but skips the lines that don't have the input's shape, see
+/// [`crate::schema`], returning the answer over the other lines in [`AocError::Partial`]
+pub fn process_lenient(input: &str) -> miette::Result<String, AocError> {
+    let lines = Lenient::lines(input, schema::check);
+    let answer = lines.answer(process)?;
+    Ok(lines.finish(answer)?)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -193,6 +237,9 @@ KK677 28
 KTJJT 220
 QQQJA 483";
         assert_eq!("5905", process(input)?);
+        #[cfg(feature = "parallel")]
+        assert_eq!("5905", process_parallel(input)?);
+        insta::assert_debug_snapshot!("ranked_hands", ranked_hands(input));
         Ok(())
     }
 }
@@ -0,0 +1,43 @@
+//! Shape of the hands
+
+use aoc_common::schema::{Checker, InvalidInput};
+
+pub const SHAPE: &str = "lines like `32T3K 765`, a hand of 5 cards out of `23456789TJQKA` and its \
+                         bid";
+
+pub fn check(input: &str) -> Result<(), InvalidInput> {
+    let mut checker = Checker::new(input, SHAPE);
+    for line in checker.lines() {
+        let Some((hand, bid)) = checker.split(line, " ") else {
+            continue;
+        };
+        checker.expect(hand.len() == 5, hand, "a hand should have 5 cards");
+        checker.chars(hand, |card| "23456789TJQKA".contains(card), "unknown card");
+        checker.bounded(bid, "the bid", u32::MAX.into());
+    }
+    checker.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check() {
+        assert!(check("32T3K 765\nT55J5 684\nKK677 28\n").is_ok());
+        let invalid = check("32T3 765\nT55j5 684\nKK677 -1").unwrap_err();
+        assert_eq!(
+            [
+                (0..4, "a hand should have 5 cards"),
+                (12..13, "unknown card"),
+                (25..27, "the bid should be a number"),
+            ]
+            .map(|(span, message)| (span, message.to_string())),
+            invalid.violations()
+        );
+        assert_eq!(
+            [(6..16, "the bid should be at most 4294967295".to_string())],
+            check("32T3K 4294967296").unwrap_err().violations()
+        );
+    }
+}
@@ -0,0 +1,54 @@
+use std::hash::BuildHasher;
+
+use aoc2023_day_08::*;
+use aoc_common::cancel::CancelToken;
+use aoc_common::hash::{AHashState, FxState, SeaState, StdState};
+
+fn main() {
+    // Run registered benchmarks.
+    divan::main();
+}
+
+#[divan::bench]
+fn part1() {
+    part1::process(divan::black_box(include_str!(
+        "../input.txt",
+    )))
+    .unwrap();
+}
+
+#[divan::bench]
+fn part2() {
+    part2::process(divan::black_box(include_str!(
+        "../input.txt",
+    )))
+    .unwrap();
+}
+#[divan::bench(types = [StdState, AHashState, FxState, SeaState])]
+fn part1_hasher<S: BuildHasher + Default>() {
+    part1::process_with_hasher::<S>(
+        divan::black_box(include_str!("../input.txt")),
+        &CancelToken::new(),
+    )
+    .unwrap();
+}
+
+#[divan::bench(types = [StdState, AHashState, FxState, SeaState])]
+fn part2_hasher<S: BuildHasher + Default>() {
+    part2::process_with_hasher::<S>(
+        divan::black_box(include_str!("../input.txt")),
+        &CancelToken::new(),
+    )
+    .unwrap();
+}
+
+#[cfg(feature = "parallel")]
+#[divan::bench(args = [1, 2, 4, 8])]
+fn part2_parallel(bencher: divan::Bencher, threads: usize) {
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(threads)
+        .build()
+        .unwrap();
+    let input = include_str!("../input.txt");
+    bencher.bench(|| pool.install(|| part2::process_parallel(divan::black_box(input)).unwrap()));
+}
@@ -0,0 +1,116 @@
+use std::collections::HashMap;
+use std::hash::BuildHasher;
+
+use aoc_common::cancel::CancelToken;
+use aoc_common::hash::FastState;
+
+use crate::custom_error::AocError;
+use crate::schema;
+
+pub fn process(input: &str) -> miette::Result<String, AocError> {
+    process_cancellable(input, &CancelToken::new())
+}
+
+/// Same as [`process`] but gives up with [`AocError::Cancelled`] once `token` is cancelled. A node
+/// map without a path to "ZZZ" would otherwise loop forever.
+pub fn process_cancellable(
+    input: &str,
+    token: &CancelToken,
+) -> miette::Result<String, AocError> {
+    process_with_hasher::<FastState>(input, token)
+}
+
+/// Same as [`process_cancellable`] with the node map hashed by `S`, for comparing hashers
+pub fn process_with_hasher<S: BuildHasher + Default>(
+    input: &str,
+    token: &CancelToken,
+) -> miette::Result<String, AocError> {
+    schema::check(input)?;
+    // Collect instructions
+    let mut lines = input.lines();
+    let steps = lines.next().unwrap();
+
+    lines.next();
+
+    // Collect nodes into hashmap for fast lookup
+    let node_map = lines
+        .map(|line| {
+            let node = &line[0..3];
+            let node_left = &line[7..10];
+            let node_right = &line[12..15];
+            (node, (node_left, node_right))
+        })
+        .collect::<HashMap<_, _, S>>();
+
+    // Start at "AAA"
+    let mut current_node = *node_map.get("AAA").ok_or(AocError::NoStart("AAA"))?;
+    let mut steps_count: u64 = 0;
+
+    for step in steps.chars().cycle() {
+        steps_count += 1;
+        if CancelToken::should_poll(steps_count) {
+            token.check()?;
+        }
+        let next_key = match step {
+            'L' => current_node.0,
+            'R' => current_node.1,
+            _ => panic!("Invalid instruction"),
+        };
+        // Stop at "ZZZ"
+        if next_key == "ZZZ" {
+            break;
+        }
+        current_node = node_map[next_key];
+    }
+
+    Ok(steps_count.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_process() -> miette::Result<()> {
+        let input1 = "\
+RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)";
+
+        let input2 = "\
+LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)";
+        assert_eq!("2", process(input1)?);
+        assert_eq!("6", process(input2)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_process_cancelled() {
+        // "ZZZ" is unreachable
+        let input = "\
+L
+
+AAA = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)";
+        let token = CancelToken::new();
+        token.cancel();
+        let result = process_cancellable(input, &token);
+        assert!(matches!(result, Err(AocError::Cancelled(_))));
+    }
+
+    #[test]
+    fn test_process_no_start() {
+        let result = process("L\n\nZZZ = (ZZZ, ZZZ)");
+        assert!(matches!(result, Err(AocError::NoStart("AAA"))));
+    }
+}
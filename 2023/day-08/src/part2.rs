@@ -0,0 +1,154 @@
+use aoc_common::cancel::CancelToken;
+use aoc_common::hash::FastState;
+use num::integer::lcm;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use std::collections::HashMap;
+use std::hash::BuildHasher;
+
+use crate::custom_error::AocError;
+use crate::schema;
+
+pub fn process(input: &str) -> miette::Result<String, AocError> {
+    process_cancellable(input, &CancelToken::new())
+}
+
+/// Same as [`process`] but gives up with [`AocError::Cancelled`] once `token` is cancelled. A ghost
+/// that never reaches a "..Z" node would otherwise loop forever.
+pub fn process_cancellable(
+    input: &str,
+    token: &CancelToken,
+) -> miette::Result<String, AocError> {
+    process_with_hasher::<FastState>(input, token)
+}
+
+/// Same as [`process_cancellable`] with the node map hashed by `S`, for comparing hashers
+pub fn process_with_hasher<S: BuildHasher + Default>(
+    input: &str,
+    token: &CancelToken,
+) -> miette::Result<String, AocError> {
+    schema::check(input)?;
+    let (steps, start_nodes, node_map) = parse::<S>(input);
+
+    // Count steps to find end node for each individual start node, then find LCM of those counts.
+    // Iterating simultaneously would be too slow (20 trillion+ iterations).
+    let lcm = start_nodes
+        .iter()
+        .map(|&start_node| ghost_steps(steps, start_node, &node_map, token))
+        .reduce(|acc, count| Ok(lcm(acc?, count?)))
+        .ok_or(AocError::NoStart("..A"))??;
+
+    Ok(lcm.to_string())
+}
+
+/// Same as [`process`] with each ghost walking on rayon's thread pool
+#[cfg(feature = "parallel")]
+pub fn process_parallel(input: &str) -> miette::Result<String, AocError> {
+    process_parallel_cancellable(input, &CancelToken::new())
+}
+
+/// Same as [`process_cancellable`] with each ghost walking on rayon's thread pool
+#[cfg(feature = "parallel")]
+pub fn process_parallel_cancellable(
+    input: &str,
+    token: &CancelToken,
+) -> miette::Result<String, AocError> {
+    schema::check(input)?;
+    let (steps, start_nodes, node_map) = parse::<FastState>(input);
+
+    let lcm = start_nodes
+        .par_iter()
+        .map(|&start_node| ghost_steps(steps, start_node, &node_map, token))
+        .try_reduce_with(|acc, count| Ok(lcm(acc, count)))
+        .ok_or(AocError::NoStart("..A"))??;
+
+    Ok(lcm.to_string())
+}
+
+/// The names of a node's left and right nodes
+type Node<'a> = (&'a str, &'a str);
+type NodeMap<'a, S> = HashMap<&'a str, Node<'a>, S>;
+
+/// The instructions, the left and right nodes of every "..A" node, and the nodes by name
+fn parse<S: BuildHasher + Default>(input: &str) -> (&str, Vec<Node<'_>>, NodeMap<'_, S>) {
+    let mut lines = input.lines();
+    
+    // Collect instructions
+    let steps = lines.next().unwrap();
+
+    lines.next();
+    let mut start_nodes = vec![];
+
+    // Collect nodes into hashmap for fast lookup
+    let node_map = lines
+        .map(|line| {
+            let node = &line[0..3];
+            let node_left = &line[7..10];
+            let node_right = &line[12..15];
+            // Find all starting nodes
+            if node.ends_with('A') {
+                start_nodes.push((node_left, node_right));
+            }
+            (node, (node_left, node_right))
+        })
+        .collect::<HashMap<_, _, S>>();
+
+    (steps, start_nodes, node_map)
+}
+
+/// How many steps the ghost starting next to `start_node` takes to reach a "..Z" node
+fn ghost_steps<S: BuildHasher>(
+    steps: &str,
+    start_node: Node<'_>,
+    node_map: &NodeMap<'_, S>,
+    token: &CancelToken,
+) -> Result<u64, AocError> {
+    let mut current_node = start_node;
+    let mut steps_count: u64 = 0;
+    for step in steps.chars().cycle() {
+        steps_count += 1;
+        if CancelToken::should_poll(steps_count) {
+            token.check()?;
+        }
+        let next_key = match step {
+            'L' => current_node.0,
+            'R' => current_node.1,
+            _ => panic!("Invalid instruction"),
+        };
+        if next_key.ends_with('Z') {
+            break;
+        }
+        current_node = node_map[next_key];
+    }
+    Ok(steps_count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_process() -> miette::Result<()> {
+        let input = "\
+LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)";
+        assert_eq!("6", process(input)?);
+        #[cfg(feature = "parallel")]
+        assert_eq!("6", process_parallel(input)?);
+
+        // Sorted, as the map iterates in hash order
+        let (steps, start_nodes, node_map) = parse::<FastState>(input);
+        let mut nodes = node_map.into_iter().collect::<Vec<_>>();
+        nodes.sort_unstable();
+        insta::assert_debug_snapshot!("parsed", (steps, start_nodes, nodes));
+        Ok(())
+    }
+}
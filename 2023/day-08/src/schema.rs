@@ -0,0 +1,87 @@
+//! Shape of the maps
+
+use std::collections::HashSet;
+
+use aoc_common::schema::{Checker, InvalidInput};
+
+pub const SHAPE: &str = "a line of `L` and `R` instructions, a blank line, then nodes like \
+                         `AAA = (BBB, CCC)` named by 3 uppercase letters or digits, naming only \
+                         nodes of the map";
+
+pub fn check(input: &str) -> Result<(), InvalidInput> {
+    let mut checker = Checker::new(input, SHAPE);
+    let mut lines = checker.lines();
+    let instructions = lines.next().unwrap_or_default();
+    checker.chars(
+        instructions,
+        |c| c == 'L' || c == 'R',
+        "should be `L` or `R`",
+    );
+    let blank = lines.next().unwrap_or_default();
+    checker.expect(
+        blank.is_empty(),
+        blank,
+        "the instructions should be followed by a blank line",
+    );
+
+    let is_name = |name: &str| {
+        name.len() == 3
+            && name
+                .bytes()
+                .all(|b| b.is_ascii_uppercase() || b.is_ascii_digit())
+    };
+    let mut nodes = vec![];
+    for line in lines {
+        let ok = line.len() == 16
+            && line.is_ascii()
+            && [&line[0..3], &line[7..10], &line[12..15]]
+                .into_iter()
+                .all(is_name)
+            && [&line[3..7], &line[10..12], &line[15..]] == [" = (", ", ", ")"];
+        if checker.expect(ok, line, "should look like `AAA = (BBB, CCC)`") {
+            nodes.push(line);
+        }
+    }
+
+    // The solvers look up every node they're sent to
+    let names = nodes.iter().map(|node| &node[0..3]).collect::<HashSet<_>>();
+    for node in nodes {
+        for next in [&node[7..10], &node[12..15]] {
+            checker.expect(
+                names.contains(next),
+                next,
+                format!("the map has no `{next}` node"),
+            );
+        }
+    }
+    checker.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check() {
+        assert!(check("LLR\n\nAAA = (BBB, BBB)\nBBB = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)\n").is_ok());
+        assert!(check(
+            "LR\n\n11A = (11B, XXX)\n11B = (XXX, 11Z)\n11Z = (11B, XXX)\nXXX = (XXX, XXX)\n"
+        )
+        .is_ok());
+        let invalid = check("LR\n\n11A = (11B, XXX)\nXXX = (XXX, XXX)\n").unwrap_err();
+        assert_eq!(
+            [(11..14, "the map has no `11B` node".to_string())],
+            invalid.violations()
+        );
+        let invalid = check("LxR\nAAA = (BBB, BBB)\nBBB = (AAA, zzz)").unwrap_err();
+        assert_eq!(
+            [
+                (1..2, "should be `L` or `R`"),
+                (4..20, "the instructions should be followed by a blank line"),
+                (21..37, "should look like `AAA = (BBB, CCC)`"),
+            ]
+            .map(|(span, message)| (span, message.to_string())),
+            invalid.violations()
+        );
+    }
+}
@@ -0,0 +1,134 @@
+use aoc2023_day_09::*;
+use aoc_common::isa::Isa;
+
+fn main() {
+    // Run registered benchmarks.
+    divan::main();
+}
+
+#[divan::bench]
+fn part1() {
+    part1::process(divan::black_box(include_str!(
+        "../input.txt",
+    )))
+    .unwrap();
+}
+
+#[divan::bench]
+fn part1_optimized() {
+    part1::process_optimized(divan::black_box(include_str!(
+        "../input.txt",
+    )))
+    .unwrap();
+}
+
+#[divan::bench]
+fn part1_optimized_dft() {
+    part1::process_optimized_dft(divan::black_box(include_str!(
+        "../input.txt",
+    )))
+    .unwrap();
+}
+
+#[divan::bench]
+fn part1_bc() {
+    part1::process_bc(divan::black_box(include_str!(
+        "../input.txt",
+    )))
+    .unwrap();
+}
+
+#[divan::bench]
+fn part2() {
+    part2::process(divan::black_box(include_str!(
+        "../input.txt",
+    )))
+    .unwrap();
+}
+#[divan::bench]
+fn part2_dft() {
+    part2::process_dft(divan::black_box(include_str!(
+        "../input.txt",
+    )))
+    .unwrap();
+}
+
+#[divan::bench]
+fn part2_bc() {
+    part2::process_bc(divan::black_box(include_str!(
+        "../input.txt",
+    )))
+    .unwrap();
+}
+
+#[divan::bench(args = Isa::supported())]
+fn part1_simd(isa: Isa) {
+    simd::next_values_sum(isa, divan::black_box(include_bytes!("../input.txt")));
+}
+
+#[divan::bench(args = Isa::supported())]
+fn part2_simd(isa: Isa) {
+    simd::previous_values_sum(isa, divan::black_box(include_bytes!("../input.txt")));
+}
+
+#[cfg(feature = "parallel")]
+#[divan::bench(args = [1, 2, 4, 8])]
+fn part1_parallel(bencher: divan::Bencher, threads: usize) {
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(threads)
+        .build()
+        .unwrap();
+    let input = include_str!("../input.txt");
+    bencher.bench(|| pool.install(|| part1::process_parallel(divan::black_box(input)).unwrap()));
+}
+
+#[cfg(feature = "parallel")]
+#[divan::bench(args = [1, 2, 4, 8])]
+fn part2_parallel(bencher: divan::Bencher, threads: usize) {
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(threads)
+        .build()
+        .unwrap();
+    let input = include_str!("../input.txt");
+    bencher.bench(|| pool.install(|| part2::process_parallel(divan::black_box(input)).unwrap()));
+}
+
+/// The histories read with `str::parse`, as the string solvers did before `aoc_common::parse`
+#[divan::bench]
+fn parse_str() -> i32 {
+    divan::black_box(include_str!("../input.txt"))
+        .lines()
+        .flat_map(|line| {
+            line.split_ascii_whitespace()
+                .map(|num| num.parse::<i32>().expect("valid number"))
+        })
+        .sum()
+}
+
+/// The histories read with the digit fold the bytes core had before `aoc_common::parse`
+#[divan::bench]
+fn parse_fold() -> i64 {
+    divan::black_box(include_bytes!("../input.txt"))
+        .split(|c| c.is_ascii_whitespace())
+        .filter(|num| !num.is_empty())
+        .map(|num| {
+            let fold = |digits: &[u8]| {
+                digits
+                    .iter()
+                    .fold(0, |acc, &d| acc * 10 + (d - b'0') as i64)
+            };
+            match num.split_first() {
+                Some((b'-', digits)) => -fold(digits),
+                _ => fold(num),
+            }
+        })
+        .sum()
+}
+
+/// The histories read by the shared parser both use now
+#[divan::bench]
+fn parse_shared() -> i64 {
+    aoc_common::parse::numbers::<i64>(divan::black_box(include_str!("../input.txt")))
+        .map(|num| num.expect("valid number"))
+        .sum()
+}
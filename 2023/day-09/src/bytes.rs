@@ -0,0 +1,63 @@
+//! Allocation-free cores of the binomial coefficient solvers, built without `std` too.
+//!
+//! The value after a history of n values is the sum of its values weighted by binomial
+//! coefficients of alternating sign. For example for a row length of 5:
+//!     prediction = a - 5b + 10c - 10d + 5e
+//! The coefficients are precomputed for the puzzle input's histories of [`HISTORY_LEN`] values, so
+//! each line is parsed once and weighted as it goes. [`crate::simd`] takes any length.
+
+use aoc_common::parse;
+
+/// Values in each history of the puzzle input
+pub const HISTORY_LEN: usize = 21;
+
+/// (-1)^(HISTORY_LEN - 1 - i) * C(HISTORY_LEN, i)
+const NEXT_COEFFICIENTS: [i64; HISTORY_LEN] = [
+    1, -21, 210, -1330, 5985, -20349, 54264, -116280, 203490, -293930, 352716, -352716, 293930,
+    -203490, 116280, -54264, 20349, -5985, 1330, -210, 21,
+];
+
+/// Going backwards the coefficients are shifted by one: (-1)^i * C(HISTORY_LEN, i + 1)
+const PREVIOUS_COEFFICIENTS: [i64; HISTORY_LEN] = [
+    21, -210, 1330, -5985, 20349, -54264, 116280, -203490, 293930, -352716, 352716, -293930,
+    203490, -116280, 54264, -20349, 5985, -1330, 210, -21, 1,
+];
+
+/// Sum of the values extrapolated after each history, one per line
+pub fn next_values_sum(input: &[u8]) -> i64 {
+    weighted_sum(input, &NEXT_COEFFICIENTS)
+}
+
+/// Sum of the values extrapolated before each history, one per line
+pub fn previous_values_sum(input: &[u8]) -> i64 {
+    weighted_sum(input, &PREVIOUS_COEFFICIENTS)
+}
+
+fn weighted_sum(input: &[u8], coefficients: &[i64; HISTORY_LEN]) -> i64 {
+    input
+        .split(|&c| c == b'\n')
+        .map(|line| {
+            parse::numbers::<i64>(line)
+                .map(|value| value.expect("valid number"))
+                .enumerate()
+                .fold(0, |acc, (i, value)| acc + coefficients[i] * value)
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_weighted_sum() {
+        // 3x, x² + x + 1 and x³ - 2x² + 10 for x from 0 to 20
+        let input = b"\
+0 3 6 9 12 15 18 21 24 27 30 33 36 39 42 45 48 51 54 57 60
+1 3 7 13 21 31 43 57 73 91 111 133 157 183 211 241 273 307 343 381 421
+10 9 10 19 42 85 154 255 394 577 810 1099 1450 1869 2362 2935 3594 4345 5194 6147 7210
+";
+        assert_eq!(63 + 463 + 8389, next_values_sum(input));
+        assert_eq!(-3 + 1 + 7, previous_values_sum(input));
+    }
+}
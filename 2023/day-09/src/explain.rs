@@ -0,0 +1,63 @@
+//! Intermediates for `aoc run --explain`: the extrapolated value of every history.
+
+use aoc_common::explain::Explanation;
+
+use crate::custom_error::AocError;
+use crate::part1::{parse_line, predict_next};
+use crate::part2::predict_previous;
+use crate::schema;
+
+pub fn part1(input: &str) -> miette::Result<Explanation, AocError> {
+    schema::check(input)?;
+    explain(input, "next", predict_next)
+}
+
+pub fn part2(input: &str) -> miette::Result<Explanation, AocError> {
+    schema::check_history_len(input)?;
+    explain(input, "previous", predict_previous)
+}
+
+fn explain(
+    input: &str,
+    name: &'static str,
+    predict: fn(&str) -> Result<i32, AocError>,
+) -> Result<Explanation, AocError> {
+    let mut explanation = Explanation::new();
+    for (number, line) in input.lines().enumerate() {
+        explanation
+            .record(format!("history {}", number + 1))
+            .field("values", parse_line(line)?)
+            .field(name, predict(line)?);
+    }
+    Ok(explanation)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::explain::Value;
+
+    use super::*;
+
+    #[test]
+    fn test_explain() -> miette::Result<()> {
+        let explanation = part1("0 3 6 9 12 15\n10 13 16 21 30 45")?;
+        assert_eq!("history 2", explanation.records[1].label);
+        assert_eq!(Some(&Value::Int(68)), explanation.records[1].get("next"));
+        assert_eq!(
+            Some(&Value::from(vec![0, 3, 6, 9, 12, 15])),
+            explanation.records[0].get("values")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_snapshots() -> miette::Result<()> {
+        let input = "\
+0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45";
+        insta::assert_snapshot!("part1", part1(input)?);
+        // Part 2 only handles histories of 21 values, like the real input's
+        Ok(())
+    }
+}
@@ -0,0 +1,200 @@
+use aoc_common::isa::Isa;
+use aoc_common::lenient::Lenient;
+use aoc_common::parse;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use crate::bytes;
+use crate::custom_error::AocError;
+use crate::schema;
+use crate::simd;
+
+/// Naive approach
+pub fn process(input: &str) -> miette::Result<String, AocError> {
+    schema::check(input)?;
+    let result = input.lines().map(predict_next).sum::<Result<i32, _>>()?;
+
+    Ok(result.to_string())
+}
+
+/// Same as [`process`] with the histories spread over rayon's thread pool
+#[cfg(feature = "parallel")]
+pub fn process_parallel(input: &str) -> miette::Result<String, AocError> {
+    schema::check(input)?;
+    let result = input
+        .par_lines()
+        .map(predict_next)
+        .sum::<Result<i32, _>>()?;
+
+    Ok(result.to_string())
+}
+
+pub(crate) fn predict_next(line: &str) -> Result<i32, AocError> {
+    // Parse line
+    let mut values = parse_line(line)?;
+    let mut prediction = *values.last().unwrap();
+    loop {
+        // Iterate over neighbors
+        values = values
+            .iter()
+            .zip(values.iter().skip(1))
+            .map(|(a, b)| b - a)
+            .collect::<Vec<_>>();
+        // If all zeros we are done
+        if values.iter().all(|v| *v == 0) {
+            return Ok(prediction);
+        }
+        prediction += values.last().unwrap();
+    }
+}
+
+pub(crate) fn parse_line(line: &str) -> Result<Vec<i32>, AocError> {
+    Ok(parse::numbers::<i32>(line).collect::<Result<_, _>>()?)
+}
+
+/// Reuse a single array instead of allocating vectors
+pub fn process_optimized(input: &str) -> miette::Result<String, AocError> {
+    schema::check_history_len(input)?;
+    const ROW_LEN: usize = 21;
+
+    let result = input
+        .lines()
+        .map(|line| {
+            let mut values = [0; ROW_LEN];
+
+            for (i, value) in parse::numbers::<i32>(line).enumerate() {
+                values[i] = value?;
+            }
+
+            let mut prediction = values[ROW_LEN - 1];
+            let mut values_len = ROW_LEN;
+
+            loop {
+                for i in 1..values_len {
+                    values[i - 1] = values[i] - values[i - 1];
+                }
+                // The last value gets ignored after each step
+                values_len -= 1;
+                if values.iter().take(values_len).all(|v| *v == 0) {
+                    return Ok(prediction);
+                }
+                prediction += values[values_len - 1];
+            }
+        })
+        .sum::<Result<i32, AocError>>()?;
+
+    Ok(result.to_string())
+}
+
+/// Depth first traversal
+pub fn process_optimized_dft(input: &str) -> miette::Result<String, AocError> {
+    schema::check_history_len(input)?;
+    const ROW_LEN: usize = 21;
+
+    let result = input
+        .lines()
+        .map(|line| {
+            let mut values = [0; ROW_LEN];
+
+            for (i, value) in parse::numbers::<i32>(line).enumerate() {
+                values[i] = value?;
+            }
+
+            let mut prediction = values[ROW_LEN - 1];
+
+            for i in (1..ROW_LEN).rev() {
+                for j in i..ROW_LEN {
+                    values[j] -= values[j - 1];
+                }
+                match values[ROW_LEN - 1] {
+                    0 => break,
+                    n => prediction += n,
+                }
+            }
+            Ok(prediction)
+        })
+        .sum::<Result<i32, AocError>>()?;
+
+    Ok(result.to_string())
+}
+
+/// Using binomial coefficients, see [`crate::bytes`]
+pub fn process_bc(input: &str) -> miette::Result<String, AocError> {
+    schema::check_history_len(input)?;
+    Ok(bytes::next_values_sum(input.as_bytes()).to_string())
+}
+
+/// Binomial coefficients as dot products with the widest SIMD instructions the CPU has, see
+/// [`crate::simd`]
+pub fn process_simd(input: &str) -> miette::Result<String, AocError> {
+    schema::check(input)?;
+    Ok(simd::next_values_sum(Isa::selected(), input.as_bytes()).to_string())
+}
+
+/// Same as [`process`] but skips the lines that don't have the input's shape, see
+/// [`crate::schema`], returning the answer over the other lines in [`AocError::Partial`]
+pub fn process_lenient(input: &str) -> miette::Result<String, AocError> {
+    let lines = Lenient::lines(input, schema::check);
+    let answer = lines.answer(process)?;
+    Ok(lines.finish(answer)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_process() -> miette::Result<()> {
+        let input = "\
+0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45";
+        assert_eq!("114", process(input)?);
+        assert_eq!("114", process_simd(input)?);
+        #[cfg(feature = "parallel")]
+        assert_eq!("114", process_parallel(input)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_process_history_len() -> miette::Result<()> {
+        // Differences that only settle at the last level
+        let input = (0..21)
+            .map(|i| (i % 2).to_string())
+            .collect::<Vec<_>>()
+            .join(" ");
+        let expected = process(&input)?;
+        assert_eq!(expected, process_optimized(&input)?);
+        assert_eq!(expected, process_optimized_dft(&input)?);
+        assert_eq!(expected, process_bc(&input)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_process_lenient() {
+        let line = (0..21)
+            .map(|i| (i * 3).to_string())
+            .collect::<Vec<_>>()
+            .join(" ");
+        let input = format!("{line}\n0 3 six\n{line}");
+        let Err(AocError::Partial(partial)) = process_lenient(&input) else {
+            panic!("line 2 should be skipped");
+        };
+        assert_eq!("126", partial.answer());
+        assert_eq!(1, partial.skipped().len());
+
+        // Nothing left to solve
+        let Err(AocError::Partial(partial)) = process_lenient("0 3 six") else {
+            panic!("every line should be skipped");
+        };
+        assert_eq!("0", partial.answer());
+    }
+}
+
+/*
+process_optimized
+[day-09\src\part1.rs:64] total_steps = 30747
+
+process_optimized_bft
+[day-09\src\part1.rs:98] total_steps = 16440
+*/
@@ -0,0 +1,156 @@
+use aoc_common::isa::Isa;
+use aoc_common::lenient::Lenient;
+use aoc_common::parse;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use crate::bytes;
+use crate::custom_error::AocError;
+use crate::schema;
+use crate::simd;
+
+pub fn process(input: &str) -> miette::Result<String, AocError> {
+    schema::check_history_len(input)?;
+    let result = input
+        .lines()
+        .map(predict_previous)
+        .sum::<Result<i32, _>>()?;
+
+    Ok(result.to_string())
+}
+
+/// Same as [`process`] with the histories spread over rayon's thread pool
+#[cfg(feature = "parallel")]
+pub fn process_parallel(input: &str) -> miette::Result<String, AocError> {
+    schema::check_history_len(input)?;
+    let result = input
+        .par_lines()
+        .map(predict_previous)
+        .sum::<Result<i32, _>>()?;
+
+    Ok(result.to_string())
+}
+
+pub(crate) fn predict_previous(line: &str) -> Result<i32, AocError> {
+    // const ROW_LEN: usize = 6; // for test input
+    const ROW_LEN: usize = 21;
+
+    let mut values = [0; ROW_LEN];
+
+    // Parse line
+    for (i, value) in parse::numbers::<i32>(line).enumerate() {
+        values[i] = value?;
+    }
+
+    let mut prediction = values[0];
+    let mut sign = -1;
+    let mut values_len = ROW_LEN;
+
+    loop {
+        for i in 1..values_len {
+            values[i - 1] = values[i] - values[i - 1];
+        }
+        // The last value gets ignored after each step
+        values_len -= 1;
+        if values.iter().take(values_len).all(|v| *v == 0) {
+            return Ok(prediction);
+        }
+        // Just need to swap signs on each step. This is because:
+        // (x_1 - (x_2 - (x_3 - (...(x_n-1 - x_n)...))))
+        // is equivalent to
+        // x_1 - x_2 + x_3 - ... + x_n-1 - x_n
+        prediction += values[0] * sign;
+        sign *= -1;
+    }
+}
+
+/// This doesn't generalize to all inputs but it does work for the given input
+pub fn process_dft(input: &str) -> miette::Result<String, AocError> {
+    schema::check_history_len(input)?;
+    // const ROW_LEN: usize = 6; // for test input
+    const ROW_LEN: usize = 21;
+
+    let result = input
+        .lines()
+        .map(|line| {
+            let mut values = [0; ROW_LEN];
+
+            for (i, value) in parse::numbers::<i32>(line).enumerate() {
+                values[i] = value?;
+            }
+
+            let mut prediction = values[0];
+            let mut sign = -1;
+
+            for i in 0..(ROW_LEN - 1) {
+                for j in (0..=i).rev() {
+                    values[j] = values[j + 1] - values[j];
+                }
+                // Checking the first 3 values is sufficient for my input.
+                match values[0..=2] {
+                    [0, 0, 0] => break,
+                    _ => {
+                        prediction += values[0] * sign;
+                    }
+                }
+                sign *= -1;
+            }
+            Ok(prediction)
+        })
+        .sum::<Result<i32, AocError>>()?;
+
+    Ok(result.to_string())
+}
+
+/// Using binomial coefficients, see [`crate::bytes`]
+pub fn process_bc(input: &str) -> miette::Result<String, AocError> {
+    schema::check_history_len(input)?;
+    Ok(bytes::previous_values_sum(input.as_bytes()).to_string())
+}
+
+/// Binomial coefficients as dot products with the widest SIMD instructions the CPU has, see
+/// [`crate::simd`]
+pub fn process_simd(input: &str) -> miette::Result<String, AocError> {
+    schema::check(input)?;
+    Ok(simd::previous_values_sum(Isa::selected(), input.as_bytes()).to_string())
+}
+
+/// Same as [`process`] but skips the lines that don't have the input's shape, see
+/// [`crate::schema`], returning the answer over the other lines in [`AocError::Partial`]
+pub fn process_lenient(input: &str) -> miette::Result<String, AocError> {
+    let lines = Lenient::lines(input, schema::check_history_len);
+    let answer = lines.answer(process)?;
+    Ok(lines.finish(answer)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_process() -> miette::Result<()> {
+        let input = "\
+0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45";
+        assert_eq!("2", process_simd(input)?);
+        Ok(())
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_process_parallel() -> miette::Result<()> {
+        // `process` needs histories of the real input's length
+        let input = (1..=4)
+            .map(|n| {
+                (0..21)
+                    .map(|i| (i * i * n).to_string())
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        assert_eq!(process(&input)?, process_parallel(&input)?);
+        Ok(())
+    }
+}
@@ -0,0 +1,78 @@
+//! Shape of the histories. The array based variants assume the puzzle's
+//! [`HISTORY_LEN`](crate::bytes::HISTORY_LEN) of 21 values, the others take any length up to
+//! [`MAX_LEN`].
+
+use std::ops::RangeInclusive;
+
+use aoc_common::schema::{Checker, InvalidInput};
+
+use crate::bytes::HISTORY_LEN;
+use crate::simd::MAX_LEN;
+
+pub const SHAPE: &str = "lines of 1 to 32 integers separated by spaces";
+
+/// The shape of the array based variants, which assume the puzzle's history length
+pub const HISTORY_SHAPE: &str = "lines of 21 integers separated by spaces";
+
+pub fn check(input: &str) -> Result<(), InvalidInput> {
+    check_lines(input, SHAPE, 1..=MAX_LEN)
+}
+
+/// Checks for [`HISTORY_SHAPE`], for the array based variants
+pub fn check_history_len(input: &str) -> Result<(), InvalidInput> {
+    check_lines(input, HISTORY_SHAPE, HISTORY_LEN..=HISTORY_LEN)
+}
+
+fn check_lines(
+    input: &str,
+    shape: &'static str,
+    lengths: RangeInclusive<usize>,
+) -> Result<(), InvalidInput> {
+    let mut checker = Checker::new(input, shape);
+    for line in checker.lines() {
+        let values = line.split(' ').collect::<Vec<_>>();
+        let count = values.len();
+        let expected = match lengths.start() == lengths.end() {
+            true => format!("{}", lengths.start()),
+            false => format!("{} to {}", lengths.start(), lengths.end()),
+        };
+        checker.expect(
+            lengths.contains(&count),
+            line,
+            format!("there should be {expected} values, found {count}"),
+        );
+        for value in values {
+            checker.signed(value, "the value");
+        }
+    }
+    checker.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check() {
+        let line = (0..21)
+            .map(|i| (i * 3 - 6).to_string())
+            .collect::<Vec<_>>()
+            .join(" ");
+        assert!(check(&format!("{line}\n{line}\n")).is_ok());
+        assert!(check_history_len(&format!("{line}\n{line}\n")).is_ok());
+        assert!(check("0 3 6 9 12 15").is_ok());
+        let invalid = check_history_len("0 3 6 9 12 15").unwrap_err();
+        assert_eq!(
+            [(0..13, "there should be 21 values, found 6".to_string())],
+            invalid.violations()
+        );
+        let invalid = check(&["0"; 33].join(" ")).unwrap_err();
+        assert_eq!(
+            [(
+                0..65,
+                "there should be 1 to 32 values, found 33".to_string()
+            )],
+            invalid.violations()
+        );
+    }
+}
@@ -0,0 +1,119 @@
+//! The binomial coefficient solvers of [`crate::bytes`] as dot products of fixed-width rows, which
+//! vectorize. A history is parsed into a zero-padded row of [`MAX_LEN`] values and multiplied
+//! with the row of signed coefficients for its length, computed once per length.
+
+use aoc_common::parse;
+
+/// Longest history supported. The coefficients of longer ones could overflow.
+pub const MAX_LEN: usize = 32;
+
+type Row = [i64; MAX_LEN];
+
+aoc_common::multiversion! {
+    /// Sum of the values extrapolated after each history, one per line
+    pub fn next_values_sum(input: &[u8]) -> i64 = next_values_sum_kernel;
+}
+
+aoc_common::multiversion! {
+    /// Sum of the values extrapolated before each history, one per line
+    pub fn previous_values_sum(input: &[u8]) -> i64 = previous_values_sum_kernel;
+}
+
+#[inline(always)]
+fn next_values_sum_kernel(input: &[u8]) -> i64 {
+    extrapolated_sum(input, false)
+}
+
+#[inline(always)]
+fn previous_values_sum_kernel(input: &[u8]) -> i64 {
+    extrapolated_sum(input, true)
+}
+
+#[inline(always)]
+fn extrapolated_sum(input: &[u8], backwards: bool) -> i64 {
+    let mut values = [0; MAX_LEN];
+    let mut coefficients = [0; MAX_LEN];
+    // Length `coefficients` were computed for
+    let mut coefficients_len = 0;
+    let mut sum = 0;
+    for line in input.split(|&c| c == b'\n') {
+        let mut len = 0;
+        for value in parse::numbers(line) {
+            assert!(
+                len < MAX_LEN,
+                "histories should have at most {MAX_LEN} values"
+            );
+            values[len] = value.expect("valid number");
+            len += 1;
+        }
+        if len == 0 {
+            continue;
+        }
+        values[len..].fill(0);
+        if len != coefficients_len {
+            coefficients = signed_binomials(len, backwards);
+            coefficients_len = len;
+        }
+        sum += dot(&values, &coefficients);
+    }
+    sum
+}
+
+/// The weight of each value of a history of `len` values in its extrapolation, the same as
+/// [`crate::bytes`] applies one value at a time
+fn signed_binomials(len: usize, backwards: bool) -> Row {
+    let mut row = [0; MAX_LEN];
+    let len = len as i64;
+    // C(len, i), updated after each value
+    let mut coefficient = 1;
+    for (i, weight) in row.iter_mut().take(len as usize).enumerate() {
+        let i = i as i64;
+        let sign = |exponent: i64| if exponent % 2 == 0 { 1 } else { -1 };
+        if backwards {
+            coefficient = coefficient * (len - i) / (i + 1);
+            *weight = sign(i) * coefficient;
+        } else {
+            *weight = sign(len - 1 - i) * coefficient;
+            coefficient = coefficient * (len - i) / (i + 1);
+        }
+    }
+    row
+}
+
+#[inline(always)]
+fn dot(values: &Row, coefficients: &Row) -> i64 {
+    values.iter().zip(coefficients).map(|(v, c)| v * c).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use aoc_common::isa::Isa;
+
+    use crate::bytes;
+
+    #[test]
+    fn test_extrapolated_sums() {
+        let input = b"0 3 6 9 12 15\n1 3 6 10 15 21\n10 13 16 21 30 45\n";
+        let history = |len: i64| {
+            (0..len)
+                .map(|i| (i * i - 7 * i).to_string())
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+        let (long, real) = (history(MAX_LEN as i64), history(bytes::HISTORY_LEN as i64));
+        for isa in Isa::supported() {
+            assert_eq!(114, next_values_sum(isa, input), "{isa}");
+            assert_eq!(2, previous_values_sum(isa, input), "{isa}");
+            assert_eq!(-4, next_values_sum(isa, b"-1 -2 -3"), "{isa}");
+            // 32² - 7 * 32
+            assert_eq!(800, next_values_sum(isa, long.as_bytes()), "{isa}");
+            assert_eq!(
+                bytes::next_values_sum(real.as_bytes()),
+                next_values_sum(isa, real.as_bytes()),
+                "{isa}"
+            );
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use day_10::*;
+use aoc2023_day_10::*;
 
 fn main() {
     // Run registered benchmarks.
@@ -1,4 +1,4 @@
-use day_10::part1::*;
+use aoc2023_day_10::part1::*;
 use miette::Context;
 
 #[cfg(feature = "dhat-heap")]
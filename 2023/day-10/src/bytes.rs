@@ -0,0 +1,179 @@
+//! Allocation-free cores of the bitwise solvers, built without `std` too. Inputs are the grid's
+//! bytes, rows ending in `\n`.
+
+/*
+^ 1100
+v 0000
+
+< 0100
+> 1000
+'|'
+0000 ^ 1100 = 1100
+0000 ^ 0000 = 0000
+'-'
+0000 ^ 0100 = 0100
+0000 ^ 1000 = 1000
+'L'
+1010 ^ 0010 = 1000
+1010 ^ 0100 = 1100
+'F'
+0100 ^ 0100 = 0000
+0100 ^ 1100 = 1000
+'J'
+0100 ^ 0000 = 0100
+0100 ^ 1000 = 1100
+'7'
+1000 ^ 1000 = 0000
+1000 ^ 1100 = 0100
+
+*/
+
+// The idea is to be able to get the next direction by doing a bitwise xor with the current tile
+// and previous direction.
+pub(crate) mod direction {
+    pub const N: u8 = 0b1100;
+    pub const S: u8 = 0b0000;
+    pub const W: u8 = 0b0100;
+    pub const E: u8 = 0b1000;
+}
+
+mod tile {
+    pub const NS: u8 = 0b0000; // b'|'
+    pub const EW: u8 = 0b0000; // b'-'
+    pub const NE: u8 = 0b1000; // b'L'
+    pub const NW: u8 = 0b0100; // b'J'
+    pub const SW: u8 = 0b1000; // b'7'
+    pub const SE: u8 = 0b0100; // b'F'
+}
+
+const MAP_LEN: usize = 128;
+pub(crate) static BIT_MAP: [u8; MAP_LEN] = create_bit_map();
+
+const fn create_bit_map() -> [u8; MAP_LEN] {
+    let mut bit_map = [0; MAP_LEN];
+    bit_map[b'|' as usize] = tile::NS;
+    bit_map[b'-' as usize] = tile::EW;
+    bit_map[b'L' as usize] = tile::NE;
+    bit_map[b'J' as usize] = tile::NW;
+    bit_map[b'7' as usize] = tile::SW;
+    bit_map[b'F' as usize] = tile::SE;
+    bit_map
+}
+
+/// Check east and west. If no match then it must be north and south
+pub(crate) fn get_start_direction(input: &[u8], start_index: usize) -> u8 {
+    // east
+    if let Some(c) = input.get(start_index + 1) {
+        if [b'-', b'J', b'7'].contains(c) {
+            return direction::E;
+        }
+    }
+    // west
+    if let Some(c) = input.get(start_index - 1) {
+        if [b'-', b'L', b'F'].contains(c) {
+            return direction::W;
+        }
+    }
+    direction::N
+}
+
+pub(crate) fn get_next_index(mut index: usize, dir: u8, row_length: usize) -> usize {
+    index -= (dir == direction::N) as usize * row_length;
+    index += (dir == direction::S) as usize * row_length;
+    index -= (dir == direction::W) as usize;
+    index += (dir == direction::E) as usize;
+    index
+}
+
+/// Steps from `S` to the farthest tile of its loop
+pub fn farthest_steps(input: &[u8]) -> usize {
+    let mut index = input.iter().position(|&c| c == b'S').unwrap();
+    let row_length = input.iter().position(|&c| c == b'\n').unwrap() + 1;
+
+    let mut dir = get_start_direction(input, index);
+
+    let mut steps = 0;
+
+    // Traverse entire loop and divide total steps by 2 to get result
+    loop {
+        steps += 1;
+        index = get_next_index(index, dir, row_length);
+
+        match input[index] {
+            b'S' => break,
+            c => dir ^= BIT_MAP[c as usize],
+        }
+    }
+    steps / 2
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+struct Vertex {
+    x: i32,
+    y: i32,
+}
+
+impl Vertex {
+    fn from_index(index: usize, row_length: usize) -> Vertex {
+        Vertex {
+            x: (index % row_length) as i32,
+            y: (index / row_length) as i32,
+        }
+    }
+}
+
+/// Tiles enclosed by the loop through `S`
+pub fn enclosed_tiles(input: &[u8]) -> usize {
+    let mut index = input.iter().position(|&c| c == b'S').unwrap();
+    let row_length = input.iter().position(|&c| c == b'\n').unwrap() + 1;
+
+    // Get start direction and vertex
+    let mut dir = get_start_direction(input, index);
+    let mut curr_vertex = Vertex::from_index(index, row_length);
+    let mut prev_vertex;
+
+    let mut perimeter_length = 0;
+    let mut area = 0;
+
+    // Traverse entire cycle path and apply shoelace algorithm
+    loop {
+        perimeter_length += 1;
+        index = get_next_index(index, dir, row_length);
+
+        let tile = input[index];
+        match tile {
+            b'L' | b'J' | b'7' | b'F' | b'S' => {
+                // Update area when we find a vertex
+                prev_vertex = curr_vertex;
+                curr_vertex = Vertex::from_index(index, row_length);
+                area += (curr_vertex.x + prev_vertex.x) * (curr_vertex.y - prev_vertex.y);
+
+                if tile == b'S' {
+                    area /= 2;
+                    break;
+                }
+            }
+            _ => (),
+        }
+        dir ^= BIT_MAP[tile as usize]
+    }
+    // use Pick's theorem to get number of tiles inside
+    i32::abs(area) as usize - perimeter_length / 2 + 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_loop() {
+        let input = b"\
+7-F7-
+.FJ|7
+SJLL7
+|F--J
+LJ.LJ";
+        assert_eq!(8, farthest_steps(input));
+        assert_eq!(1, enclosed_tiles(input));
+    }
+}
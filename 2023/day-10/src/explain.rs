@@ -0,0 +1,51 @@
+//! Intermediates for `aoc run --explain`: the loop found from `S`.
+
+use aoc_common::explain::Explanation;
+
+use crate::custom_error::AocError;
+use crate::part1::trace_loop;
+
+/// The loop's length, its farthest tile and every tile along it as `[x, y]`, shared by both parts
+pub fn found_loop(input: &str) -> miette::Result<Explanation, AocError> {
+    let row_length = input.find('\n').unwrap() + 1;
+    let position = |index: usize| [index % row_length, index / row_length];
+
+    let path = trace_loop(input);
+    let mut explanation = Explanation::new();
+    explanation
+        .record("loop")
+        .field("start", position(path[0]))
+        .field("length", path.len())
+        .field("farthest_steps", path.len() / 2)
+        .field("farthest", position(path[path.len() / 2]))
+        .field("tiles", path.into_iter().map(position).collect::<Vec<_>>());
+    Ok(explanation)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::explain::Value;
+
+    use super::*;
+
+    #[test]
+    fn test_found_loop() -> miette::Result<()> {
+        let input = "\
+7-F7-
+.FJ|7
+SJLL7
+|F--J
+LJ.LJ";
+        let explanation = found_loop(input)?;
+        let record = &explanation.records[0];
+        assert_eq!(Some(&Value::from([0, 2])), record.get("start"));
+        assert_eq!(Some(&Value::Int(16)), record.get("length"));
+        assert_eq!(Some(&Value::Int(8)), record.get("farthest_steps"));
+        assert_eq!(Some(&Value::from([4, 2])), record.get("farthest"));
+        let Some(Value::List(tiles)) = record.get("tiles") else {
+            panic!("tiles should be a list");
+        };
+        assert_eq!(16, tiles.len());
+        Ok(())
+    }
+}
@@ -0,0 +1,16 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod bytes;
+
+#[cfg(feature = "std")]
+pub mod custom_error;
+#[cfg(feature = "std")]
+pub mod explain;
+#[cfg(feature = "std")]
+pub mod part1;
+#[cfg(feature = "std")]
+pub mod part2;
+#[cfg(feature = "std")]
+pub mod schema;
+#[cfg(feature = "std")]
+pub mod viz;
@@ -1,4 +1,6 @@
+use crate::bytes::{self, get_next_index, get_start_direction, BIT_MAP};
 use crate::custom_error::AocError;
+use crate::schema;
 use phf::phf_map;
 
 const UP: (i32, i32) = (0, -1);
@@ -17,6 +19,7 @@ static PIPE_DIR_MAP: phf::Map<u8, [(i32, i32); 2]> = phf_map! {
 };
 
 pub fn process(input: &str) -> miette::Result<String, AocError> {
+    schema::check(input)?;
     let row_length = (input.find('\n').unwrap() + 1) as i32;
     let start_index = input.find('S').unwrap() as i32;
     // indexing is easier if in bytes
@@ -61,112 +64,29 @@ pub fn process(input: &str) -> miette::Result<String, AocError> {
     let result = steps / 2;
     Ok(result.to_string())
 }
-/*
-^ 1100
-v 0000
 
-< 0100
-> 1000
-'|'
-0000 ^ 1100 = 1100
-0000 ^ 0000 = 0000
-'-'
-0000 ^ 0100 = 0100
-0000 ^ 1000 = 1000
-'L'
-1010 ^ 0010 = 1000
-1010 ^ 0100 = 1100
-'F'
-0100 ^ 0100 = 0000
-0100 ^ 1100 = 1000
-'J'
-0100 ^ 0000 = 0100
-0100 ^ 1000 = 1100
-'7'
-1000 ^ 1000 = 0000
-1000 ^ 1100 = 0100
-
-*/
-
-// The idea is to be able to get the next direction by doing a bitwise xor with the current tile
-// and previous direction.
-mod direction {
-    pub const N: u8 = 0b1100;
-    pub const S: u8 = 0b0000;
-    pub const W: u8 = 0b0100;
-    pub const E: u8 = 0b1000;
-}
-
-mod tile {
-    pub const NS: u8 = 0b0000; // b'|'
-    pub const EW: u8 = 0b0000; // b'-'
-    pub const NE: u8 = 0b1000; // b'L'
-    pub const NW: u8 = 0b0100; // b'J'
-    pub const SW: u8 = 0b1000; // b'7'
-    pub const SE: u8 = 0b0100; // b'F'
-}
-
-const MAP_LEN: usize = 128;
-static BIT_MAP: [u8; MAP_LEN] = create_bit_map();
-
-const fn create_bit_map() -> [u8; MAP_LEN] {
-    let mut bit_map = [0; MAP_LEN];
-    bit_map[b'|' as usize] = tile::NS;
-    bit_map[b'-' as usize] = tile::EW;
-    bit_map[b'L' as usize] = tile::NE;
-    bit_map[b'J' as usize] = tile::NW;
-    bit_map[b'7' as usize] = tile::SW;
-    bit_map[b'F' as usize] = tile::SE;
-    bit_map
-}
-
-/// Check east and west. If no match then it must be north and south
-fn get_start_direction(input: &[u8], start_index: usize) -> u8 {
-    // east
-    if let Some(c) = input.get(start_index + 1) {
-        if [b'-', b'J', b'7'].contains(c) {
-            return direction::E;
-        }
-    }
-    // west
-    if let Some(c) = input.get(start_index - 1) {
-        if [b'-', b'L', b'F'].contains(c) {
-            return direction::W;
-        }
-    }
-    direction::N
-}
-
-fn get_next_index(mut index: usize, dir: u8, row_length: usize) -> usize {
-    index -= (dir == direction::N) as usize * row_length;
-    index += (dir == direction::S) as usize * row_length;
-    index -= (dir == direction::W) as usize;
-    index += (dir == direction::E) as usize;
-    index
-}
-
-pub fn process_bits(input: &str) -> miette::Result<String, AocError> {
-    let mut index = input.find('S').unwrap();
+/// Index of every tile of the loop in the order they're walked, starting at `S`
+pub(crate) fn trace_loop(input: &str) -> Vec<usize> {
+    let start = input.find('S').unwrap();
     let row_length = input.find('\n').unwrap() + 1;
-    let input = input.as_bytes();
-
-    let mut dir = get_start_direction(input, index);
-
-    let mut steps = 0;
+    let tiles = input.as_bytes();
 
-    // Traverse entire loop and divide total steps by 2 to get result
+    let mut path = vec![];
+    let mut dir = get_start_direction(tiles, start);
+    let mut index = start;
     loop {
-        steps += 1;
+        path.push(index);
         index = get_next_index(index, dir, row_length);
-
-        match input[index] {
-            b'S' => break,
+        match tiles[index] {
+            b'S' => return path,
             c => dir ^= BIT_MAP[c as usize],
         }
     }
-    let result = steps / 2;
+}
 
-    Ok(result.to_string())
+pub fn process_bits(input: &str) -> miette::Result<String, AocError> {
+    schema::check(input)?;
+    Ok(bytes::farthest_steps(input.as_bytes()).to_string())
 }
 
 #[cfg(test)]
@@ -1,4 +1,6 @@
+use crate::bytes;
 use crate::custom_error::AocError;
+use crate::schema;
 // use phf::phf_map;
 
 // Associate pipes with directions (SLOW)
@@ -33,6 +35,7 @@ const fn create_pipe_dir_map() -> [[(i32, i32); 2]; 128] {
 
 /// See `process_bits` for fast version
 pub fn process(input: &str) -> miette::Result<String, AocError> {
+    schema::check(input)?;
     let row_length = (input.find('\n').unwrap() + 1) as i32;
     let start_index = input.find('S').unwrap() as i32;
 
@@ -188,146 +191,9 @@ fn replace_start(start_dir: (i32, i32), end_dir: (i32, i32)) -> u8 {
     }
 }
 
-/*
-^ 1100
-v 0000
-
-< 0100
-> 1000
-'|'
-0000 ^ 1100 = 1100
-0000 ^ 0000 = 0000
-'-'
-0000 ^ 0100 = 0100
-0000 ^ 1000 = 1000
-'L'
-1010 ^ 0010 = 1000
-1010 ^ 0100 = 1100
-'F'
-0100 ^ 0100 = 0000
-0100 ^ 1100 = 1000
-'J'
-0100 ^ 0000 = 0100
-0100 ^ 1000 = 1100
-'7'
-1000 ^ 1000 = 0000
-1000 ^ 1100 = 0100
-
-*/
-
-// The idea is to be able to get the next direction by doing a bitwise xor with the current tile
-// and previous direction.
-mod direction {
-    pub const N: u8 = 0b1100;
-    pub const S: u8 = 0b0000;
-    pub const W: u8 = 0b0100;
-    pub const E: u8 = 0b1000;
-}
-
-mod tile {
-    pub const NS: u8 = 0b0000; // b'|'
-    pub const EW: u8 = 0b0000; // b'-'
-    pub const NE: u8 = 0b1000; // b'L'
-    pub const NW: u8 = 0b0100; // b'J'
-    pub const SW: u8 = 0b1000; // b'7'
-    pub const SE: u8 = 0b0100; // b'F'
-}
-
-const MAP_LEN: usize = 128;
-static TILE_BYTE_MAP: [u8; MAP_LEN] = create_tile_byte_map();
-
-const fn create_tile_byte_map() -> [u8; MAP_LEN] {
-    let mut map = [0; MAP_LEN];
-    map[b'|' as usize] = tile::NS;
-    map[b'-' as usize] = tile::EW;
-    map[b'L' as usize] = tile::NE;
-    map[b'J' as usize] = tile::NW;
-    map[b'7' as usize] = tile::SW;
-    map[b'F' as usize] = tile::SE;
-    map
-}
-
-/// Check east and west. If no match then it must be north and south
-fn get_start_direction(input: &[u8], start_index: usize) -> u8 {
-    // east
-    if let Some(c) = input.get(start_index + 1) {
-        if [b'-', b'J', b'7'].contains(c) {
-            return direction::E;
-        }
-    }
-    // west
-    if let Some(c) = input.get(start_index - 1) {
-        if [b'-', b'L', b'F'].contains(c) {
-            return direction::W;
-        }
-    }
-    direction::N
-}
-
-fn get_next_index(mut index: usize, dir: u8, row_length: usize) -> usize {
-    index -= (dir == direction::N) as usize * row_length;
-    index += (dir == direction::S) as usize * row_length;
-    index -= (dir == direction::W) as usize;
-    index += (dir == direction::E) as usize;
-    index
-}
-
-#[derive(Debug, Default, Clone, Copy)]
-struct Vertex {
-    x: i32,
-    y: i32,
-}
-
-impl Vertex {
-    pub fn from_index(index: usize, row_length: usize) -> Vertex {
-        Vertex {
-            x: (index % row_length) as i32,
-            y: (index / row_length) as i32,
-        }
-    }
-}
-
 pub fn process_bits(input: &str) -> miette::Result<String, AocError> {
-    let mut index = input.find('S').unwrap();
-    let row_length = input.find('\n').unwrap() + 1;
-
-    // as bytes makes indexing simpler
-    let input = input.as_bytes();
-
-    // Get start direction and vertex
-    let mut dir = get_start_direction(input, index);
-    let mut curr_vertex = Vertex::from_index(index, row_length);
-    let mut prev_vertex;
-
-    let mut perimeter_length = 0;
-    let mut area = 0;
-
-    // Traverse entire cycle path and apply shoelace algorithm
-    loop {
-        perimeter_length += 1;
-        index = get_next_index(index, dir, row_length);
-
-        let tile = input[index];
-        match tile {
-            b'L' | b'J' | b'7' | b'F' | b'S' => {
-                // Update area when we find a vertex
-                prev_vertex = curr_vertex;
-                curr_vertex = Vertex::from_index(index, row_length);
-                area += (curr_vertex.x + prev_vertex.x) * (curr_vertex.y - prev_vertex.y);
-
-                if tile == b'S'{
-                    area /= 2;
-                    break;
-                }
-            },
-            _ => ()
-        }
-        dir ^= TILE_BYTE_MAP[tile as usize]
-    }
-    // use Pick's theorem to get number of tiles inside
-    let result = i32::abs(area) as usize - perimeter_length / 2 + 1;
-
-    Ok(result.to_string())
+    schema::check(input)?;
+    Ok(bytes::enclosed_tiles(input.as_bytes()).to_string())
 }
 
 #[cfg(test)]
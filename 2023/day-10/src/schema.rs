@@ -0,0 +1,112 @@
+//! Shape of the pipe sketch
+
+use aoc_common::schema::{Checker, InvalidInput};
+
+pub const SHAPE: &str = "a rectangular grid of the pipes `|-LJ7F`, ground `.` and one start `S` \
+                         on a loop of pipes";
+
+pub fn check(input: &str) -> Result<(), InvalidInput> {
+    let mut checker = Checker::new(input, SHAPE);
+    checker.grid(input, "|-LJ7FS.");
+    let starts = input.matches('S').count();
+    if checker.expect(
+        starts == 1,
+        input,
+        format!("there should be exactly one start `S`, found {starts}"),
+    ) {
+        check_loop(&mut checker, input);
+    }
+    checker.finish()
+}
+
+/// Offsets of the two tiles `pipe` connects, in a grid of rows `width` bytes apart
+fn pipe_ends(pipe: u8, width: isize) -> Option<[isize; 2]> {
+    Some(match pipe {
+        b'|' => [-width, width],
+        b'-' => [-1, 1],
+        b'L' => [-width, 1],
+        b'J' => [-width, -1],
+        b'7' => [-1, width],
+        b'F' => [1, width],
+        _ => return None,
+    })
+}
+
+/// The solvers follow the pipes from `S` until they're back, so `S` should connect to exactly two
+/// pipes and those should lead back to it
+fn check_loop(checker: &mut Checker<'_>, input: &str) {
+    let tiles = input.as_bytes();
+    let width = input.find('\n').map_or(input.len(), |i| i + 1) as isize;
+    let start = input.find('S').unwrap_or_default();
+    let span = &input[start..start + 1];
+    let ends = |index: isize| {
+        let tile = usize::try_from(index)
+            .ok()
+            .and_then(|index| tiles.get(index));
+        tile.and_then(|&tile| pipe_ends(tile, width))
+    };
+
+    let exits = [-width, width, -1, 1]
+        .into_iter()
+        .filter(|&step| ends(start as isize + step).is_some_and(|ends| ends.contains(&-step)))
+        .collect::<Vec<_>>();
+    let count = exits.len();
+    if !checker.expect(
+        count == 2,
+        span,
+        format!("the start should connect to exactly 2 pipes, found {count}"),
+    ) {
+        return;
+    }
+    let (mut index, mut step) = (start as isize, exits[0]);
+    for _ in 0..tiles.len() {
+        index += step;
+        if index == start as isize {
+            return;
+        }
+        match ends(index) {
+            Some([a, b]) if a == -step => step = b,
+            Some([a, b]) if b == -step => step = a,
+            _ => break,
+        }
+    }
+    checker.expect(
+        false,
+        span,
+        "the pipes from the start should lead back to it",
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check() {
+        assert!(check(".....\n.S-7.\n.|.|.\n.L-J.\n.....\n").is_ok());
+        let invalid = check(".....\n.S-7.\n.|.|.\n.L-..\n.....\n").unwrap_err();
+        assert_eq!(
+            [(
+                7..8,
+                "the pipes from the start should lead back to it".to_string()
+            )],
+            invalid.violations()
+        );
+        let invalid = check("S-7\n..|\n").unwrap_err();
+        assert_eq!(
+            [(
+                0..1,
+                "the start should connect to exactly 2 pipes, found 1".to_string()
+            )],
+            invalid.violations()
+        );
+        let invalid = check(".....\n.F-7.\n.|.|.\n.L-J.\n").unwrap_err();
+        assert_eq!(
+            [(
+                0..24,
+                "there should be exactly one start `S`, found 0".to_string()
+            )],
+            invalid.violations()
+        );
+    }
+}
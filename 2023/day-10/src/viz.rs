@@ -0,0 +1,109 @@
+//! Frames for `aoc-viz`: the loop traced from `S` one pipe per frame.
+
+use std::rc::Rc;
+
+use aoc_common::frame::{Cell, Colour, Frame};
+
+use crate::part1::trace_loop;
+
+/// The sketch with the first `steps` pipes of the loop traced.
+#[derive(Clone)]
+pub struct LoopFrame<'a> {
+    tiles: &'a [u8],
+    width: usize,
+    /// Position of each tile along the loop, counting `S` as 1. 0 for tiles not on the loop.
+    order: Rc<[usize]>,
+    loop_len: usize,
+    steps: usize,
+}
+
+impl Frame for LoopFrame<'_> {
+    fn width(&self) -> usize {
+        self.width
+    }
+
+    fn height(&self) -> usize {
+        self.tiles.len().div_ceil(self.width + 1)
+    }
+
+    fn cell(&self, x: usize, y: usize) -> Cell {
+        let index = y * (self.width + 1) + x;
+        let tile = self.tiles[index];
+        let order = self.order[index];
+        if order == 0 || order > self.steps {
+            return Cell::new(tile as char, Colour::Dim);
+        }
+        let glyph = match tile {
+            b'|' => '│',
+            b'-' => '─',
+            b'L' => '└',
+            b'J' => '┘',
+            b'7' => '┐',
+            b'F' => '┌',
+            _ => tile as char,
+        };
+        let colour = match order {
+            1 => Colour::Green,
+            order if order == self.steps => Colour::Red,
+            _ => Colour::Yellow,
+        };
+        Cell::new(glyph, colour)
+    }
+
+    fn caption(&self) -> String {
+        format!(
+            "{}/{} pipes traced, farthest point {} steps away",
+            self.steps,
+            self.loop_len,
+            self.loop_len / 2
+        )
+    }
+}
+
+pub fn frames(input: &str, _part: u8) -> impl Iterator<Item = LoopFrame<'_>> {
+    let width = input.find('\n').unwrap();
+    let tiles = input.as_bytes();
+
+    let path = trace_loop(input);
+    let loop_len = path.len();
+    let mut order = vec![0; tiles.len()];
+    for (step, &index) in path.iter().enumerate() {
+        order[index] = step + 1;
+    }
+
+    let order: Rc<[usize]> = order.into();
+    (0..=loop_len).map(move |steps| LoopFrame {
+        tiles,
+        width,
+        order: order.clone(),
+        loop_len,
+        steps,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use aoc_common::frame::to_text;
+
+    #[test]
+    fn test_frames() {
+        let input = "\
+7-F7-
+.FJ|7
+SJLL7
+|F--J
+LJ.LJ";
+        let frames = frames(input, 1).collect::<Vec<_>>();
+        assert_eq!(17, frames.len());
+        assert_eq!("0/16 pipes traced, farthest point 8 steps away", frames[0].caption());
+        assert_eq!(Cell::new('S', Colour::Dim), frames[0].cell(0, 2));
+
+        let last = &frames[16];
+        assert_eq!(Cell::new('S', Colour::Green), last.cell(0, 2));
+        assert_eq!(Cell::new('┌', Colour::Yellow), last.cell(2, 0));
+        assert_eq!(Cell::new('.', Colour::Dim), last.cell(0, 1));
+        insta::assert_snapshot!("loop", to_text(last));
+    }
+}
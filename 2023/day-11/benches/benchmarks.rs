@@ -0,0 +1,68 @@
+use aoc2023_day_11::*;
+
+fn main() {
+    // Run registered benchmarks.
+    divan::main();
+}
+
+#[divan::bench]
+fn part1() {
+    part1::process(divan::black_box(include_str!(
+        "../input.txt",
+    )))
+    .unwrap();
+}
+
+#[divan::bench]
+fn part1_faster() {
+    part1::process_faster(divan::black_box(include_str!(
+        "../input.txt",
+    )))
+    .unwrap();
+}
+
+#[divan::bench]
+fn part1_fastest() {
+    part1::process_fastest(divan::black_box(include_str!(
+        "../input.txt",
+    )))
+    .unwrap();
+}
+
+#[divan::bench]
+fn part2() {
+    part2::process(divan::black_box(include_str!(
+        "../input.txt",
+    )))
+    .unwrap();
+}
+
+#[divan::bench]
+fn part2_fastest() {
+    part2::process_fastest(divan::black_box(include_str!(
+        "../input.txt",
+    )))
+    .unwrap();
+}
+
+#[cfg(feature = "parallel")]
+#[divan::bench(args = [1, 2, 4, 8])]
+fn part1_parallel(bencher: divan::Bencher, threads: usize) {
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(threads)
+        .build()
+        .unwrap();
+    let input = include_str!("../input.txt");
+    bencher.bench(|| pool.install(|| part1::process_parallel(divan::black_box(input)).unwrap()));
+}
+
+#[cfg(feature = "parallel")]
+#[divan::bench(args = [1, 2, 4, 8])]
+fn part2_parallel(bencher: divan::Bencher, threads: usize) {
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(threads)
+        .build()
+        .unwrap();
+    let input = include_str!("../input.txt");
+    bencher.bench(|| pool.install(|| part2::process_parallel(divan::black_box(input)).unwrap()));
+}
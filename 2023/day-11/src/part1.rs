@@ -1,4 +1,8 @@
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
 use crate::custom_error::AocError;
+use crate::schema;
 
 #[derive(Debug, Default, Clone, Copy)]
 struct Vertex {
@@ -20,6 +24,41 @@ impl Vertex {
 
 // Brute force O(n^2) approach
 pub fn process(input: &str) -> miette::Result<String, AocError> {
+    schema::check(input)?;
+    let galaxies = expanded_galaxies(input);
+
+    // Sum manhattan distance between all galaxy pairs
+    let result = galaxies.iter().enumerate().fold(0, |acc, (index, g1)| {
+        acc + galaxies[(index + 1)..]
+            .iter()
+            .fold(0, |acc_inner, g2| acc_inner + g1.manhattan_dist(g2))
+    });
+
+    Ok(result.to_string())
+}
+
+/// Same as [`process`] with each galaxy's pairs summed on rayon's thread pool
+#[cfg(feature = "parallel")]
+pub fn process_parallel(input: &str) -> miette::Result<String, AocError> {
+    schema::check(input)?;
+    let galaxies = expanded_galaxies(input);
+
+    let result = galaxies
+        .par_iter()
+        .enumerate()
+        .map(|(index, g1)| {
+            galaxies[(index + 1)..]
+                .iter()
+                .map(|g2| g1.manhattan_dist(g2))
+                .sum::<i64>()
+        })
+        .sum::<i64>();
+
+    Ok(result.to_string())
+}
+
+/// Galaxy coordinates after every empty row and column doubled
+fn expanded_galaxies(input: &str) -> Vec<Vertex> {
     let row_length = input.find('\n').unwrap() + 1;
     let column_length = input.len() / row_length + 1;
 
@@ -58,15 +97,7 @@ pub fn process(input: &str) -> miette::Result<String, AocError> {
         g.x += empty_columns[g.x as usize];
         g.y += empty_rows[g.y as usize];
     }
-
-    // Sum manhattan distance between all galaxy pairs
-    let result = galaxies.iter().enumerate().fold(0, |acc, (index, g1)| {
-        acc + galaxies[(index + 1)..]
-            .iter()
-            .fold(0, |acc_inner, g2| acc_inner + g1.manhattan_dist(g2))
-    });
-
-    Ok(result.to_string())
+    galaxies
 }
 
 /*
@@ -95,6 +126,7 @@ The expansion can be added by the same method as the naive approach above.
 Due to the sort, the time complexity is O(n + klogk) where n is input length and k is number of galaxies
 */
 pub fn process_faster(input: &str) -> miette::Result<String, AocError> {
+    schema::check(input)?;
     let row_length = input.find('\n').unwrap() + 1;
     let column_length = input.len() / row_length + 1;
 
@@ -174,7 +206,8 @@ counter whenever a zero is encountered.
 Time complexity is O(n) where n is input length
 */
 pub fn process_fastest(input: &str) -> miette::Result<String, AocError> {
-    // dim => dimension aka side length (assume square grid).
+    schema::check(input)?;
+    // dim => dimension aka row length, the grid needn't be square.
     // padding is the number of characters used for new line
     //   with windows it is 1 ("\n") (my test input)
     //   with linux it is 2 ("\r\n") (actual input)
@@ -186,8 +219,9 @@ pub fn process_fastest(input: &str) -> miette::Result<String, AocError> {
 
     let input = input.as_bytes();
 
+    let rows = input.len().div_ceil(dim + padding);
     let mut galaxies_x = vec![0; dim];
-    let mut galaxies_y = vec![0; dim];
+    let mut galaxies_y = vec![0; rows];
     let mut total_galaxies = 0;
 
     for (index, c) in input.iter().enumerate() {
@@ -238,6 +272,12 @@ mod tests {
 .......#..
 #...#.....";
         assert_eq!("374", process_fastest(input)?);
+        assert_eq!("374", process(input)?);
+        #[cfg(feature = "parallel")]
+        assert_eq!("374", process_parallel(input)?);
+        insta::assert_debug_snapshot!("expanded_galaxies", expanded_galaxies(input));
+        // Taller than wide
+        assert_eq!("5", process_fastest("#\n.\n.\n#\n")?);
         Ok(())
     }
 }
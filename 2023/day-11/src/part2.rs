@@ -1,4 +1,18 @@
+use aoc_common::params::{ParamSpec, Params};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
 use crate::custom_error::AocError;
+use crate::schema;
+
+pub const PARAMS: &[ParamSpec] = &[ParamSpec {
+    name: "expansion",
+    help: "How many times larger each empty row and column becomes",
+    default: 1_000_000,
+    min: 1,
+    max: u32::MAX as u64,
+}];
 
 #[derive(Debug, Default, Clone, Copy)]
 struct Vertex {
@@ -20,13 +34,62 @@ impl Vertex {
 
 // Brute force O(n^2) approach
 pub fn process(input: &str) -> miette::Result<String, AocError> {
+    process_with_params(input, &Params::defaults(PARAMS))
+}
+
+pub fn process_with_params(input: &str, params: &Params) -> miette::Result<String, AocError> {
+    schema::check(input)?;
+    let galaxies = expanded_galaxies(input, params);
+
+    // Sum manhattan distance between all galaxy pairs
+    let result = galaxies.iter().enumerate().fold(0, |acc, (index, g1)| {
+        acc + galaxies[(index + 1)..]
+            .iter()
+            .fold(0, |acc_inner, g2| acc_inner + g1.manhattan_dist(g2))
+    });
+
+    Ok(result.to_string())
+}
+
+/// Same as [`process`] with each galaxy's pairs summed on rayon's thread pool
+#[cfg(feature = "parallel")]
+pub fn process_parallel(input: &str) -> miette::Result<String, AocError> {
+    process_parallel_with_params(input, &Params::defaults(PARAMS))
+}
+
+#[cfg(feature = "parallel")]
+pub fn process_parallel_with_params(
+    input: &str,
+    params: &Params,
+) -> miette::Result<String, AocError> {
+    schema::check(input)?;
+    let galaxies = expanded_galaxies(input, params);
+
+    let result = galaxies
+        .par_iter()
+        .enumerate()
+        .map(|(index, g1)| {
+            galaxies[(index + 1)..]
+                .iter()
+                .map(|g2| g1.manhattan_dist(g2))
+                .sum::<i64>()
+        })
+        .sum::<i64>();
+
+    Ok(result.to_string())
+}
+
+/// Galaxy coordinates after every empty row and column grew by the `expansion` parameter
+fn expanded_galaxies(input: &str, params: &Params) -> Vec<Vertex> {
+    // each empty row/column is replaced by `expansion` of them, so it grows by one less
+    let growth = params.get::<i64>("expansion") - 1;
     let row_length = input.find('\n').unwrap() + 1;
     let column_length = input.len() / row_length + 1;
 
     let input = input.as_bytes();
 
-    let mut empty_rows = vec![999_999; column_length];
-    let mut empty_columns = vec![999_999; row_length];
+    let mut empty_rows = vec![growth; column_length];
+    let mut empty_columns = vec![growth; row_length];
     let mut galaxies = vec![];
 
     for (index, tile) in input.iter().enumerate() {
@@ -58,20 +121,21 @@ pub fn process(input: &str) -> miette::Result<String, AocError> {
         g.x += empty_columns[g.x as usize];
         g.y += empty_rows[g.y as usize];
     }
-
-    // Sum manhattan distance between all galaxy pairs
-    let result = galaxies.iter().enumerate().fold(0, |acc, (index, g1)| {
-        acc + galaxies[(index + 1)..]
-            .iter()
-            .fold(0, |acc_inner, g2| acc_inner + g1.manhattan_dist(g2))
-    });
-
-    Ok(result.to_string())
+    galaxies
 }
 
 // See part 1 for explanation
 pub fn process_fastest(input: &str) -> miette::Result<String, AocError> {
-    // dim => dimension aka side length (assume square grid).
+    process_fastest_with_params(input, &Params::defaults(PARAMS))
+}
+
+pub fn process_fastest_with_params(
+    input: &str,
+    params: &Params,
+) -> miette::Result<String, AocError> {
+    schema::check(input)?;
+    let growth = params.get::<i128>("expansion") - 1;
+    // dim => dimension aka row length, the grid needn't be square.
     // padding is the number of characters used for new line
     //   with windows it is 1 ("\n") (my test input)
     //   with linux it is 2 ("\r\n") (actual input)
@@ -83,8 +147,9 @@ pub fn process_fastest(input: &str) -> miette::Result<String, AocError> {
 
     let input = input.as_bytes();
 
+    let rows = input.len().div_ceil(dim + padding);
     let mut galaxies_x = vec![0; dim];
-    let mut galaxies_y = vec![0; dim];
+    let mut galaxies_y = vec![0; rows];
     let mut total_galaxies = 0;
 
     for (index, c) in input.iter().enumerate() {
@@ -96,14 +161,14 @@ pub fn process_fastest(input: &str) -> miette::Result<String, AocError> {
         }
     }
 
-    let x_dist = sum_diff_all_pairs(&galaxies_x, total_galaxies);
-    let y_dist = sum_diff_all_pairs(&galaxies_y, total_galaxies);
+    let x_dist = sum_diff_all_pairs(&galaxies_x, total_galaxies, growth);
+    let y_dist = sum_diff_all_pairs(&galaxies_y, total_galaxies, growth);
     let result = x_dist + y_dist;
 
     Ok(result.to_string())
 }
 
-fn sum_diff_all_pairs(galaxy_coords: &[usize], total_galaxies: usize) -> i128 {
+fn sum_diff_all_pairs(galaxy_coords: &[usize], total_galaxies: usize, growth: i128) -> i128 {
     let mut expansion = 0;
     let mut curr_galaxy = 0;
 
@@ -111,7 +176,7 @@ fn sum_diff_all_pairs(galaxy_coords: &[usize], total_galaxies: usize) -> i128 {
         let f = c as i128 * (c as i128 + 2 * curr_galaxy - total_galaxies as i128);
 
         curr_galaxy += c as i128;
-        expansion += (c == 0) as i128 * 999_999;
+        expansion += (c == 0) as i128 * growth;
         
         acc + f * (i as i128 + expansion)
     })
@@ -136,6 +201,17 @@ mod tests {
 #...#.....";
         // assert_eq!("82000210", process(input)?);
         assert_eq!("82000210", process_fastest(input)?);
+
+        for (expansion, expected) in [("10", "1030"), ("100", "8410")] {
+            let overrides = [("expansion".to_string(), expansion.to_string())];
+            let params = Params::resolve(PARAMS, &overrides)?;
+            assert_eq!(expected, process_with_params(input, &params)?);
+            assert_eq!(expected, process_fastest_with_params(input, &params)?);
+            #[cfg(feature = "parallel")]
+            assert_eq!(expected, process_parallel_with_params(input, &params)?);
+        }
+        // Taller than wide
+        assert_eq!("2000001", process_fastest("#\n.\n.\n#\n")?);
         Ok(())
     }
 }
@@ -0,0 +1,30 @@
+//! Shape of the image
+
+use aoc_common::schema::{Checker, InvalidInput};
+
+pub const SHAPE: &str = "a rectangular grid of empty space `.` and galaxies `#`";
+
+pub fn check(input: &str) -> Result<(), InvalidInput> {
+    let mut checker = Checker::new(input, SHAPE);
+    checker.grid(input, ".#");
+    checker.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check() {
+        assert!(check("...#......\n.......#..\n#.........\n").is_ok());
+        let invalid = check("...#\n..*.\n#..").unwrap_err();
+        assert_eq!(
+            [
+                (7..8, "cells should be one of `.#`"),
+                (10..13, "every row should be 4 cells wide like the first"),
+            ]
+            .map(|(span, message)| (span, message.to_string())),
+            invalid.violations()
+        );
+    }
+}
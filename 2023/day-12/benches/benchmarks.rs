@@ -0,0 +1,72 @@
+use aoc2023_day_12::*;
+
+fn main() {
+    // Run registered benchmarks.
+    divan::main();
+}
+
+#[divan::bench]
+fn part1_naive() {
+    part1::process_naive(divan::black_box(include_str!(
+        "../input.txt",
+    )))
+    .unwrap();
+}
+
+#[divan::bench(sample_size = 10, sample_count = 100)]
+fn part1() {
+    part1::process(divan::black_box(include_str!(
+        "../input.txt",
+    )))
+    .unwrap();
+}
+
+#[divan::bench]
+fn part2() {
+    part2::process(divan::black_box(include_str!(
+        "../input.txt",
+    )))
+    .unwrap();
+}
+
+#[cfg(feature = "parallel")]
+#[divan::bench(args = [1, 2, 4, 8])]
+fn part1_parallel(bencher: divan::Bencher, threads: usize) {
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(threads)
+        .build()
+        .unwrap();
+    let input = include_str!("../input.txt");
+    bencher.bench(|| pool.install(|| part1::process_parallel(divan::black_box(input)).unwrap()));
+}
+
+#[cfg(feature = "parallel")]
+#[divan::bench(args = [1, 2, 4, 8])]
+fn part2_parallel(bencher: divan::Bencher, threads: usize) {
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(threads)
+        .build()
+        .unwrap();
+    let input = include_str!("../input.txt");
+    bencher.bench(|| pool.install(|| part2::process_parallel(divan::black_box(input)).unwrap()));
+}
+
+/// The damaged groups' lengths, read like the solvers did before `aoc_common::parse`
+#[divan::bench]
+fn parse_str() -> usize {
+    group_lengths(divan::black_box(include_str!("../input.txt")))
+        .flat_map(|lengths| lengths.split(',').map(|n| n.parse::<usize>().unwrap()))
+        .sum()
+}
+
+/// The same lengths read by the shared parser
+#[divan::bench]
+fn parse_shared() -> usize {
+    group_lengths(divan::black_box(include_str!("../input.txt")))
+        .flat_map(|lengths| aoc_common::parse::numbers::<usize>(lengths).map(Result::unwrap))
+        .sum()
+}
+
+fn group_lengths(input: &str) -> impl Iterator<Item = &str> {
+    input.lines().map(|line| line.split_once(' ').unwrap().1)
+}
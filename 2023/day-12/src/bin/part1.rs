@@ -1,4 +1,4 @@
-use day_08::part1::process;
+use aoc2023_day_12::part1::*;
 use miette::Context;
 
 #[cfg(feature = "dhat-heap")]
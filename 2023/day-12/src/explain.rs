@@ -0,0 +1,62 @@
+//! Intermediates for `aoc run --explain`: the number of arrangements of every row.
+
+use aoc_common::explain::Explanation;
+use aoc_common::params::Params;
+
+use crate::custom_error::AocError;
+use crate::{part1, part2};
+
+pub fn part1(input: &str) -> miette::Result<Explanation, AocError> {
+    let mut explanation = Explanation::new();
+    for (number, line) in input.lines().enumerate() {
+        explanation
+            .record(format!("row {}", number + 1))
+            .field("springs", line)
+            .field("arrangements", part1::process_line(line)?);
+    }
+    Ok(explanation)
+}
+
+/// Arrangements of every row unfolded the default number of times
+pub fn part2(input: &str) -> miette::Result<Explanation, AocError> {
+    let unfold = Params::defaults(part2::PARAMS).get("unfold");
+    let mut explanation = Explanation::new();
+    for (number, line) in input.lines().enumerate() {
+        explanation
+            .record(format!("row {}", number + 1))
+            .field("springs", line)
+            .field("arrangements", part2::process_line(line, unfold)?);
+    }
+    Ok(explanation)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::explain::Value;
+
+    use super::*;
+
+    #[test]
+    fn test_explain() -> miette::Result<()> {
+        let input = "???.### 1,1,3\n?###???????? 3,2,1";
+        let explanation = part1(input)?;
+        assert_eq!("row 2", explanation.records[1].label);
+        assert_eq!(Some(&Value::Int(10)), explanation.records[1].get("arrangements"));
+        assert_eq!(Some(&Value::Int(506250)), part2(input)?.records[1].get("arrangements"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_snapshots() -> miette::Result<()> {
+        let input = "\
+???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1";
+        insta::assert_snapshot!("part1", part1(input)?);
+        insta::assert_snapshot!("part2", part2(input)?);
+        Ok(())
+    }
+}
@@ -1,19 +1,39 @@
+use aoc_common::cancel::CancelToken;
+use aoc_common::lenient::Lenient;
+use aoc_common::parse;
 use itertools::Itertools;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 
 use crate::custom_error::AocError;
+use crate::schema;
 
 // brute force aka checking every possible combination
 pub fn process_naive(input: &str) -> miette::Result<String, AocError> {
-    let result = input.lines().map(process_line_naive).sum::<i32>();
+    process_naive_cancellable(input, &CancelToken::new())
+}
+
+/// Same as [`process_naive`] but gives up with [`AocError::Cancelled`] once `token` is cancelled
+pub fn process_naive_cancellable(
+    input: &str,
+    token: &CancelToken,
+) -> miette::Result<String, AocError> {
+    schema::check(input)?;
+    let total_lines = input.lines().count() as u64;
+    let result = input
+        .lines()
+        .enumerate()
+        .map(|(done_lines, line)| {
+            token.progress(done_lines as u64, total_lines);
+            process_line_naive(line, token)
+        })
+        .sum::<Result<i32, _>>()?;
     Ok(result.to_string())
 }
 
-fn process_line_naive(line: &str) -> i32 {
+fn process_line_naive(line: &str, token: &CancelToken) -> Result<i32, AocError> {
     let (record, group_lengths) = line.split_ascii_whitespace().collect_tuple().unwrap();
-    let group_lengths = group_lengths
-        .split(',')
-        .map(|n| n.parse::<i32>().unwrap())
-        .collect::<Vec<_>>();
+    let group_lengths = parse::numbers::<i32>(group_lengths).collect::<Result<Vec<_>, _>>()?;
     
     let mut current_total = 0;
     let mut q_indices = vec![];
@@ -26,11 +46,19 @@ fn process_line_naive(line: &str) -> i32 {
         }
     }
     let target_total: i32 = group_lengths.iter().sum();
-    let damaged_diff = target_total - current_total;
+    // no arrangement fits if more springs are damaged than the groups add up to, or fewer than
+    // the `?`s could make up
+    let damaged_diff = match usize::try_from(target_total - current_total) {
+        Ok(diff) if diff <= q_indices.len() => diff,
+        _ => return Ok(0),
+    };
     let mut successes = 0;
 
     // iterate over all `damaged_diff`-length combinations of the '?' indices
-    'comb: for comb in q_indices.iter().combinations(damaged_diff as usize) {
+    'comb: for (iteration, comb) in q_indices.iter().combinations(damaged_diff).enumerate() {
+        if CancelToken::should_poll(iteration as u64) {
+            token.check()?;
+        }
         // make a byte copy of record
         let mut record_bytes = record.as_bytes().to_vec();
 
@@ -66,20 +94,30 @@ fn process_line_naive(line: &str) -> i32 {
         }
         successes += 1;
     }
-    successes
+    Ok(successes)
 }
 
 // Dynamic programming approach. Mostly copied from https://github.com/mfornet/advent-of-code-2023/blob/main/src/bin/12.rs
 pub fn process(input: &str) -> miette::Result<String, AocError> {
-    let result = input.lines().map(process_line).sum::<i32>();
+    schema::check(input)?;
+    let result = input.lines().map(process_line).sum::<Result<i32, _>>()?;
     Ok(result.to_string())
 }
 
-fn process_line(line: &str) -> i32 {
+/// Same as [`process`] with the records spread over rayon's thread pool
+#[cfg(feature = "parallel")]
+pub fn process_parallel(input: &str) -> miette::Result<String, AocError> {
+    schema::check(input)?;
+    let result = input
+        .par_lines()
+        .map(process_line)
+        .sum::<Result<i32, _>>()?;
+    Ok(result.to_string())
+}
+
+pub(crate) fn process_line(line: &str) -> Result<i32, AocError> {
     let (record, group_lengths) = line.split_once(' ').unwrap();
-    let group_lengths = group_lengths
-        .split(',')
-        .map(|n| n.parse::<usize>().unwrap());
+    let group_lengths = parse::numbers::<usize>(group_lengths);
 
     let record = record.as_bytes();
 
@@ -92,6 +130,7 @@ fn process_line(line: &str) -> i32 {
     }
 
     for group_length in group_lengths {
+        let group_length = group_length?;
         let mut contiguous = 0;
         dp_curr.fill(0);
 
@@ -107,7 +146,15 @@ fn process_line(line: &str) -> i32 {
         }
         std::mem::swap(&mut dp, &mut dp_curr);
     }
-    *dp.last().unwrap()
+    Ok(*dp.last().unwrap())
+}
+
+/// Same as [`process`] but skips the lines that don't have the input's shape, see
+/// [`crate::schema`], returning the answer over the other lines in [`AocError::Partial`]
+pub fn process_lenient(input: &str) -> miette::Result<String, AocError> {
+    let lines = Lenient::lines(input, schema::check);
+    let answer = lines.answer(process)?;
+    Ok(lines.finish(answer)?)
 }
 
 #[cfg(test)]
@@ -163,6 +210,37 @@ mod tests {
 ????.######..#####. 1,6,5
 ?###???????? 3,2,1";
         assert_eq!("21", process(input)?);
+        assert_eq!("21", process_naive(input)?);
+        #[cfg(feature = "parallel")]
+        assert_eq!("21", process_parallel(input)?);
+        // More damaged springs than the groups have
+        assert_eq!("0", process_naive("###.# 3")?);
+        assert_eq!("0", process("###.# 3")?);
+        assert_eq!("0", process_naive("???# 888888888,1")?);
         Ok(())
     }
+
+    #[test]
+    fn test_process_naive_cancelled() {
+        let token = CancelToken::new();
+        token.cancel();
+        let result = process_naive_cancellable("???.### 1,1,3", &token);
+        assert!(matches!(result, Err(AocError::Cancelled(_))));
+    }
+
+    #[test]
+    fn test_process_lenient() {
+        let input = "???.### 1,1,3\n???.### 1,x\n.??..??...?##. 1,1,3";
+        let Err(AocError::Partial(partial)) = process_lenient(input) else {
+            panic!("line 2 should be skipped");
+        };
+        assert_eq!("5", partial.answer());
+        assert_eq!(1, partial.skipped().len());
+
+        // Nothing left to solve
+        let Err(AocError::Partial(partial)) = process_lenient("???.### 1,x\n") else {
+            panic!("every line should be skipped");
+        };
+        assert_eq!("0", partial.answer());
+    }
 }
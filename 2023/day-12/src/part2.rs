@@ -0,0 +1,144 @@
+use aoc_common::lenient::Lenient;
+use aoc_common::params::{ParamSpec, Params};
+use aoc_common::parse;
+use itertools::Itertools;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use crate::custom_error::AocError;
+use crate::schema;
+
+pub const PARAMS: &[ParamSpec] = &[ParamSpec {
+    name: "unfold",
+    help: "How many copies of each record and its group lengths to join",
+    default: 5,
+    min: 1,
+    max: 20,
+}];
+
+pub fn process(input: &str) -> miette::Result<String, AocError> {
+    process_with_params(input, &Params::defaults(PARAMS))
+}
+
+pub fn process_with_params(input: &str, params: &Params) -> miette::Result<String, AocError> {
+    schema::check(input)?;
+    let unfold = params.get("unfold");
+    let result = input
+        .lines()
+        .map(|line| process_line(line, unfold))
+        .sum::<Result<i128, _>>()?;
+    Ok(result.to_string())
+}
+
+/// Same as [`process`] with the records spread over rayon's thread pool
+#[cfg(feature = "parallel")]
+pub fn process_parallel(input: &str) -> miette::Result<String, AocError> {
+    process_parallel_with_params(input, &Params::defaults(PARAMS))
+}
+
+#[cfg(feature = "parallel")]
+pub fn process_parallel_with_params(
+    input: &str,
+    params: &Params,
+) -> miette::Result<String, AocError> {
+    schema::check(input)?;
+    let unfold = params.get("unfold");
+    let result = input
+        .par_lines()
+        .map(|line| process_line(line, unfold))
+        .sum::<Result<i128, _>>()?;
+    Ok(result.to_string())
+}
+
+pub(crate) fn process_line(line: &str, unfold: usize) -> Result<i128, AocError> {
+    let (record, group_lengths) = line.split_once(' ').unwrap();
+    let group_lengths = parse::numbers::<usize>(group_lengths);
+
+    let group_lengths = std::iter::once(group_lengths).cycle().take(unfold).flatten();
+
+    let record = std::iter::once(record).cycle().take(unfold).join("?");
+    let record = record.as_bytes();
+
+    let mut dp = vec![0; record.len() + 2];
+    let mut dp_curr = vec![0; record.len() + 2];
+
+    dp[0] = 1;
+    for (i, _) in record.iter().take_while(|&&c| c != b'#').enumerate() {
+        dp[i + 1] = 1;
+    }
+
+    for group_length in group_lengths {
+        let group_length = group_length?;
+        let mut contiguous = 0;
+        dp_curr.fill(0);
+
+        for (i, &r) in record.iter().enumerate() {
+            contiguous = (r != b'.') as usize * (contiguous + 1);
+            dp_curr[i + 2] += (r != b'#') as i128 * dp_curr[i + 1];
+
+            if contiguous >= group_length && (i < group_length || record[i - group_length] != b'#')
+            {
+                dp_curr[i + 2] += dp[i + 1 - group_length]
+            }
+        }
+        std::mem::swap(&mut dp, &mut dp_curr);
+    }
+    Ok(*dp.last().unwrap())
+}
+
+/// Same as [`process`] but skips the lines that don't have the input's shape, see
+/// [`crate::schema`], returning the answer over the other lines in [`AocError::Partial`]
+pub fn process_lenient(input: &str) -> miette::Result<String, AocError> {
+    let lines = Lenient::lines(input, schema::check);
+    let answer = lines.answer(process)?;
+    Ok(lines.finish(answer)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_process0() -> miette::Result<()> {
+        let input = "???.### 1,1,3";
+        assert_eq!("1", process(input)?);
+
+        let input = ".??..??...?##. 1,1,3";
+        assert_eq!("16384", process(input)?);
+
+        let input = "?#?#?#?#?#?#?#? 1,3,1,6";
+        assert_eq!("1", process(input)?);
+
+        let input = "????.#...#... 4,1,1";
+        assert_eq!("16", process(input)?);
+
+        let input = "????.######..#####. 1,6,5";
+        assert_eq!("2500", process(input)?);
+
+        let input = "?###???????? 3,2,1";
+        assert_eq!("506250", process(input)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_process() -> miette::Result<()> {
+        let input = "\
+???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1";
+        assert_eq!("525152", process(input)?);
+        #[cfg(feature = "parallel")]
+        assert_eq!("525152", process_parallel(input)?);
+
+        // Without unfolding this is part 1
+        let params = Params::resolve(PARAMS, &[("unfold".to_string(), "1".to_string())])?;
+        assert_eq!("21", process_with_params(input, &params)?);
+        #[cfg(feature = "parallel")]
+        assert_eq!("21", process_parallel_with_params(input, &params)?);
+        Ok(())
+    }
+}
\ No newline at end of file
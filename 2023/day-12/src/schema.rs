@@ -0,0 +1,45 @@
+//! Shape of the condition records
+
+use aoc_common::schema::{Checker, InvalidInput};
+
+pub const SHAPE: &str = "lines like `???.### 1,1,3`, springs out of `.#?` and the sizes of the \
+                         damaged groups separated by commas";
+
+pub fn check(input: &str) -> Result<(), InvalidInput> {
+    let mut checker = Checker::new(input, SHAPE);
+    for line in checker.lines() {
+        let Some((springs, groups)) = checker.split(line, " ") else {
+            continue;
+        };
+        checker.expect(!springs.is_empty(), line, "the springs should come first");
+        checker.chars(
+            springs,
+            |spring| ".#?".contains(spring),
+            "springs should be `.`, `#` or `?`",
+        );
+        for group in groups.split(',') {
+            checker.number(group, "the group size");
+        }
+    }
+    checker.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check() {
+        assert!(check("???.### 1,1,3\n.??..??...?##. 1,1,3\n").is_ok());
+        let invalid = check("???.### 1,,3\n?x? 1\n.#.").unwrap_err();
+        assert_eq!(
+            [
+                (10..10, "the group size should be a number"),
+                (14..15, "springs should be `.`, `#` or `?`"),
+                (19..22, "should contain ` `"),
+            ]
+            .map(|(span, message)| (span, message.to_string())),
+            invalid.violations()
+        );
+    }
+}
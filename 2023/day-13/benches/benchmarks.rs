@@ -0,0 +1,33 @@
+use aoc2023_day_13::*;
+use aoc_common::isa::Isa;
+
+fn main() {
+    // Run registered benchmarks.
+    divan::main();
+}
+
+#[divan::bench]
+fn part1() {
+    part1::process(divan::black_box(include_str!(
+        "../input.txt",
+    )))
+    .unwrap();
+}
+
+#[divan::bench]
+fn part2() {
+    part2::process(divan::black_box(include_str!(
+        "../input.txt",
+    )))
+    .unwrap();
+}
+
+#[divan::bench(args = Isa::supported())]
+fn part1_simd(isa: Isa) {
+    simd::summarize(isa, divan::black_box(include_str!("../input.txt")), 0).unwrap();
+}
+
+#[divan::bench(args = Isa::supported())]
+fn part2_simd(isa: Isa) {
+    simd::summarize(isa, divan::black_box(include_str!("../input.txt")), 1).unwrap();
+}
@@ -1,33 +1,47 @@
-use crate::custom_error::AocError;
+use aoc_common::isa::Isa;
 
-// use 2 for CRLF, 1 for LF
-const PADDING: usize = 2;
+use crate::custom_error::AocError;
+use crate::schema;
+use crate::simd;
 
 pub fn process(input: &str) -> miette::Result<String, AocError> {
-    let patterns = input.split("\r\n\r\n"); // use "\n\n" for LF
+    schema::check(input)?;
+    // line breaks are 2 bytes with CRLF, 1 with LF
+    let (separator, padding) = match input.contains("\r\n") {
+        true => ("\r\n\r\n", 2),
+        false => ("\n\n", 1),
+    };
+    let patterns = input.trim_end().split(separator);
     let mut result = 0;
 
     'patterns: for pattern in patterns {
         // check for reflection at row
         let row_length = pattern.lines().next().unwrap().len();
-        for row in 0..pattern.len() / (row_length + PADDING) {
-            if is_reflected_at_row(pattern, row, row_length) {
+        for row in 0..pattern.len() / (row_length + padding) {
+            if is_reflected_at_row(pattern, row, row_length + padding) {
                 result += (row + 1) * 100;
                 continue 'patterns;
             }
         }
         // check for reflection at column
-        for col in 0..row_length {
+        for col in 0..row_length - 1 {
             if is_reflected_at_column(pattern, col) {
                 result += col + 1;
                 continue 'patterns;
             }
         }
-        panic!("No reflection found for pattern:\n{pattern}");
+        return Err(AocError::NoReflection);
     }
     Ok(result.to_string())
 }
 
+/// Compares rows with the widest SIMD instructions the CPU has, see [`crate::simd`]
+pub fn process_simd(input: &str) -> miette::Result<String, AocError> {
+    schema::check(input)?;
+    let result = simd::summarize(Isa::selected(), input, 0).ok_or(AocError::NoReflection)?;
+    Ok(result.to_string())
+}
+
 fn is_reflected_at_column(pattern: &str, col: usize) -> bool {
     // check for reflection around column line by line, moving outwards from reflection column
     for line in pattern.lines() {
@@ -41,12 +55,13 @@ fn is_reflected_at_column(pattern: &str, col: usize) -> bool {
     true
 }
 
-fn is_reflected_at_row(pattern: &str, row: usize, row_length: usize) -> bool {
+/// `stride` is the length of a row including its line break
+fn is_reflected_at_row(pattern: &str, row: usize, stride: usize) -> bool {
     // check for reflection around row, moving outwards from reflection row
-    !pattern[..(row + 1) * (row_length + PADDING)]
+    !pattern[..(row + 1) * stride]
         .lines()
         .rev()
-        .zip(pattern[(row + 1) * (row_length + PADDING)..].lines())
+        .zip(pattern[(row + 1) * stride..].lines())
         .any(|(a, b)| a != b)
 }
 
@@ -73,6 +88,10 @@ mod tests {
 ..##..###\r
 #....#..#";
         assert_eq!("405", process(input)?);
+        assert_eq!("405", process_simd(input)?);
+        let lf = format!("{}\n", input.replace("\r\n", "\n"));
+        assert_eq!("405", process(&lf)?);
+        assert!(matches!(process("#.\n.#\n"), Err(AocError::NoReflection)));
         Ok(())
     }
 }
@@ -1,17 +1,24 @@
-use crate::custom_error::AocError;
+use aoc_common::isa::Isa;
 
-// use 2 for CRLF, 1 for LF
-const PADDING: usize = 2;
+use crate::custom_error::AocError;
+use crate::schema;
+use crate::simd;
 
 pub fn process(input: &str) -> miette::Result<String, AocError> {
-    let patterns = input.split("\r\n\r\n"); // use "\n\n" for LF
+    schema::check(input)?;
+    // line breaks are 2 bytes with CRLF, 1 with LF
+    let (separator, padding) = match input.contains("\r\n") {
+        true => ("\r\n\r\n", 2),
+        false => ("\n\n", 1),
+    };
+    let patterns = input.trim_end().split(separator);
     let mut result = 0;
 
     'patterns: for pattern in patterns {
         // check for reflection at row
         let row_length = pattern.lines().next().unwrap().len();
-        for row in 0..pattern.len() / (row_length + PADDING) {
-            if is_reflected_at_row(pattern, row, row_length) {
+        for row in 0..pattern.len() / (row_length + padding) {
+            if is_reflected_at_row(pattern, row, row_length + padding) {
                 result += (row + 1) * 100;
                 continue 'patterns;
             }
@@ -23,11 +30,18 @@ pub fn process(input: &str) -> miette::Result<String, AocError> {
                 continue 'patterns;
             }
         }
-        panic!("No reflection found for pattern:\n{pattern}");
+        return Err(AocError::NoReflection);
     }
     Ok(result.to_string())
 }
 
+/// Compares rows with the widest SIMD instructions the CPU has, see [`crate::simd`]
+pub fn process_simd(input: &str) -> miette::Result<String, AocError> {
+    schema::check(input)?;
+    let result = simd::summarize(Isa::selected(), input, 1).ok_or(AocError::NoReflection)?;
+    Ok(result.to_string())
+}
+
 fn is_reflected_at_column(pattern: &str, col: usize) -> bool {
     // there must be exactly one character different in the reflection to return true
     let mut one_diff = false;
@@ -47,19 +61,20 @@ fn is_reflected_at_column(pattern: &str, col: usize) -> bool {
     one_diff
 }
 
-fn is_reflected_at_row(pattern: &str, row: usize, row_length: usize) -> bool {
+/// `stride` is the length of a row including its line break
+fn is_reflected_at_row(pattern: &str, row: usize, stride: usize) -> bool {
     // there must be exactly one character different in the reflection to return true
     let mut one_diff = false;
 
     // check for reflection around row, moving outwards from reflection row
-    for (a, b) in pattern[..(row + 1) * (row_length + PADDING)]
+    for (a, b) in pattern[..(row + 1) * stride]
         .lines()
         .rev()
-        .zip(pattern[(row + 1) * (row_length + PADDING)..].lines())
+        .zip(pattern[(row + 1) * stride..].lines())
         .map(|(a, b)| (a.as_bytes(), b.as_bytes()))
     {
-        for i in 0..row_length {
-            if a[i] != b[i] {
+        for (a, b) in a.iter().zip(b) {
+            if a != b {
                 if one_diff {
                     return false;
                 }
@@ -93,6 +108,10 @@ mod tests {
 ..##..###\r
 #....#..#";
         assert_eq!("400", process(input)?);
+        assert_eq!("400", process_simd(input)?);
+        let lf = format!("{}\n", input.replace("\r\n", "\n"));
+        assert_eq!("400", process(&lf)?);
+        assert!(matches!(process("#.\n.#\n"), Err(AocError::NoReflection)));
         Ok(())
     }
 }
@@ -0,0 +1,50 @@
+//! Shape of the notes
+
+use aoc_common::schema::{Checker, InvalidInput};
+
+use crate::simd::MAX_SIZE;
+
+pub const SHAPE: &str = "rectangular grids of ash `.` and rocks `#` at most 32 cells wide and \
+                         tall, separated by blank lines";
+
+pub fn check(input: &str) -> Result<(), InvalidInput> {
+    let mut checker = Checker::new(input, SHAPE);
+    // The scalar solvers also take CRLF line endings, though not mixed with LF
+    let patterns = input.trim_end_matches(['\r', '\n']).split("\n\n");
+    for pattern in patterns.flat_map(|pattern| pattern.split("\r\n\r\n")) {
+        checker.grid(pattern, ".#");
+        let (width, height) = (pattern.find(['\r', '\n']), pattern.lines().count());
+        checker.expect(
+            width.unwrap_or(pattern.len()) <= MAX_SIZE && height <= MAX_SIZE,
+            pattern,
+            format!("patterns should be at most {MAX_SIZE} cells wide and tall"),
+        );
+    }
+    let crlf = input.matches("\r\n").count();
+    checker.expect(
+        crlf == input.matches('\r').count() && (crlf == 0 || crlf == input.matches('\n').count()),
+        input,
+        "lines should all end in LF or all in CRLF",
+    );
+    checker.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check() {
+        assert!(check("#.##..#\n..#.##.\n\n#...##.\n#....#.\n").is_ok());
+        assert!(check("#.##..#\r\n..#.##.\r\n\r\n#...##.\r\n#....#.\r\n").is_ok());
+        assert!(check(&format!("{0}\n{0}\n", "#".repeat(33))).is_err());
+        assert!(check("#.\r\n.#\n").is_err());
+        assert!(check("#.\r\n.#\r").is_err());
+        assert!(check("#.\n.#\n\n\t\n").is_err());
+        let invalid = check("#.#\n..#\n\n\n#..\n").unwrap_err();
+        assert_eq!(
+            [(9..13, "the grid should have at least two rows".to_string())],
+            invalid.violations()
+        );
+    }
+}
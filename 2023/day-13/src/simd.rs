@@ -0,0 +1,115 @@
+//! Both parts as comparisons of fixed-width rows, which vectorize. Each pattern's rows, and its
+//! columns as rows of the transposed pattern, are zero-padded to [`MAX_SIZE`] cells, so counting
+//! the cells two rows differ in is a single vector compare for the widths of the inputs.
+//!
+//! Like the scalar solvers this takes both LF and CRLF line endings.
+
+/// Widest and tallest pattern supported
+pub const MAX_SIZE: usize = 32;
+
+type Row = [u8; MAX_SIZE];
+
+aoc_common::multiversion! {
+    /// Sum of the notes of every pattern, counting only reflections whose two sides differ in
+    /// exactly `smudges` cells: 0 for part 1 and 1 for part 2. `None` if a pattern has no such
+    /// reflection.
+    pub fn summarize(input: &str, smudges: u32) -> Option<usize> = summarize_kernel;
+}
+
+#[inline(always)]
+fn summarize_kernel(input: &str, smudges: u32) -> Option<usize> {
+    let mut sum = 0;
+    let mut rows = vec![];
+    let mut width = 0;
+    // A blank line after the last pattern ends it like the others
+    for line in input.lines().chain([""]) {
+        if !line.is_empty() {
+            assert!(
+                line.len() <= MAX_SIZE,
+                "patterns should be at most {MAX_SIZE} wide"
+            );
+            let mut row = [0; MAX_SIZE];
+            row[..line.len()].copy_from_slice(line.as_bytes());
+            rows.push(row);
+            width = line.len();
+        } else if !rows.is_empty() {
+            sum += summarize_pattern(&rows, width, smudges)?;
+            rows.clear();
+        }
+    }
+    Some(sum)
+}
+
+#[inline(always)]
+fn summarize_pattern(rows: &[Row], width: usize, smudges: u32) -> Option<usize> {
+    if let Some(above) = reflection(rows, smudges) {
+        return Some(100 * above);
+    }
+    assert!(
+        rows.len() <= MAX_SIZE,
+        "patterns should be at most {MAX_SIZE} tall"
+    );
+    let mut columns = vec![[0; MAX_SIZE]; width];
+    for (y, row) in rows.iter().enumerate() {
+        for (column, &cell) in columns.iter_mut().zip(row) {
+            column[y] = cell;
+        }
+    }
+    reflection(&columns, smudges)
+}
+
+/// Number of rows before the first mirror whose two sides differ in exactly `smudges` cells
+#[inline(always)]
+fn reflection(rows: &[Row], smudges: u32) -> Option<usize> {
+    (1..rows.len()).find(|&mirror| {
+        let mut differences = 0;
+        for (a, b) in rows[..mirror].iter().rev().zip(&rows[mirror..]) {
+            differences += row_differences(a, b);
+            if differences > smudges {
+                return false;
+            }
+        }
+        differences == smudges
+    })
+}
+
+#[inline(always)]
+fn row_differences(a: &Row, b: &Row) -> u32 {
+    a.iter().zip(b).map(|(a, b)| u32::from(a != b)).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use aoc_common::isa::Isa;
+
+    const INPUT: &str = "\
+#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#.
+
+#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#
+";
+
+    #[test]
+    fn test_summarize() {
+        let crlf = INPUT.replace('\n', "\r\n");
+        for isa in Isa::supported() {
+            assert_eq!(Some(405), summarize(isa, INPUT, 0), "{isa}");
+            assert_eq!(Some(400), summarize(isa, INPUT, 1), "{isa}");
+            assert_eq!(Some(405), summarize(isa, &crlf, 0), "{isa}");
+            assert_eq!(None, summarize(isa, "#.\n.#\n", 0), "{isa}");
+        }
+    }
+}
@@ -1,4 +1,7 @@
-use day_18::*;
+use std::hash::BuildHasher;
+
+use aoc2023_day_14::*;
+use aoc_common::hash::{AHashState, FxState, SeaState, StdState};
 
 fn main() {
     // Run registered benchmarks.
@@ -12,13 +15,6 @@ fn part1() {
     )))
     .unwrap();
 }
-#[divan::bench]
-fn part1_bytes() {
-    part1::process_bytes(divan::black_box(include_str!(
-        "../input.txt",
-    )))
-    .unwrap();
-}
 
 #[divan::bench]
 fn part2() {
@@ -27,11 +23,7 @@ fn part2() {
     )))
     .unwrap();
 }
-
-#[divan::bench]
-fn part2_bytes() {
-    part2::process_bytes(divan::black_box(include_str!(
-        "../input.txt",
-    )))
-    .unwrap();
-}
\ No newline at end of file
+#[divan::bench(types = [StdState, AHashState, FxState, SeaState])]
+fn part2_hasher<S: BuildHasher + Default>() {
+    part2::process_with_hasher::<S>(divan::black_box(include_str!("../input.txt"))).unwrap();
+}
@@ -0,0 +1,58 @@
+//! Intermediates for `aoc run --explain`: the period the spin cycles settle into.
+
+use aoc_common::cancel::CancelToken;
+use aoc_common::explain::Explanation;
+use aoc_common::params::Params;
+
+use crate::custom_error::AocError;
+use crate::part2::{spin, Platform, PARAMS};
+
+pub fn part2(input: &str) -> miette::Result<Explanation, AocError> {
+    let total_spin_cycles = Params::defaults(PARAMS).get("spin_cycles");
+    let mut platform = Platform::new(input);
+    let period = spin(&mut platform, total_spin_cycles, &CancelToken::new())?;
+
+    let mut explanation = Explanation::new();
+    let record = explanation.record("spin cycles");
+    record.field("total", total_spin_cycles);
+    match period {
+        Some(period) => {
+            let state = period.start + (total_spin_cycles - period.start) % period.length;
+            record
+                .field("period_start", period.start)
+                .field("period_length", period.length)
+                .field("same_as_cycle", state)
+        }
+        None => record.field("period_found", false),
+    }
+    .field("north_load", platform.calculate_north_load());
+    Ok(explanation)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::explain::Value;
+
+    use super::*;
+
+    #[test]
+    fn test_explain() -> miette::Result<()> {
+        let input = "\
+O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....";
+        let explanation = part2(input)?;
+        let record = &explanation.records[0];
+        assert_eq!(Some(&Value::Int(3)), record.get("period_start"));
+        assert_eq!(Some(&Value::Int(7)), record.get("period_length"));
+        assert_eq!(Some(&Value::Int(64)), record.get("north_load"));
+        Ok(())
+    }
+}
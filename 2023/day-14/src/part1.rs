@@ -1,8 +1,10 @@
 use crate::custom_error::AocError;
+use crate::schema;
 
 pub fn process(input: &str) -> miette::Result<String, AocError> {
+    schema::check(input)?;
     let width = input.find('\n').unwrap();
-    let height = width + 1;
+    let height = input.lines().count() + 1;
     let input = input.as_bytes();
 
     let mut column_loads = vec![height - 1; width];
@@ -41,6 +43,8 @@ O.#..O.#.#
 #....###..
 #OO..#....";
         assert_eq!("136", process(input)?);
+        // Taller than wide
+        assert_eq!("3", process("O\nO\n")?);
         Ok(())
     }
 }
@@ -1,9 +1,18 @@
 use crate::custom_error::AocError;
+use crate::schema;
 
+use aoc_common::cancel::{CancelToken, Cancelled};
+use aoc_common::frame::{Cell, Colour, Frame};
+use aoc_common::hash::FastState;
+use aoc_common::params::{ParamSpec, Params};
+use aoc_common::progress;
 use indexmap::IndexSet;
 use itertools::Itertools;
+use std::hash::BuildHasher;
 
-struct Platform {
+/// The dish of rounded (`O`) and cube-shaped (`#`) rocks, tilted in place
+#[derive(Clone)]
+pub struct Platform {
     grid: String,
     width: usize,
     height: usize,
@@ -16,9 +25,11 @@ impl Platform {
     const EMPTY: u8 = b'.';
 
     pub fn new(grid: &str) -> Platform {
+        // The tilts find the last row from the end of the grid
+        let grid = grid.trim_end_matches('\n');
         let size = grid.len();
         let width = grid.find('\n').unwrap();
-        let height = size / width;
+        let height = size.div_ceil(width + 1);
         let grid = grid.to_string();
 
         Platform {
@@ -112,13 +123,14 @@ impl Platform {
         // Need to scan right to left
         for i in (0..self.size).rev() {
             match grid[i] {
+                // Saturating at the first tile of the grid, which has nothing left of it
                 Platform::BLOCK | b'\n' => {
-                    free_slot = i - 1;
+                    free_slot = i.saturating_sub(1);
                 }
                 Platform::BALL => {
                     grid[i] = Platform::EMPTY;
                     grid[free_slot] = Platform::BALL;
-                    free_slot -= 1;
+                    free_slot = free_slot.saturating_sub(1);
                 }
                 _ => (),
             }
@@ -144,15 +156,110 @@ impl Platform {
     }
 }
 
+impl Frame for Platform {
+    fn width(&self) -> usize {
+        self.width
+    }
+
+    fn height(&self) -> usize {
+        self.grid.len().div_ceil(self.width + 1)
+    }
+
+    fn cell(&self, x: usize, y: usize) -> Cell {
+        let tile = self.grid.as_bytes()[y * (self.width + 1) + x];
+        let colour = match tile {
+            Platform::BALL => Colour::Yellow,
+            Platform::BLOCK => Colour::Default,
+            _ => Colour::Dim,
+        };
+        Cell::new(tile as char, colour)
+    }
+
+    fn caption(&self) -> String {
+        format!("north load {}", self.calculate_north_load())
+    }
+}
+
+pub const PARAMS: &[ParamSpec] = &[ParamSpec {
+    name: "spin_cycles",
+    help: "Number of spin cycles to run before measuring the load",
+    default: 1_000_000_000,
+    min: 0,
+    max: u64::MAX,
+}];
+
+/// The platform of a whole puzzle input
+pub fn parse(input: &str) -> Result<Platform, AocError> {
+    schema::check(input)?;
+    Ok(Platform::new(input))
+}
+
 pub fn process(input: &str) -> miette::Result<String, AocError> {
-    let mut platform = Platform::new(input);
+    process_cancellable(input, &CancelToken::new())
+}
 
-    // Using IndexSet to index the set by insertion order
-    let mut grid_states: IndexSet<String, ahash::RandomState> = IndexSet::default();
+/// Same as [`process`] but gives up with [`AocError::Cancelled`] once `token` is cancelled. Only
+/// matters for inputs that don't settle into a cycle quickly.
+pub fn process_cancellable(
+    input: &str,
+    token: &CancelToken,
+) -> miette::Result<String, AocError> {
+    process_with_params(input, &Params::defaults(PARAMS), token)
+}
 
-    let total_spin_cycles = 1_000_000_000;
+pub fn process_with_params(
+    input: &str,
+    params: &Params,
+    token: &CancelToken,
+) -> miette::Result<String, AocError> {
+    let mut platform = parse(input)?;
+    spin(&mut platform, params.get("spin_cycles"), token)?;
+    Ok(platform.calculate_north_load().to_string())
+}
+
+/// Same as [`process`] with the seen states hashed by `S`, for comparing hashers
+pub fn process_with_hasher<S: BuildHasher + Default>(
+    input: &str,
+) -> miette::Result<String, AocError> {
+    let mut platform = parse(input)?;
+    let total_spin_cycles = Params::defaults(PARAMS).get("spin_cycles");
+    spin_with_hasher::<S>(&mut platform, total_spin_cycles, &CancelToken::new())?;
+    Ok(platform.calculate_north_load().to_string())
+}
+
+/// Where the platform starts repeating itself
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Period {
+    /// Number of spin cycles until the repeated state is first reached
+    pub start: usize,
+    /// Number of spin cycles between repeats
+    pub length: usize,
+}
+
+/// Leaves `platform` as it is after `total_spin_cycles` spin cycles, skipping ahead once a state
+/// repeats. Returns the period if one was found within `total_spin_cycles`.
+pub fn spin(
+    platform: &mut Platform,
+    total_spin_cycles: usize,
+    token: &CancelToken,
+) -> Result<Option<Period>, Cancelled> {
+    spin_with_hasher::<FastState>(platform, total_spin_cycles, token)
+}
+
+fn spin_with_hasher<S: BuildHasher + Default>(
+    platform: &mut Platform,
+    total_spin_cycles: usize,
+    token: &CancelToken,
+) -> Result<Option<Period>, Cancelled> {
+    // Using IndexSet to index the set by insertion order
+    let mut grid_states: IndexSet<String, S> = IndexSet::default();
 
     for i in 0..total_spin_cycles {
+        if CancelToken::should_poll(i as u64) {
+            token.check()?;
+            // The loop ends once the platform repeats itself, long before `total_spin_cycles`
+            token.progress(i as u64, progress::UNKNOWN_TOTAL);
+        }
         platform.spin_cycle();
 
         let (first_occurrence, is_new_state) = grid_states.insert_full(platform.grid.clone());
@@ -163,11 +270,14 @@ pub fn process(input: &str) -> miette::Result<String, AocError> {
 
             platform.grid = grid_states.swap_remove_index(final_state_index).unwrap();
 
-            return Ok(platform.calculate_north_load().to_string());
+            return Ok(Some(Period {
+                start: first_occurrence + 1,
+                length: period,
+            }));
         }
     }
     // Cycle not found
-    Ok(platform.calculate_north_load().to_string())
+    Ok(None)
 }
 
 #[cfg(test)]
@@ -188,6 +298,25 @@ O.#..O.#.#
 #....###..
 #OO..#....";
         assert_eq!("64", process(input)?);
+        assert_eq!("64", process(&format!("{input}\n"))?);
+        // Taller than wide
+        assert_eq!("3", process("O\nO\n")?);
+
+        // Few enough cycles to check against spinning every one of them
+        for spin_cycles in [0, 1, 3, 20] {
+            let mut platform = Platform::new(input);
+            for _ in 0..spin_cycles {
+                platform.spin_cycle();
+            }
+            let params = Params::resolve(
+                PARAMS,
+                &[("spin_cycles".to_string(), spin_cycles.to_string())],
+            )?;
+            assert_eq!(
+                platform.calculate_north_load().to_string(),
+                process_with_params(input, &params, &CancelToken::new())?
+            );
+        }
         Ok(())
     }
 }
@@ -0,0 +1,31 @@
+//! Shape of the platform
+
+use aoc_common::schema::{Checker, InvalidInput};
+
+pub const SHAPE: &str = "a rectangular grid of rounded rocks `O`, cube-shaped rocks `#` and empty \
+                         space `.`, with LF line endings";
+
+pub fn check(input: &str) -> Result<(), InvalidInput> {
+    let mut checker = Checker::new(input, SHAPE);
+    checker.grid(input, "O#.");
+    // The tilts would roll rocks onto the `\r` of CRLF
+    checker.chars(input, |c| c != '\r', "lines should end in LF, not CRLF");
+    checker.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check() {
+        assert!(check("O....#....\nO.OO#....#\n").is_ok());
+        assert!(check("O.#.O").is_err());
+        assert!(check("O.\r\n#.\r\n").is_err());
+        let invalid = check("O..\n.0.\n").unwrap_err();
+        assert_eq!(
+            [(5..6, "cells should be one of `O#.`".to_string())],
+            invalid.violations()
+        );
+    }
+}
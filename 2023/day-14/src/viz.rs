@@ -0,0 +1,64 @@
+//! Frames for `aoc-viz`: the platform after every tilt.
+
+use crate::part2::Platform;
+
+/// Part 1 tilts north once. Part 2 keeps spinning (north, west, south then east) for as long as
+/// the viewer asks for frames.
+pub fn frames(input: &str, part: u8) -> impl Iterator<Item = Platform> {
+    let tilts: &[fn(&mut Platform)] = if part == 1 {
+        &[Platform::tilt_north]
+    } else {
+        &[
+            Platform::tilt_north,
+            Platform::tilt_west,
+            Platform::tilt_south,
+            Platform::tilt_east,
+        ]
+    };
+    let limit = if part == 1 { 1 } else { usize::MAX };
+
+    let mut tilt = tilts.iter().cycle().take(limit);
+    std::iter::successors(Some(Platform::new(input)), move |platform| {
+        let tilt = tilt.next()?;
+        let mut next = platform.clone();
+        tilt(&mut next);
+        Some(next)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use aoc_common::frame::{to_text, Frame};
+
+    const INPUT: &str = "\
+O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....";
+
+    #[test]
+    fn test_frames() {
+        let tilted = frames(INPUT, 1).collect::<Vec<_>>();
+        assert_eq!(2, tilted.len());
+        assert_eq!("north load 136", tilted[1].caption());
+        assert_eq!((10, 10), (tilted[1].width(), tilted[1].height()));
+
+        // One spin cycle is four tilts
+        let spun = frames(INPUT, 2).nth(4).unwrap();
+        let mut expected = Platform::new(INPUT);
+        expected.spin_cycle();
+        assert_eq!(expected.caption(), spun.caption());
+        assert_eq!('O', spun.cell(8, 1).glyph);
+
+        insta::assert_snapshot!("tilted_north", to_text(&tilted[1]));
+        insta::assert_snapshot!("three_cycles", to_text(&frames(INPUT, 2).nth(12).unwrap()));
+    }
+}
@@ -1,4 +1,5 @@
-use day_19::*;
+use aoc2023_day_15::*;
+use aoc_common::isa::Isa;
 
 fn main() {
     // Run registered benchmarks.
@@ -21,10 +22,7 @@ fn part2() {
     .unwrap();
 }
 
-#[divan::bench]
-fn part2_dfs() {
-    part2_dfs::process(divan::black_box(include_str!(
-        "../input.txt",
-    )))
-    .unwrap();
-}
\ No newline at end of file
+#[divan::bench(args = Isa::supported())]
+fn part1_simd(isa: Isa) {
+    simd::hash_sum(isa, divan::black_box(include_bytes!("../input.txt")));
+}
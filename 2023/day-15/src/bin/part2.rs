@@ -1,4 +1,4 @@
-use day_15::part2::*;
+use aoc2023_day_15::part2::*;
 use miette::Context;
 
 #[cfg(feature = "dhat-heap")]
@@ -0,0 +1,24 @@
+//! Allocation-free core of the HASH algorithm, built without `std` too.
+
+/// Sum of the HASH of every comma-separated step
+pub fn hash_sum(input: &[u8]) -> u32 {
+    input.split(|&c| c == b',').map(hash).sum()
+}
+
+pub fn hash(step: &[u8]) -> u32 {
+    step.iter().fold(0, |acc, &c| (acc + c as u32) * 17 % 256)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hash() {
+        assert_eq!(52, hash(b"HASH"));
+        assert_eq!(
+            1320,
+            hash_sum(b"rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7")
+        );
+    }
+}
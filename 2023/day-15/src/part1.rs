@@ -0,0 +1,30 @@
+use aoc_common::isa::Isa;
+
+use crate::bytes;
+use crate::custom_error::AocError;
+use crate::schema;
+use crate::simd;
+
+pub fn process(input: &str) -> miette::Result<String, AocError> {
+    schema::check(input)?;
+    Ok(bytes::hash_sum(input.as_bytes()).to_string())
+}
+
+/// Hashes with the widest SIMD instructions the CPU has, see [`crate::simd`]
+pub fn process_simd(input: &str) -> miette::Result<String, AocError> {
+    schema::check(input)?;
+    Ok(simd::hash_sum(Isa::selected(), input.as_bytes()).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_process() -> miette::Result<()> {
+        let input = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
+        assert_eq!("1320", process(input)?);
+        assert_eq!("1320", process_simd(input)?);
+        Ok(())
+    }
+}
@@ -1,4 +1,5 @@
 use crate::custom_error::AocError;
+use crate::schema;
 
 // Store label and focal_length as byte slices to avoid unnecessary type conversion
 #[derive(Clone, Copy)]
@@ -32,10 +33,13 @@ impl<'a> std::fmt::Debug for Lens<'a> {
     }
 }
 
-pub fn process(input: &str) -> miette::Result<String, AocError> {
+/// The lenses in each box after every step of the initialization sequence
+fn arrange(input: &str) -> [Vec<Lens<'_>>; 256] {
     let mut lens_boxes: [Vec<Lens>; 256] = std::array::from_fn(|_| vec![]);
 
-    for step in input.as_bytes().split(|&c| c == b',') {
+    // The sequence may end with a newline, like the schema allows
+    let sequence = input.trim_end_matches('\n');
+    for step in sequence.as_bytes().split(|&c| c == b',') {
         let mut step_index = 0;
         let mut box_index = 0;
         loop {
@@ -69,6 +73,12 @@ pub fn process(input: &str) -> miette::Result<String, AocError> {
             step_index += 1;
         }
     }
+    lens_boxes
+}
+
+pub fn process(input: &str) -> miette::Result<String, AocError> {
+    schema::check(input)?;
+    let lens_boxes = arrange(input);
 
     // Calculate focusing power
     let result = lens_boxes
@@ -101,6 +111,10 @@ mod tests {
     fn test_process() -> miette::Result<()> {
         let input = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
         assert_eq!("145", process(input)?);
+        assert_eq!("145", process(&format!("{input}\n"))?);
+        // The lenses all end up in boxes 0 and 3
+        let arranged = arrange(input);
+        insta::assert_debug_snapshot!("arranged", [&arranged[0], &arranged[3]]);
         Ok(())
     }
 }
@@ -0,0 +1,57 @@
+//! Shape of the initialization sequence
+
+use aoc_common::schema::{Checker, InvalidInput};
+
+pub const SHAPE: &str = "one line of steps separated by commas, each a label of lowercase \
+                         letters followed by `=` and a focal length from 1 to 9, or by `-`";
+
+pub fn check(input: &str) -> Result<(), InvalidInput> {
+    let mut checker = Checker::new(input, SHAPE);
+    let sequence = input.trim_end_matches('\n');
+    checker.expect(!sequence.is_empty(), input, "the input is empty");
+    checker.expect(
+        !sequence.contains('\n'),
+        sequence,
+        "the steps should be on one line",
+    );
+    for step in sequence.split(',').filter(|_| !sequence.is_empty()) {
+        let label_len = step
+            .find(|c: char| !c.is_ascii_lowercase())
+            .unwrap_or(step.len());
+        let (label, operation) = step.split_at(label_len);
+        checker.expect(!label.is_empty(), step, "a step should start with a label");
+        let ok = operation == "-"
+            || (operation.len() == 2
+                && operation.starts_with('=')
+                && matches!(operation.as_bytes()[1], b'1'..=b'9'));
+        checker.expect(
+            ok,
+            operation,
+            "the label should be followed by `-` or `=1` to `=9`",
+        );
+    }
+    checker.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check() {
+        assert!(check("rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9\n").is_ok());
+        let invalid = check("rn=1,cm,=3,ot=10").unwrap_err();
+        assert_eq!(
+            [
+                (7..7, "the label should be followed by `-` or `=1` to `=9`"),
+                (8..10, "a step should start with a label"),
+                (
+                    13..16,
+                    "the label should be followed by `-` or `=1` to `=9`"
+                ),
+            ]
+            .map(|(span, message)| (span, message.to_string())),
+            invalid.violations()
+        );
+    }
+}
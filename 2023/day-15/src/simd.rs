@@ -0,0 +1,55 @@
+//! HASH without its chain of dependent multiplications, so the bytes of a step can be summed in
+//! vector lanes.
+//!
+//! HASH multiplies by 17 after adding each byte, so a step of n bytes hashes to
+//!     c_0 * 17^n + c_1 * 17^(n-1) + ... + c_(n-1) * 17
+//! and since 17^k = (1 + 16)^k = 1 + 16k modulo 256, that's
+//!     sum(c_i) + 16 * sum((n - i) * c_i)
+//! Both sums wrap at 256 on their own.
+
+aoc_common::multiversion! {
+    /// Sum of the HASH of every comma-separated step
+    pub fn hash_sum(input: &[u8]) -> u32 = hash_sum_kernel;
+}
+
+#[inline(always)]
+fn hash_sum_kernel(input: &[u8]) -> u32 {
+    input
+        .split(|&c| c == b',')
+        .map(|step| u32::from(hash(step)))
+        .sum()
+}
+
+#[inline(always)]
+fn hash(step: &[u8]) -> u8 {
+    let mut sum = 0u8;
+    let mut weighted = 0u8;
+    for (i, &c) in step.iter().enumerate() {
+        // Only the weight modulo 256 matters
+        let weight = (step.len() - i) as u8;
+        sum = sum.wrapping_add(c);
+        weighted = weighted.wrapping_add(c.wrapping_mul(weight));
+    }
+    sum.wrapping_add(weighted.wrapping_mul(16))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use aoc_common::isa::Isa;
+
+    use crate::bytes;
+
+    #[test]
+    fn test_hash_sum() {
+        assert_eq!(52, hash(b"HASH"));
+        let input = b"rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
+        // A step longer than 255 bytes, whose weights wrap
+        let long = [b"abcdefghij".repeat(40).as_slice(), b",x=1"].concat();
+        for isa in Isa::supported() {
+            assert_eq!(1320, hash_sum(isa, input), "{isa}");
+            assert_eq!(bytes::hash_sum(&long), hash_sum(isa, &long), "{isa}");
+        }
+    }
+}
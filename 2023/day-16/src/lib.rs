@@ -2,4 +2,5 @@ pub mod custom_error;
 
 pub mod part1;
 pub mod part2;
-pub mod part2_biscardi;
\ No newline at end of file
+pub mod schema;
+pub mod viz;
@@ -1,4 +1,5 @@
 use crate::custom_error::AocError;
+use crate::schema;
 
 #[derive(Debug, Clone, Copy)]
 enum Direction {
@@ -42,27 +43,6 @@ impl<'a> BeamMap<'a> {
         }
     }
 
-    #[allow(dead_code)]
-    pub fn print_grid(&self) {
-        for i in 0..self.energized.len() {
-            if self.grid[i] != b'.' {
-                print!("{}", self.grid[i] as char);
-                continue;
-            }
-            match self.energized[i] {
-                0 => print!("."),
-                0b1000 => print!("▲"),
-                0b0100 => print!("▼"),
-                0b0010 => print!("◄"),
-                0b0001 => print!("►"),
-                mask => {
-                    print!("{}", mask.count_ones());
-                }
-            }
-        }
-        println!();
-    }
-
     pub fn get_energized_count(&self) -> u32 {
         self.energized
             .iter()
@@ -194,6 +174,7 @@ impl<'a> BeamMap<'a> {
 }
 
 pub fn process(input: &str) -> miette::Result<String, AocError> {
+    schema::check(input)?;
     let mut beam_map = BeamMap::init(input.as_bytes());
 
     while beam_map.advance_photons() {}
@@ -1,7 +1,12 @@
+use aoc_common::frame::{Cell, Colour, Frame};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
 use crate::custom_error::AocError;
+use crate::schema;
 
 #[derive(Debug, Clone, Copy)]
-enum Direction {
+pub enum Direction {
     Up = 0b1000,
     Down = 0b0100,
     Left = 0b0010,
@@ -11,7 +16,7 @@ enum Direction {
 use Direction::*;
 
 #[derive(Debug, Clone, Copy)]
-struct Photon {
+pub struct Photon {
     index: usize,
     direction: Direction,
 }
@@ -22,7 +27,9 @@ impl Photon {
     }
 }
 
-struct BeamMap<'a> {
+/// The contraption grid and the beams of light travelling through it
+#[derive(Clone)]
+pub struct BeamMap<'a> {
     grid: &'a [u8],
     width: usize,
     photons: Vec<Photon>,
@@ -47,27 +54,6 @@ impl<'a> BeamMap<'a> {
         self.photons = vec![start];
     }
 
-    #[allow(dead_code)]
-    pub fn print_grid(&self) {
-        for i in 0..self.energized.len() {
-            if self.grid[i] != b'.' {
-                print!("{}", self.grid[i] as char);
-                continue;
-            }
-            match self.energized[i] {
-                0 => print!("."),
-                0b1000 => print!("▲"),
-                0b0100 => print!("▼"),
-                0b0010 => print!("◄"),
-                0b0001 => print!("►"),
-                mask => {
-                    print!("{}", mask.count_ones());
-                }
-            }
-        }
-        println!();
-    }
-
     pub fn get_energized_count(&self) -> u32 {
         self.energized
             .iter()
@@ -197,12 +183,47 @@ impl<'a> BeamMap<'a> {
     }
 }
 
-pub fn process(input: &str) -> miette::Result<String, AocError> {
-    let mut beam_map = BeamMap::init(input.as_bytes());
+/// Mirrors and splitters as they are, energized empty tiles as the direction of the beam through
+/// them, or the number of beams if several cross it
+impl Frame for BeamMap<'_> {
+    fn width(&self) -> usize {
+        self.width
+    }
+
+    fn height(&self) -> usize {
+        self.grid.len().div_ceil(self.width + 1)
+    }
+
+    fn cell(&self, x: usize, y: usize) -> Cell {
+        let index = y * (self.width + 1) + x;
+        let colour = if self.energized[index] == 0 { Colour::Dim } else { Colour::Yellow };
+        if self.grid[index] != b'.' {
+            return Cell::new(self.grid[index] as char, colour);
+        }
+        match self.energized[index] {
+            0 => Cell::new('.', colour),
+            0b1000 => Cell::new('▲', colour),
+            0b0100 => Cell::new('▼', colour),
+            0b0010 => Cell::new('◄', colour),
+            0b0001 => Cell::new('►', colour),
+            mask => Cell::new(char::from_digit(mask.count_ones(), 10).unwrap(), Colour::Magenta),
+        }
+    }
+
+    fn caption(&self) -> String {
+        format!(
+            "{} beam(s), {} tiles energized",
+            self.photons.len(),
+            self.get_energized_count()
+        )
+    }
+}
+
+/// Every beam entering the contraption from one of its edges
+pub fn edge_photons(beam_map: &BeamMap<'_>) -> impl Iterator<Item = Photon> {
     let grid_len = beam_map.grid.len();
     let width = beam_map.width;
 
-    // Naively run simulation for each starting photon
     let up_iter = (grid_len - width..grid_len).map(|i| Photon::new(i, Up));
 
     let down_iter = (0..width).map(|i| Photon::new(i, Down));
@@ -215,11 +236,20 @@ pub fn process(input: &str) -> miette::Result<String, AocError> {
         .step_by(width + 1)
         .map(|i| Photon::new(i, Right));
 
-    let result = up_iter
-        .chain(down_iter)
-        .chain(left_iter)
-        .chain(right_iter)
-        .fold(0, |max, start| {
+    up_iter.chain(down_iter).chain(left_iter).chain(right_iter)
+}
+
+/// The contraption of a whole puzzle input without any beam, start one with [`BeamMap::reset`]
+pub fn parse(input: &str) -> Result<BeamMap<'_>, AocError> {
+    schema::check(input)?;
+    Ok(BeamMap::init(input.as_bytes()))
+}
+
+pub fn process(input: &str) -> miette::Result<String, AocError> {
+    let mut beam_map = parse(input)?;
+
+    // Naively run simulation for each starting photon
+    let result = edge_photons(&beam_map).fold(0, |max, start| {
             beam_map.reset(start);
 
             while beam_map.advance_photons() {}
@@ -230,6 +260,31 @@ pub fn process(input: &str) -> miette::Result<String, AocError> {
     Ok(result.to_string())
 }
 
+/// Same as [`process`] with the starting photons simulated on rayon's thread pool, each thread
+/// reusing its own copy of the map
+#[cfg(feature = "parallel")]
+pub fn process_parallel(input: &str) -> miette::Result<String, AocError> {
+    let beam_map = parse(input)?;
+
+    let result = edge_photons(&beam_map)
+        .collect::<Vec<_>>()
+        .into_par_iter()
+        .map_init(
+            || beam_map.clone(),
+            |beam_map, start| {
+                beam_map.reset(start);
+
+                while beam_map.advance_photons() {}
+
+                beam_map.get_energized_count()
+            },
+        )
+        .max()
+        .unwrap_or(0);
+
+    Ok(result.to_string())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -247,6 +302,8 @@ mod tests {
 .|....-|.\
 ..//.|....";
         assert_eq!("51", process(input)?);
+        #[cfg(feature = "parallel")]
+        assert_eq!("51", process_parallel(input)?);
         Ok(())
     }
 }
@@ -0,0 +1,30 @@
+//! Shape of the contraption
+
+use aoc_common::schema::{Checker, InvalidInput};
+
+pub const SHAPE: &str =
+    r"a rectangular grid of empty space `.`, mirrors `/` and `\` and splitters `|` and `-`";
+
+pub fn check(input: &str) -> Result<(), InvalidInput> {
+    let mut checker = Checker::new(input, SHAPE);
+    checker.grid(input, r"./\|-");
+    // The beams would light up the `\r` of CRLF as a tile
+    checker.chars(input, |c| c != '\r', "lines should end in LF, not CRLF");
+    checker.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check() {
+        assert!(check(".|...\\....\n|.-.\\.....\n").is_ok());
+        assert!(check("\\\r\n/").is_err());
+        let invalid = check(".|.\n.+.\n").unwrap_err();
+        assert_eq!(
+            [(5..6, r"cells should be one of `./\|-`".to_string())],
+            invalid.violations()
+        );
+    }
+}
@@ -0,0 +1,63 @@
+//! Frames for `aoc-viz`: the beams spreading through the contraption one tile per frame.
+
+use crate::part2::{edge_photons, BeamMap, Direction, Photon};
+
+/// Part 1 follows the beam entering the top-left corner, part 2 the edge beam that energizes the
+/// most tiles.
+pub fn frames(input: &str, part: u8) -> impl Iterator<Item = BeamMap<'_>> {
+    let mut beam_map = BeamMap::init(input.as_bytes());
+    let start = if part == 1 {
+        Photon::new(0, Direction::Right)
+    } else {
+        let energized = |map: &mut BeamMap, start| {
+            map.reset(start);
+            while map.advance_photons() {}
+            map.get_energized_count()
+        };
+        let mut scratch = beam_map.clone();
+        edge_photons(&beam_map)
+            .max_by_key(|&start| energized(&mut scratch, start))
+            .unwrap()
+    };
+    beam_map.reset(start);
+
+    // Keep stepping until the frame after the last beam left or looped
+    std::iter::successors(Some((beam_map, true)), |(beam_map, moving)| {
+        moving.then(|| {
+            let mut next = beam_map.clone();
+            let moving = next.advance_photons();
+            (next, moving)
+        })
+    })
+    .map(|(beam_map, _)| beam_map)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use aoc_common::frame::{to_text, Frame};
+
+    const INPUT: &str = r".|...\....
+|.-.\.....
+.....|-...
+........|.
+..........
+.........\
+..../.\\..
+.-.-/..|..
+.|....-|.\
+..//.|....";
+
+    #[test]
+    fn test_frames() {
+        let last = frames(INPUT, 1).last().unwrap();
+        assert_eq!("0 beam(s), 46 tiles energized", last.caption());
+        assert_eq!((10, 10), (last.width(), last.height()));
+        assert_eq!('►', last.cell(0, 0).glyph);
+        insta::assert_snapshot!("energized", to_text(&last));
+
+        let last = frames(INPUT, 2).last().unwrap();
+        assert_eq!("0 beam(s), 51 tiles energized", last.caption());
+    }
+}
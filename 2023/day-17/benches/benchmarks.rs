@@ -1,4 +1,4 @@
-use day_17::*;
+use aoc2023_day_17::*;
 
 fn main() {
     // Run registered benchmarks.
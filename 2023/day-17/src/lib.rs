@@ -0,0 +1,6 @@
+pub mod custom_error;
+
+pub mod part1;
+pub mod part2;
+pub mod schema;
+pub mod viz;
@@ -1,6 +1,7 @@
 use std::{collections::BinaryHeap, fmt::Debug};
 
 use crate::custom_error::AocError;
+use crate::schema;
 
 /// Dijkstra's algo with some adjustments
 pub fn solve_2dim(grid: &[&[u8]]) -> i32 {
@@ -52,7 +53,8 @@ pub fn solve_2dim(grid: &[&[u8]]) -> i32 {
 }
 
 /// Same as `solve_2dim`` but indexing 1dim byte array instead of 2dim. This is a bit more performant.
-pub fn solve_1dim(grid: &[u8]) -> i32 {
+/// `None` if the crucible can't reach the end, e.g. on a grid 1 block across and 5 down.
+pub fn solve_1dim(grid: &[u8]) -> Option<i32> {
     let width = grid.iter().position(|&c| c == b'\n').unwrap() as isize + 1;
     let grid_len = grid.len();
 
@@ -68,7 +70,7 @@ pub fn solve_1dim(grid: &[u8]) -> i32 {
     while let Some((cost, index, dir)) = unvisited.pop() {
         // Check if reached end
         if index == grid_len - 1 {
-            return -cost;
+            return Some(-cost);
         }
         // Check if there is already a better cost for this tile and alignment
         if cost < cost_cache[index][(dir.abs() == 1) as usize] {
@@ -100,11 +102,12 @@ pub fn solve_1dim(grid: &[u8]) -> i32 {
             }
         }
     }
-    unreachable!();
+    None
 }
 
 /// Using a custom bucket queue type instead of BinaryHeap for getting lowest cost node among unvisited.
-pub fn solve_bucket(grid: &[u8]) -> usize {
+/// `None` if the crucible can't reach the end.
+pub fn solve_bucket(grid: &[u8]) -> Option<usize> {
     let width = grid.iter().position(|&c| c == b'\n').unwrap() as isize + 1;
     let grid_len = grid.len();
 
@@ -119,7 +122,7 @@ pub fn solve_bucket(grid: &[u8]) -> usize {
     while let Some((cost, (index, dir))) = unvisited.pop() {
         // Check if reached end
         if index == grid_len - 1 {
-            return cost;
+            return Some(cost);
         }
         // Check if there is already a better cost for this tile and alignment
         if cost > cost_cache[index][(dir.abs() == 1) as usize] {
@@ -152,7 +155,7 @@ pub fn solve_bucket(grid: &[u8]) -> usize {
             }
         }
     }
-    unreachable!();
+    None
 }
 
 // Copied from https://github.com/kcaffrey/aoc2023/blob/main/src/bin/17.rs
@@ -193,12 +196,14 @@ impl<T: Debug + Copy> BucketQueue<T> {
 }
 
 pub fn process_heap(input: &str) -> miette::Result<String, AocError> {
-    let result = solve_1dim(input.as_bytes());
+    schema::check(input)?;
+    let result = solve_1dim(input.trim_end().as_bytes()).ok_or(AocError::NoPath)?;
     Ok(result.to_string())
 }
 
 pub fn process_bucket(input: &str) -> miette::Result<String, AocError> {
-    let result = solve_bucket(input.as_bytes());
+    schema::check(input)?;
+    let result = solve_bucket(input.trim_end().as_bytes()).ok_or(AocError::NoPath)?;
     Ok(result.to_string())
 }
 
@@ -224,6 +229,17 @@ mod tests {
 4322674655533";
         assert_eq!("102", process_heap(input)?);
         assert_eq!("102", process_bucket(input)?);
+        assert_eq!("2", process_heap("11\n11\n")?);
+        assert_eq!("2", process_bucket("11\n11\n")?);
+        // Can't turn to break up 4 blocks in a line
+        assert!(matches!(
+            process_heap("1\n1\n1\n1\n1\n"),
+            Err(AocError::NoPath)
+        ));
+        assert!(matches!(
+            process_bucket("1\n1\n1\n1\n1\n"),
+            Err(AocError::NoPath)
+        ));
         Ok(())
     }
 }
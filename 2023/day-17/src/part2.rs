@@ -1,9 +1,11 @@
 use std::fmt::Debug;
 
 use crate::custom_error::AocError;
+use crate::schema;
 
 /// Using a custom bucket queue type instead of BinaryHeap for getting lowest cost node among unvisited.
-pub fn solve_bucket(grid: &[u8]) -> usize {
+/// `None` if the ultra crucible can't reach the end, e.g. on a grid under 5 blocks each way.
+pub fn solve_bucket(grid: &[u8]) -> Option<usize> {
     const MIN_STEPS: isize = 4;
     const MAX_STEPS: isize = 10;
 
@@ -21,7 +23,7 @@ pub fn solve_bucket(grid: &[u8]) -> usize {
     while let Some((cost, (index, dir))) = unvisited.pop() {
         // Check if reached end
         if index == grid_len - 1 {
-            return cost;
+            return Some(cost);
         }
         // Check if there is already a better cost for this tile and alignment
         if cost > cost_cache[index][(dir.abs() == 1) as usize] {
@@ -59,7 +61,7 @@ pub fn solve_bucket(grid: &[u8]) -> usize {
             }
         }
     }
-    unreachable!();
+    None
 }
 
 // Copied from https://github.com/kcaffrey/aoc2023/blob/main/src/bin/17.rs
@@ -100,7 +102,8 @@ impl<T: Debug + Copy> BucketQueue<T> {
 }
 
 pub fn process(input: &str) -> miette::Result<String, AocError> {
-    let result = solve_bucket(input.as_bytes());
+    schema::check(input)?;
+    let result = solve_bucket(input.trim_end().as_bytes()).ok_or(AocError::NoPath)?;
     Ok(result.to_string())
 }
 
@@ -125,6 +128,8 @@ mod tests {
 2546548887735
 4322674655533";
         assert_eq!("94", process(input)?);
+        // Too small to move 4 blocks before turning
+        assert!(matches!(process("11\n11\n"), Err(AocError::NoPath)));
         Ok(())
     }
 }
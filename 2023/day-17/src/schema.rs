@@ -0,0 +1,29 @@
+//! Shape of the map
+
+use aoc_common::schema::{Checker, InvalidInput};
+
+pub const SHAPE: &str = "a rectangular grid of heat losses from 1 to 9, with LF line endings";
+
+pub fn check(input: &str) -> Result<(), InvalidInput> {
+    let mut checker = Checker::new(input, SHAPE);
+    checker.grid(input, "123456789");
+    // The solvers would read the `\r` of CRLF as a block
+    checker.chars(input, |c| c != '\r', "lines should end in LF, not CRLF");
+    checker.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check() {
+        assert!(check("2413432311323\n3215453535623\n").is_ok());
+        assert!(check("2413\r\n3215\r\n").is_err());
+        let invalid = check("241\n305\n").unwrap_err();
+        assert_eq!(
+            [(5..6, "cells should be one of `123456789`".to_string())],
+            invalid.violations()
+        );
+    }
+}
@@ -0,0 +1,194 @@
+//! Frames for `aoc-viz`: the crucible's cheapest route, revealed one city block per frame.
+
+use std::rc::Rc;
+
+use aoc_common::frame::{Cell, Colour, Frame};
+
+use crate::part2::BucketQueue;
+
+/// The map with the first `steps` blocks of the route highlighted.
+#[derive(Clone)]
+pub struct PathFrame<'a> {
+    grid: &'a [u8],
+    width: usize,
+    /// Position of each block along the route, counting the start as 1. 0 for blocks off the route.
+    order: Rc<[usize]>,
+    /// Heat lost by the time the crucible leaves the block at the same position of the route
+    heat_loss: Rc<[usize]>,
+    steps: usize,
+}
+
+impl Frame for PathFrame<'_> {
+    fn width(&self) -> usize {
+        self.width
+    }
+
+    fn height(&self) -> usize {
+        self.grid.len().div_ceil(self.width + 1)
+    }
+
+    fn cell(&self, x: usize, y: usize) -> Cell {
+        let index = y * (self.width + 1) + x;
+        let glyph = self.grid[index] as char;
+        let colour = match self.order[index] {
+            0 => Colour::Dim,
+            order if order == self.steps => Colour::Red,
+            order if order < self.steps => Colour::Cyan,
+            _ => Colour::Dim,
+        };
+        Cell::new(glyph, colour)
+    }
+
+    fn caption(&self) -> String {
+        format!(
+            "{}/{} blocks, heat loss {}",
+            self.steps - 1,
+            self.heat_loss.len() - 1,
+            self.heat_loss[self.steps - 1]
+        )
+    }
+}
+
+/// Cheapest route from the top-left to the bottom-right block as grid indices, moving between
+/// `min_steps` and `max_steps` blocks before each turn. Empty if there's none.
+pub fn find_path(grid: &[u8], min_steps: isize, max_steps: isize) -> Vec<usize> {
+    let width = grid.iter().position(|&c| c == b'\n').unwrap() as isize + 1;
+    let end = grid.iter().rposition(u8::is_ascii_digit).unwrap();
+
+    // Same search as `part2::solve_bucket`, but remembering where each (block, axis) was reached
+    // from. Axis 1 is for arriving horizontally.
+    let mut best = vec![[usize::MAX; 2]; grid.len()];
+    let mut previous = vec![[None; 2]; grid.len()];
+    let mut unvisited = BucketQueue::new();
+    best[0] = [0, 0];
+    unvisited.push(0, (0, 0));
+    unvisited.push(0, (0, 1));
+
+    while let Some((cost, (index, axis))) = unvisited.pop() {
+        if index == end {
+            let mut path = vec![index];
+            let mut state = (index, axis);
+            while let Some((from, from_axis)) = previous[state.0][state.1] {
+                // Walk back to `from`, which is on the axis the block was reached along
+                let step = axis_step(state.1, width) * if from > state.0 { 1 } else { -1 };
+                let mut block = state.0 as isize + step;
+                while block != from as isize {
+                    path.push(block as usize);
+                    block += step;
+                }
+                path.push(from);
+                state = (from, from_axis);
+            }
+            path.reverse();
+            return path;
+        }
+        if cost > best[index][axis] {
+            continue;
+        }
+        let next_axis = 1 - axis;
+        let step = axis_step(next_axis, width);
+        for dir in [-step, step] {
+            let mut new_cost = cost;
+            for steps in 1..=max_steps {
+                let next = index as isize + dir * steps;
+                let Some(&block) = usize::try_from(next).ok().and_then(|next| grid.get(next)) else {
+                    break;
+                };
+                if block == b'\n' {
+                    break;
+                }
+                new_cost += (block - b'0') as usize;
+                if steps < min_steps {
+                    continue;
+                }
+                let next = next as usize;
+                if new_cost < best[next][next_axis] {
+                    best[next][next_axis] = new_cost;
+                    previous[next][next_axis] = Some((index, axis));
+                    unvisited.push(new_cost, (next, next_axis));
+                }
+            }
+        }
+    }
+    vec![]
+}
+
+fn axis_step(axis: usize, width: isize) -> isize {
+    if axis == 1 {
+        1
+    } else {
+        width
+    }
+}
+
+/// Part 1 steers a regular crucible (1 to 3 blocks between turns), part 2 an ultra crucible
+/// (4 to 10).
+pub fn frames(input: &str, part: u8) -> impl Iterator<Item = PathFrame<'_>> {
+    let grid = input.as_bytes();
+    let width = input.find('\n').unwrap();
+    let path = if part == 1 {
+        find_path(grid, 1, 3)
+    } else {
+        find_path(grid, 4, 10)
+    };
+
+    let mut order = vec![0; grid.len()];
+    let mut heat_loss = vec![0];
+    for (position, &index) in path.iter().enumerate() {
+        order[index] = position + 1;
+        if position > 0 {
+            heat_loss.push(heat_loss[position - 1] + (grid[index] - b'0') as usize);
+        }
+    }
+
+    let (order, heat_loss): (Rc<[usize]>, Rc<[usize]>) = (order.into(), heat_loss.into());
+    (1..=path.len()).map(move |steps| PathFrame {
+        grid,
+        width,
+        order: order.clone(),
+        heat_loss: heat_loss.clone(),
+        steps,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "\
+2413432311323
+3215453535623
+3255245654254
+3446585845452
+4546657867536
+1438598798454
+4457876987766
+3637877979653
+4654967986887
+4564679986453
+1224686865563
+2546548887735
+4322674655533
+";
+
+    #[test]
+    fn test_frames() {
+        let path = find_path(INPUT.as_bytes(), 1, 3);
+        for pair in path.windows(2) {
+            assert!([1, 14].contains(&pair[0].abs_diff(pair[1])), "{pair:?}");
+        }
+        let mut map = INPUT.as_bytes().to_vec();
+        for &index in &path {
+            map[index] = b'#';
+        }
+        insta::assert_snapshot!("part1_path", String::from_utf8(map).unwrap());
+
+        let last = frames(INPUT, 1).last().unwrap();
+        assert_eq!("28/28 blocks, heat loss 102", last.caption());
+        assert_eq!(Cell::new('2', Colour::Cyan), last.cell(0, 0));
+        assert_eq!(Cell::new('3', Colour::Red), last.cell(12, 12));
+
+        let last = frames(INPUT, 2).last().unwrap();
+        assert!(last.caption().ends_with("heat loss 94"), "{}", last.caption());
+    }
+}
@@ -0,0 +1,64 @@
+use aoc2023_day_18::*;
+
+fn main() {
+    // Run registered benchmarks.
+    divan::main();
+}
+
+#[divan::bench]
+fn part1() {
+    part1::process(divan::black_box(include_str!(
+        "../input.txt",
+    )))
+    .unwrap();
+}
+#[divan::bench]
+fn part1_bytes() {
+    part1::process_bytes(divan::black_box(include_str!(
+        "../input.txt",
+    )))
+    .unwrap();
+}
+
+#[divan::bench]
+fn part2() {
+    part2::process(divan::black_box(include_str!(
+        "../input.txt",
+    )))
+    .unwrap();
+}
+
+#[divan::bench]
+fn part2_bytes() {
+    part2::process_bytes(divan::black_box(include_str!(
+        "../input.txt",
+    )))
+    .unwrap();
+}
+/// The colours' hex distances, read with the digit fold the bytes core had before
+/// `aoc_common::parse`
+#[divan::bench]
+fn parse_fold() -> i64 {
+    hex_distances(divan::black_box(include_bytes!("../input.txt")))
+        .map(|hex| {
+            hex.iter().fold(0, |acc, &h| {
+                (acc * 16) + if h < b'a' { h - b'0' } else { h - b'a' + 10 } as i64
+            })
+        })
+        .sum()
+}
+
+/// The same distances read by the shared parser
+#[divan::bench]
+fn parse_shared() -> i64 {
+    hex_distances(divan::black_box(include_bytes!("../input.txt")))
+        .map(|hex| aoc_common::parse::hex::<i64>(hex).expect("valid number"))
+        .sum()
+}
+
+fn hex_distances(input: &[u8]) -> impl Iterator<Item = &[u8]> {
+    input.split(|&c| c == b'\n').filter_map(|line| {
+        let start = line.iter().position(|&c| c == b'#')? + 1;
+        line.get(start..start + 5)
+    })
+}
@@ -1,4 +1,4 @@
-use day_18::part1::*;
+use aoc2023_day_18::part1::*;
 use miette::Context;
 
 #[cfg(feature = "dhat-heap")]
@@ -1,74 +1,82 @@
-use crate::custom_error::AocError;
+//! Allocation-free cores of the byte solvers, built without `std` too. Both use the shoelace
+//! formula and Pick's theorem on the dig plan's vertices.
+
+use aoc_common::parse;
+
+/// Cubic meters of lava held by the lagoon dug following the plan's directions
+pub fn lagoon_size(input: &[u8]) -> i64 {
+    const LINE_LEN: usize = 14;
 
-// Uses shoelace algorithm and Pick's theorem
-pub fn process(input: &str) -> miette::Result<String, AocError> {
-    let mut perimeter: i32 = 0;
     let mut det_sum = 0; // determinants sum
+    let mut perimeter = 0;
     let mut point = (0, 0);
 
-    for line in input.lines() {
-        let mut tokens = line.split_ascii_whitespace();
+    let mut index = 0;
 
-        let dir = match tokens.next().unwrap() {
-            "U" => (0, -1),
-            "D" => (0, 1),
-            "L" => (-1, 0),
-            "R" => (1, 0),
+    while index < input.len() {
+        let dir = match input[index] {
+            b'U' => (0, -1),
+            b'D' => (0, 1),
+            b'L' => (-1, 0),
+            b'R' => (1, 0),
             _ => panic!("invalid direction"),
         };
-        let dist = tokens.next().unwrap().parse::<i32>().unwrap();
-
+        // check for 1 or 2 digit number
+        let dist = if input[index + 4] == b' ' {
+            index += 1;
+            parse::decimal::<i64>(&input[index + 1..index + 3])
+        } else {
+            parse::decimal::<i64>(&input[index + 2..index + 3])
+        }
+        .expect("valid number");
         let next_point = (point.0 + (dist * dir.0), point.1 + (dist * dir.1));
+
         det_sum += (next_point.0 + point.0) * (next_point.1 - point.1); // see shoelace algorithm
         perimeter += dist;
+
         point = next_point;
+        index += LINE_LEN;
     }
     let area = (det_sum / 2).abs();
     let interior_area = area - perimeter / 2 + 1; // see Pick's theorem
 
-    let result = interior_area + perimeter;
-    Ok(result.to_string())
+    interior_area + perimeter
 }
 
-// Using bytes is less ergonomic but much faster
-pub fn process_bytes(input: &str) -> miette::Result<String, AocError> {
-    let input = input.as_bytes();
+/// Cubic meters of lava held by the lagoon dug following the colour codes
+pub fn hex_lagoon_size(input: &[u8]) -> i64 {
     const LINE_LEN: usize = 14;
 
     let mut det_sum = 0; // determinants sum
     let mut perimeter = 0;
     let mut point = (0, 0);
 
-    let mut index = 0;
+    let mut index = 6;
 
     while index < input.len() {
-        let dir = match input[index] {
-            b'U' => (0, -1),
-            b'D' => (0, 1),
-            b'L' => (-1, 0),
-            b'R' => (1, 0),
-            _ => panic!("invalid direction"),
-        };
-        // check for 1 or 2 digit number
-        let dist = if input[index + 4] == b' ' {
+        if input[index - 1] != b'#' {
             index += 1;
-            ((input[index + 1] - b'0') * 10 + input[index + 2] - b'0') as i32
-        } else {
-            (input[index + 2] - b'0') as i32
+        }
+        let dir = match input[index + 5] {
+            b'0' => (1, 0),  // right
+            b'1' => (0, 1),  // down
+            b'2' => (-1, 0), // left
+            b'3' => (0, -1), // up
+            c => panic!("invalid direction: {}", c as char),
         };
+        let dist = parse::hex::<i64>(&input[index..index + 5]).expect("valid number");
         let next_point = (point.0 + (dist * dir.0), point.1 + (dist * dir.1));
 
         det_sum += (next_point.0 + point.0) * (next_point.1 - point.1); // see shoelace algorithm
         perimeter += dist;
 
         point = next_point;
-        index += LINE_LEN;
+        index += LINE_LEN
     }
     let area = (det_sum / 2).abs();
     let interior_area = area - perimeter / 2 + 1; // see Pick's theorem
 
-    let result = interior_area + perimeter;
-    Ok(result.to_string())
+    interior_area + perimeter
 }
 
 #[cfg(test)]
@@ -76,8 +84,8 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_process() -> miette::Result<()> {
-        let input = "\
+    fn test_lagoon_size() {
+        let input = b"\
 R 6 (#70c710)
 D 5 (#0dc571)
 L 2 (#5713f0)
@@ -92,8 +100,7 @@ R 2 (#7807d2)
 U 3 (#a77fa3)
 L 2 (#015232)
 U 2 (#7a21e3)";
-        assert_eq!("62", process(input)?);
-        assert_eq!("62", process_bytes(input)?);
-        Ok(())
+        assert_eq!(62, lagoon_size(input));
+        assert_eq!(952408144115, hex_lagoon_size(input));
     }
 }
@@ -0,0 +1,109 @@
+use aoc_common::lenient::Lenient;
+use aoc_common::parse;
+
+use crate::bytes;
+use crate::custom_error::AocError;
+use crate::schema;
+
+// Uses shoelace algorithm and Pick's theorem
+pub fn process(input: &str) -> miette::Result<String, AocError> {
+    schema::check(input)?;
+    let mut perimeter: i32 = 0;
+    let mut det_sum = 0; // determinants sum
+    let mut point = (0, 0);
+
+    for line in input.lines() {
+        let mut tokens = line.split_ascii_whitespace();
+
+        let dir = match tokens.next().unwrap() {
+            "U" => (0, -1),
+            "D" => (0, 1),
+            "L" => (-1, 0),
+            "R" => (1, 0),
+            _ => panic!("invalid direction"),
+        };
+        let dist = parse::decimal::<i32>(tokens.next().unwrap_or_default())?;
+
+        let next_point = (point.0 + (dist * dir.0), point.1 + (dist * dir.1));
+        det_sum += (next_point.0 + point.0) * (next_point.1 - point.1); // see shoelace algorithm
+        perimeter += dist;
+        point = next_point;
+    }
+    let area = (det_sum / 2).abs();
+    let interior_area = area - perimeter / 2 + 1; // see Pick's theorem
+
+    let result = interior_area + perimeter;
+    Ok(result.to_string())
+}
+
+// Using bytes is less ergonomic but much faster
+pub fn process_bytes(input: &str) -> miette::Result<String, AocError> {
+    schema::check(input)?;
+    Ok(bytes::lagoon_size(input.as_bytes()).to_string())
+}
+
+/// Same as [`process`] but skips the lines that don't have the input's shape, see
+/// [`crate::schema`], returning the answer over the other lines in [`AocError::Partial`]
+pub fn process_lenient(input: &str) -> miette::Result<String, AocError> {
+    let lines = Lenient::lines(input, schema::check);
+    let answer = lines.answer(process)?;
+    Ok(lines.finish(answer)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_process() -> miette::Result<()> {
+        let input = "\
+R 6 (#70c710)
+D 5 (#0dc571)
+L 2 (#5713f0)
+D 2 (#d2c081)
+R 2 (#59c680)
+D 2 (#411b91)
+L 5 (#8ceee2)
+U 2 (#caa173)
+L 1 (#1b58a2)
+U 2 (#caa171)
+R 2 (#7807d2)
+U 3 (#a77fa3)
+L 2 (#015232)
+U 2 (#7a21e3)";
+        assert_eq!("62", process(input)?);
+        assert_eq!("62", process_bytes(input)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_process_lenient() {
+        let input = "\
+R 6 (#70c710)
+D 5 (#0dc571)
+L 2 (#5713f0)
+D 2 (#d2c081)
+R 2 (#59c680)
+D 2 (#411b91)
+L 5 (#8ceee2)
+R 100 (#8ceee2)
+U 2 (#caa173)
+L 1 (#1b58a2)
+U 2 (#caa171)
+R 2 (#7807d2)
+U 3 (#a77fa3)
+L 2 (#015232)
+U 2 (#7a21e3)";
+        let Err(AocError::Partial(partial)) = process_lenient(input) else {
+            panic!("line 8 should be skipped");
+        };
+        assert_eq!("62", partial.answer());
+        assert_eq!(1, partial.skipped().len());
+
+        // Nothing left to solve
+        let Err(AocError::Partial(partial)) = process_lenient("R 100 (#8ceee2)\nD 5") else {
+            panic!("every line should be skipped");
+        };
+        assert_eq!("0", partial.answer());
+    }
+}
@@ -1,4 +1,9 @@
+use aoc_common::lenient::Lenient;
+use aoc_common::parse;
+
+use crate::bytes;
 use crate::custom_error::AocError;
+use crate::schema;
 
 fn get_direction(dir: &str) -> (i128, i128) {
     match dir {
@@ -12,6 +17,7 @@ fn get_direction(dir: &str) -> (i128, i128) {
 
 // Uses shoelace algorithm and Pick's theorem
 pub fn process(input: &str) -> miette::Result<String, AocError> {
+    schema::check(input)?;
     let mut perimeter: i128 = 0;
     let mut det_sum = 0; // determinants sum
     let mut vert = (0, 0);
@@ -19,7 +25,7 @@ pub fn process(input: &str) -> miette::Result<String, AocError> {
     for line in input.lines() {
         let mut tokens = line.split_ascii_whitespace().skip(2);
         let hex_code = &tokens.next().unwrap()[2..];
-        let dist = i128::from_str_radix(&hex_code[..hex_code.len() - 2], 16).unwrap();
+        let dist = parse::hex::<i128>(&hex_code[..hex_code.len() - 2])?;
         let dir = get_direction(&hex_code[hex_code.len() - 2..hex_code.len() - 1]);
 
         let next_vert = (vert.0 + (dist * dir.0), vert.1 + (dist * dir.1));
@@ -36,43 +42,16 @@ pub fn process(input: &str) -> miette::Result<String, AocError> {
 
 // Using bytes is less ergonomic but much faster
 pub fn process_bytes(input: &str) -> miette::Result<String, AocError> {
-    let input = input.as_bytes();
-    const LINE_LEN: usize = 14;
-
-    let mut det_sum = 0; // determinants sum
-    let mut perimeter = 0;
-    let mut point = (0, 0);
-
-    let mut index = 6;
-
-    while index < input.len() {
-        if input[index - 1] != b'#' {
-            index += 1;
-        }
-        let dir = match input[index + 5] {
-            b'0' => (1, 0),  // right
-            b'1' => (0, 1),  // down
-            b'2' => (-1, 0), // left
-            b'3' => (0, -1), // up
-            c => panic!("invalid direction: {}", c as char),
-        };
-        // manually parse hex to decimal
-        let dist = input[index..index + 5].iter().fold(0, |acc, &h| {
-            (acc * 16) + if h < b'a' { h - b'0' } else { h - b'a' + 10 } as i64
-        });
-        let next_point = (point.0 + (dist * dir.0), point.1 + (dist * dir.1));
-
-        det_sum += (next_point.0 + point.0) * (next_point.1 - point.1); // see shoelace algorithm
-        perimeter += dist;
-
-        point = next_point;
-        index += LINE_LEN
-    }
-    let area = (det_sum / 2).abs();
-    let interior_area = area - perimeter / 2 + 1; // see Pick's theorem
+    schema::check(input)?;
+    Ok(bytes::hex_lagoon_size(input.as_bytes()).to_string())
+}
 
-    let result = interior_area + perimeter;
-    Ok(result.to_string())
+/// Same as [`process`] but skips the lines that don't have the input's shape, see
+/// [`crate::schema`], returning the answer over the other lines in [`AocError::Partial`]
+pub fn process_lenient(input: &str) -> miette::Result<String, AocError> {
+    let lines = Lenient::lines(input, schema::check);
+    let answer = lines.answer(process)?;
+    Ok(lines.finish(answer)?)
 }
 
 #[cfg(test)]
@@ -0,0 +1,80 @@
+//! Shape of the dig plan. The byte variants step over the plan in fixed-width lines.
+
+use aoc_common::schema::{Checker, InvalidInput};
+
+pub const SHAPE: &str = "lines like `R 6 (#70c710)`, a direction out of `UDLR`, a distance of 1 \
+                         or 2 digits and a colour of 6 lowercase hex digits, the last one 0 to 3";
+
+pub fn check(input: &str) -> Result<(), InvalidInput> {
+    let mut checker = Checker::new(input, SHAPE);
+    for line in checker.lines() {
+        let parts = line.split(' ').collect::<Vec<_>>();
+        let [direction, distance, colour] = parts[..] else {
+            checker.expect(
+                false,
+                line,
+                "should be a direction, a distance and a colour",
+            );
+            continue;
+        };
+        checker.expect(
+            matches!(direction, "U" | "D" | "L" | "R"),
+            direction,
+            "the direction should be `U`, `D`, `L` or `R`",
+        );
+        checker.expect(
+            matches!(distance.len(), 1 | 2) && distance.bytes().all(|b| b.is_ascii_digit()),
+            distance,
+            "the distance should be a number of 1 or 2 digits",
+        );
+        let hex = colour
+            .strip_prefix("(#")
+            .and_then(|colour| colour.strip_suffix(')'))
+            .filter(|hex| hex.len() == 6)
+            .filter(|hex| hex.bytes().all(|b| matches!(b, b'0'..=b'9' | b'a'..=b'f')));
+        if checker.expect(
+            hex.is_some(),
+            colour,
+            "the colour should look like `(#70c710)`",
+        ) {
+            checker.expect(
+                matches!(colour.as_bytes()[7], b'0'..=b'3'),
+                &colour[7..8],
+                "the colour's last digit should be 0 to 3, part 2 reads it as the direction",
+            );
+        }
+    }
+    // The byte variants would step into the `\r` of CRLF
+    checker.chars(input, |c| c != '\r', "lines should end in LF, not CRLF");
+    checker.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check() {
+        assert!(check("R 6 (#70c710)\nD 5 (#0dc571)\nL 12 (#5713f0)\n").is_ok());
+        assert!(check("R 6 (#70c710)\r\nD 5 (#0dc571)\r\n").is_err());
+        let invalid = check("R 6 (#70C710)\nN 123 (#0dc571)\nL 2").unwrap_err();
+        assert_eq!(
+            [
+                (4..13, "the colour should look like `(#70c710)`"),
+                (14..15, "the direction should be `U`, `D`, `L` or `R`"),
+                (16..19, "the distance should be a number of 1 or 2 digits"),
+                (30..33, "should be a direction, a distance and a colour"),
+            ]
+            .map(|(span, message)| (span, message.to_string())),
+            invalid.violations()
+        );
+        assert_eq!(
+            [(
+                11..12,
+                "the colour's last digit should be 0 to 3, part 2 reads it as the direction"
+                    .to_string()
+            )],
+            check("R 6 (#70c714)").unwrap_err().violations()
+        );
+    }
+}
@@ -0,0 +1,58 @@
+use aoc2023_day_19::*;
+
+fn main() {
+    // Run registered benchmarks.
+    divan::main();
+}
+
+#[divan::bench]
+fn part1() {
+    part1::process(divan::black_box(include_str!(
+        "../input.txt",
+    )))
+    .unwrap();
+}
+
+#[divan::bench]
+fn part2() {
+    part2::process(divan::black_box(include_str!(
+        "../input.txt",
+    )))
+    .unwrap();
+}
+
+#[divan::bench]
+fn part2_dfs() {
+    part2_dfs::process(divan::black_box(include_str!(
+        "../input.txt",
+    )))
+    .unwrap();
+}
+/// The parts' ratings, read with the digit fold the solvers had before `aoc_common::parse`
+#[divan::bench]
+fn parse_fold() -> u32 {
+    ratings(divan::black_box(include_bytes!("../input.txt")))
+        .map(|rating| {
+            rating
+                .iter()
+                .fold(0, |acc, d| (acc * 10) + (d - b'0') as u32)
+        })
+        .sum()
+}
+
+/// The same ratings read by the shared parser
+#[divan::bench]
+fn parse_shared() -> u32 {
+    ratings(divan::black_box(include_bytes!("../input.txt")))
+        .map(|rating| aoc_common::parse::decimal::<u32>(rating).expect("valid number"))
+        .sum()
+}
+
+/// Each part's `x`, `m`, `a` and `s` ratings without their names
+fn ratings(input: &[u8]) -> impl Iterator<Item = &[u8]> {
+    input
+        .split(|&c| c == b'\n')
+        .filter(|line| line.first() == Some(&b'{'))
+        .flat_map(|line| line[1..line.len() - 1].split(|&c| c == b','))
+        .map(|rating| &rating[2..])
+}
@@ -0,0 +1,73 @@
+//! Intermediates for `aoc run --explain`: the box of ratings each `A` rule accepts.
+
+use aoc_common::explain::Explanation;
+use aoc_common::params::Params;
+
+use crate::custom_error::AocError;
+use crate::part2::{accepted_boxes, PARAMS};
+
+/// Every non-empty box as inclusive `[min, max]` ratings, with its number of combinations
+pub fn part2(input: &str) -> miette::Result<Explanation, AocError> {
+    let max_rating = Params::defaults(PARAMS).get("max_rating");
+    let mut explanation = Explanation::new();
+    for accepted in accepted_boxes(input, max_rating)? {
+        if accepted.ratings.iter().any(|range| range.is_empty()) {
+            continue;
+        }
+        let label = format!("{} rule {}", accepted.workflow, accepted.rule + 1);
+        let record = explanation.record(label);
+        let mut combinations = 1_u64;
+        for (name, range) in ["x", "m", "a", "s"].into_iter().zip(accepted.ratings) {
+            combinations *= range.len() as u64;
+            record.field(name, [range.start, range.end - 1]);
+        }
+        record.field("combinations", combinations);
+    }
+    Ok(explanation)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::explain::Value;
+
+    use super::*;
+
+    #[test]
+    fn test_explain() -> miette::Result<()> {
+        let input = "\
+in{s<1351:px,A}
+px{a<2006:A,R}
+
+{x=787,m=2655,a=1222,s=2876}";
+        let explanation = part2(input)?;
+        let labels = explanation.records.iter().map(|record| record.label.as_str());
+        assert_eq!(vec!["in rule 2", "px rule 1"], labels.collect::<Vec<_>>());
+
+        let px = &explanation.records[1];
+        assert_eq!(Some(&Value::from([1, 1350])), px.get("s"));
+        assert_eq!(Some(&Value::from([1, 2005])), px.get("a"));
+        assert_eq!(Some(&Value::from([1, 4000])), px.get("x"));
+        assert_eq!(Some(&Value::Int(1350 * 2005 * 4000 * 4000)), px.get("combinations"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_snapshots() -> miette::Result<()> {
+        let input = "\
+px{a<2006:qkq,m>2090:A,rfg}
+pv{a>1716:R,A}
+lnx{m>1548:A,A}
+rfg{s<537:gd,x>2440:R,A}
+qs{s>3448:A,lnx}
+qkq{x<1416:A,crn}
+crn{x>2662:A,R}
+in{s<1351:px,qqz}
+qqz{s>2770:qs,m<1801:hdj,R}
+gd{a>3333:R,R}
+hdj{m>838:A,pv}
+
+{x=787,m=2655,a=1222,s=2876}";
+        insta::assert_snapshot!("part2", part2(input)?);
+        Ok(())
+    }
+}
@@ -0,0 +1,214 @@
+use aoc_common::parse;
+
+use crate::custom_error::AocError;
+use crate::schema;
+
+pub fn process(input: &str) -> miette::Result<String, AocError> {
+    schema::check(input)?;
+    let mut input_split = input.split("\n\n");
+
+    let workflows = Workflows::parse(input_split.next().unwrap_or_default())?;
+    let parts_list = input_split.next().unwrap_or_default();
+
+    let mut result = 0;
+    for line in parts_list.lines() {
+        let ratings = parse_part(line)?;
+        if workflows.accepts(ratings) {
+            result += ratings.iter().sum::<u32>();
+        }
+    }
+    Ok(result.to_string())
+}
+
+/// The workflows and the ratings of every part, in "xmas" order
+pub fn parse(input: &str) -> Result<(Workflows, Vec<[u32; 4]>), AocError> {
+    schema::check(input)?;
+    let (workflows, parts) = input.split_once("\n\n").unwrap_or_default();
+    let parts = parts.lines().map(parse_part).collect::<Result<_, _>>()?;
+    Ok((Workflows::parse(workflows)?, parts))
+}
+
+/// Ratings of a part like `{x=787,m=2655,a=1222,s=2876}`, in "xmas" order
+pub fn parse_part(line: &str) -> Result<[u32; 4], AocError> {
+    let line = &line.as_bytes()[1..line.len() - 1];
+    let mut ratings = [0; 4];
+
+    for (index, rating) in line.split(|&c| c == b',').enumerate() {
+        ratings[index] = parse::decimal::<u32>(&rating[2..])?;
+    }
+    Ok(ratings)
+}
+
+/// The workflows section of the input, indexed by hashed workflow name
+#[derive(Clone, Debug)]
+pub struct Workflows {
+    rules: Vec<Vec<Rule<u32>>>,
+}
+
+impl Workflows {
+    pub fn parse(workflows_list: &str) -> Result<Workflows, AocError> {
+        let largest_index = hash("zzz".as_bytes());
+        let mut workflows = vec![vec![]; largest_index + 1];
+
+        for line in workflows_list.as_bytes().split(|&c| c == b'\n') {
+            let divider = line.iter().position(|&c| c == b'{').unwrap();
+            let index = hash(&line[..divider]);
+
+            for rule in line[divider + 1..line.len() - 1].split(|&c| c == b',') {
+                let (condition, target_start) = match rule.iter().position(|&c| c == b':') {
+                    Some(colon_index) => (get_condition(&rule[..colon_index])?, colon_index + 1),
+                    None => (Condition::None, 0),
+                };
+                let target = match rule[target_start] {
+                    b'A' => Target::Accept,
+                    b'R' => Target::Reject,
+                    _ => Target::Workflow(hash(&rule[target_start..])),
+                };
+                workflows[index].push(Rule::new(condition, target));
+            }
+        }
+        Ok(Workflows { rules: workflows })
+    }
+
+    /// Runs a part with the given ratings through the workflows, starting at `in`
+    pub fn accepts(&self, ratings: [u32; 4]) -> bool {
+        let mut workflow_index = hash("in".as_bytes());
+        'rules: loop {
+            for rule in &self.rules[workflow_index] {
+                if rule.condition.is_satisfied_for(ratings) {
+                    match rule.target {
+                        Target::Workflow(i) => {
+                            workflow_index = i;
+                            continue 'rules;
+                        }
+                        Target::Accept => return true,
+                        Target::Reject => return false,
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Converts workflow name to index
+fn hash(workflow_name: &[u8]) -> usize {
+    workflow_name
+        .iter()
+        .fold(0, |acc, d| (acc * 26) + (d - b'a') as usize)
+}
+
+fn get_condition(condition: &[u8]) -> Result<Condition<u32>, AocError> {
+    let value = parse::decimal::<u32>(&condition[2..])?;
+    let index = match condition[0] {
+        b'x' => 0,
+        b'm' => 1,
+        b'a' => 2,
+        b's' => 3,
+        _ => unreachable!("Must be in \"xmas\""),
+    };
+    Ok(match condition[1] {
+        b'<' => Condition::LessThan(index, value),
+        b'>' => Condition::GreaterThan(index, value),
+        _ => unreachable!("Must be either '>' or '<'"),
+    })
+}
+
+#[derive(Clone, Debug)]
+struct Rule<T: Ord> {
+    condition: Condition<T>,
+    target: Target,
+}
+
+impl<T: Ord> Rule<T> {
+    pub fn new(condition: Condition<T>, target: Target) -> Self {
+        Rule { condition, target }
+    }
+}
+
+#[derive(Clone, Debug)]
+enum Target {
+    // stores index
+    Workflow(usize),
+    Accept,
+    Reject,
+}
+
+#[derive(Clone, Debug)]
+enum Condition<T: Ord> {
+    // stores index and value to compare against
+    GreaterThan(usize, T),
+    LessThan(usize, T),
+    None,
+}
+
+impl<T: Ord> Condition<T> {
+    pub fn is_satisfied_for(&self, rankings: [T; 4]) -> bool {
+        match self {
+            Self::GreaterThan(i, c) => rankings[*i] > *c,
+            Self::LessThan(i, c) => rankings[*i] < *c,
+            Self::None => true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_process() -> miette::Result<()> {
+        let input = "\
+px{a<2006:qkq,m>2090:A,rfg}
+pv{a>1716:R,A}
+lnx{m>1548:A,A}
+rfg{s<537:gd,x>2440:R,A}
+qs{s>3448:A,lnx}
+qkq{x<1416:A,crn}
+crn{x>2662:A,R}
+in{s<1351:px,qqz}
+qqz{s>2770:qs,m<1801:hdj,R}
+gd{a>3333:R,R}
+hdj{m>838:A,pv}
+
+{x=787,m=2655,a=1222,s=2876}
+{x=1679,m=44,a=2067,s=496}
+{x=2036,m=264,a=79,s=2244}
+{x=2461,m=1339,a=466,s=291}
+{x=2127,m=1623,a=2188,s=1013}";
+        assert_eq!("19114", process(input)?);
+
+        let (workflows, parts) = input.split_once("\n\n").unwrap();
+        let workflows = Workflows::parse(workflows)?;
+        let accepted = parts
+            .lines()
+            .map(|line| Ok(workflows.accepts(parse_part(line)?)))
+            .collect::<Result<Vec<_>, AocError>>()?;
+        assert_eq!(vec![true, false, true, false, true], accepted);
+        assert_eq!([787, 2655, 1222, 2876], parse_part("{x=787,m=2655,a=1222,s=2876}")?);
+        assert!(matches!(
+            Workflows::parse("in{x>1x:A,R}"),
+            Err(AocError::ParseInt(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_snapshot() -> miette::Result<()> {
+        let workflows = Workflows::parse(
+            "\
+px{a<2006:qkq,m>2090:A,rfg}
+in{s<1351:px,qqz}
+qqz{s>2770:qs,m<1801:hdj,R}",
+        )?;
+        // Only the named workflows, not every possible hash
+        let named = workflows
+            .rules
+            .iter()
+            .enumerate()
+            .filter(|(_, rules)| !rules.is_empty())
+            .collect::<Vec<_>>();
+        insta::assert_debug_snapshot!("workflows", named);
+        insta::assert_debug_snapshot!("part", parse_part("{x=787,m=2655,a=1222,s=2876}")?);
+        Ok(())
+    }
+}
@@ -1,18 +1,105 @@
 use std::ops::Range;
 
+use aoc_common::params::{ParamSpec, Params};
+use aoc_common::parse;
+
 use crate::custom_error::AocError;
+use crate::schema;
+
+pub const PARAMS: &[ParamSpec] = &[ParamSpec {
+    name: "max_rating",
+    help: "Highest rating of each category, the lowest being 1",
+    default: 4000,
+    min: 1,
+    max: 1_000_000,
+}];
 
 pub fn process(input: &str) -> miette::Result<String, AocError> {
-    let mut input_split = input.split("\n\n");
+    process_with_params(input, &Params::defaults(PARAMS))
+}
+
+pub fn process_with_params(input: &str, params: &Params) -> miette::Result<String, AocError> {
+    let result = accepted_boxes(input, params.get("max_rating"))?
+        .iter()
+        .map(|accepted| {
+            accepted
+                .ratings
+                .iter()
+                .fold(1, |acc, r| acc * (r.end - r.start) as u128)
+        })
+        .sum::<u128>();
+
+    Ok(result.to_string())
+}
+
+/// Ratings accepted by one `A` rule, every combination of them reaching it from `in`
+pub(crate) struct AcceptedBox<'a> {
+    pub workflow: &'a str,
+    /// Position of the rule in its workflow
+    pub rule: usize,
+    /// Half-open range of each rating, in "xmas" order
+    pub ratings: [Range<u32>; 4],
+}
+
+pub(crate) fn accepted_boxes(
+    input: &str,
+    max_rating: u32,
+) -> Result<Vec<AcceptedBox<'_>>, AocError> {
+    schema::check(input)?;
+    let workflows_list = input.split("\n\n").next().unwrap_or_default();
+    let (workflows, accepted) = parse_workflows(workflows_list)?;
 
-    let workflows_list = input_split.next().unwrap().as_bytes();
+    let start_workflow = hash("in".as_bytes());
+    let mut boxes = vec![];
+
+    let rating_range: Range<u32> = 1..max_rating + 1;
 
+    // work backwards from each 'A' endpoint
+    'accepted: for (workflow, location) in accepted {
+        let RuleLocation {
+            mut workflow_index,
+            mut rule_index,
+        } = location;
+        let mut ranges: [Range<u32>; 4] = std::array::from_fn(|_| rating_range.clone());
+        loop {
+            for i in (0..=rule_index).rev() {
+                let mut condition = workflows[workflow_index].conditions[i];
+                if i != rule_index {
+                    condition.negate();
+                }
+                if !condition.apply(&mut ranges) {
+                    continue 'accepted;
+                }
+            }
+            if workflow_index == start_workflow {
+                break;
+            }
+            RuleLocation {
+                workflow_index,
+                rule_index,
+            } = workflows[workflow_index].parent;
+        }
+        boxes.push(AcceptedBox {
+            workflow,
+            rule: location.rule_index,
+            ratings: ranges,
+        });
+    }
+    Ok(boxes)
+}
+
+/// Location of every rule accepting parts, along with its workflow's name
+type Accepted<'a> = Vec<(&'a str, RuleLocation)>;
+
+/// Every workflow indexed by hashed name, linked to the rule sending parts to it, and the location
+/// of every rule accepting parts along with its workflow's name
+fn parse_workflows(workflows_list: &str) -> Result<(Vec<Workflow>, Accepted<'_>), AocError> {
     let largest_index = hash("zzz".as_bytes());
     let mut workflows = vec![Workflow::default(); largest_index + 1];
     let mut accepted = vec![];
 
     // parse workflows
-    for line in workflows_list.split(|&c| c == b'\n') {
+    for line in workflows_list.as_bytes().split(|&c| c == b'\n') {
         let divider = line.iter().position(|&c| c == b'{').unwrap();
         let index = hash(&line[..divider]);
 
@@ -21,12 +108,13 @@ pub fn process(input: &str) -> miette::Result<String, AocError> {
             .enumerate()
         {
             let (condition, target_start) = match rule.iter().position(|&c| c == b':') {
-                Some(colon_index) => (get_condition(&rule[..colon_index]), colon_index + 1),
+                Some(colon_index) => (get_condition(&rule[..colon_index])?, colon_index + 1),
                 None => (Condition::None, 0),
             };
             match rule[target_start] {
                 b'A' => {
-                    accepted.push(RuleLocation::new(index, rule_index));
+                    let name = std::str::from_utf8(&line[..divider]).unwrap();
+                    accepted.push((name, RuleLocation::new(index, rule_index)));
                 }
                 b'R' => (),
                 _ => {
@@ -37,44 +125,10 @@ pub fn process(input: &str) -> miette::Result<String, AocError> {
             workflows[index].add_condition(condition);
         }
     }
-    let start_workflow = hash("in".as_bytes());
-    let mut result = 0;
-
-    // work backwards from each 'A' endpoint
-    'accepted: for RuleLocation {
-        mut workflow_index,
-        mut rule_index,
-    } in accepted
-    {
-        const DEFAULT_RANGE: Range<u32> = 1..4000 + 1;
-        let mut ranges = [DEFAULT_RANGE; 4];
-        loop {
-            for i in (0..=rule_index).rev() {
-                let mut condition = workflows[workflow_index].conditions[i];
-                if i != rule_index {
-                    condition.negate();
-                }
-                if !condition.apply(&mut ranges) {
-                    continue 'accepted;
-                }
-            }
-            if workflow_index == start_workflow {
-                break;
-            }
-            RuleLocation {
-                workflow_index,
-                rule_index,
-            } = workflows[workflow_index].parent;
-        }
-        result += ranges
-            .iter()
-            .fold(1, |acc, r| acc * (r.end - r.start) as u128);
-    }
-
-    Ok(result.to_string())
+    Ok((workflows, accepted))
 }
 
-#[derive(Default, Clone)]
+#[derive(Default, Clone, Debug)]
 struct RuleLocation {
     workflow_index: usize,
     rule_index: usize,
@@ -89,7 +143,7 @@ impl RuleLocation {
     }
 }
 
-#[derive(Default, Clone)]
+#[derive(Default, Clone, Debug)]
 struct Workflow {
     parent: RuleLocation,
     conditions: Vec<Condition>,
@@ -121,10 +175,8 @@ fn unhash(mut workflow_index: usize) -> String {
     std::str::from_utf8(&bytes).unwrap().to_string()
 }
 
-fn get_condition(condition: &[u8]) -> Condition {
-    let value = condition[2..]
-        .iter()
-        .fold(0, |acc, d| (acc * 10) + (d - b'0') as u32);
+fn get_condition(condition: &[u8]) -> Result<Condition, AocError> {
+    let value = parse::decimal::<u32>(&condition[2..])?;
     let index = match condition[0] {
         b'x' => 0,
         b'm' => 1,
@@ -132,11 +184,11 @@ fn get_condition(condition: &[u8]) -> Condition {
         b's' => 3,
         _ => unreachable!("Must be in \"xmas\""),
     };
-    match condition[1] {
+    Ok(match condition[1] {
         b'<' => Condition::LessThan(index, value),
         b'>' => Condition::GreaterThan(index, value),
         _ => unreachable!("Must be either '>' or '<'"),
-    }
+    })
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -177,6 +229,8 @@ impl Condition {
 
 #[cfg(test)]
 mod tests {
+    use std::fmt::Write;
+
     use super::*;
 
     #[test]
@@ -200,6 +254,50 @@ hdj{m>838:A,pv}
 {x=2461,m=1339,a=466,s=291}
 {x=2127,m=1623,a=2188,s=1013}";
         assert_eq!("167409079868000", process(input)?);
+
+        // Only {x=1,m=1,a=1,s=1} is left, which `in` -> `px` -> `qkq` accepts
+        let params = Params::resolve(PARAMS, &[("max_rating".to_string(), "1".to_string())])?;
+        assert_eq!("1", process_with_params(input, &params)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_snapshot() -> miette::Result<()> {
+        let workflows_list = "\
+px{a<2006:qkq,m>2090:A,rfg}
+pv{a>1716:R,A}
+lnx{m>1548:A,A}
+rfg{s<537:gd,x>2440:R,A}
+qs{s>3448:A,lnx}
+qkq{x<1416:A,crn}
+crn{x>2662:A,R}
+in{s<1351:px,qqz}
+qqz{s>2770:qs,m<1801:hdj,R}
+gd{a>3333:R,R}
+hdj{m>838:A,pv}";
+        let (workflows, accepted) = parse_workflows(workflows_list)?;
+
+        let mut snapshot = String::new();
+        for (index, workflow) in workflows.iter().enumerate() {
+            if workflow.conditions.is_empty() {
+                continue;
+            }
+            let RuleLocation {
+                workflow_index,
+                rule_index,
+            } = workflow.parent;
+            let parent = if index == hash(b"in") {
+                "-".to_string()
+            } else {
+                format!("{} rule {}", unhash(workflow_index), rule_index + 1)
+            };
+            let conditions = &workflow.conditions;
+            writeln!(snapshot, "{}: parent {parent}, {conditions:?}", unhash(index)).unwrap();
+        }
+        for (name, location) in accepted {
+            writeln!(snapshot, "accepted by {name} rule {}", location.rule_index + 1).unwrap();
+        }
+        insta::assert_snapshot!("workflows", snapshot);
         Ok(())
     }
 }
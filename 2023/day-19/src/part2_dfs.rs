@@ -1,8 +1,18 @@
 use std::ops::Range;
 
+use aoc_common::params::Params;
+use aoc_common::parse;
+
 use crate::custom_error::AocError;
+pub use crate::part2::PARAMS;
+use crate::schema;
 
 pub fn process(input: &str) -> miette::Result<String, AocError> {
+    process_with_params(input, &Params::defaults(PARAMS))
+}
+
+pub fn process_with_params(input: &str, params: &Params) -> miette::Result<String, AocError> {
+    schema::check(input)?;
     let mut input_split = input.split("\n\n");
 
     let workflows_list = input_split.next().unwrap().as_bytes();
@@ -17,7 +27,7 @@ pub fn process(input: &str) -> miette::Result<String, AocError> {
 
         for rule in line[divider + 1..line.len() - 1].split(|&c| c == b',') {
             let (condition, target_start) = match rule.iter().position(|&c| c == b':') {
-                Some(colon_index) => (get_condition(&rule[..colon_index]), colon_index + 1),
+                Some(colon_index) => (get_condition(&rule[..colon_index])?, colon_index + 1),
                 None => (Condition::None, 0),
             };
             let target = match rule[target_start] {
@@ -34,8 +44,9 @@ pub fn process(input: &str) -> miette::Result<String, AocError> {
     let start_workflow = hash("in".as_bytes());
     let mut result = 0;
 
-    const DEFAULT_RANGE: Range<u32> = 1..4000 + 1;
-    let mut stack = vec![(start_workflow, [DEFAULT_RANGE; 4])];
+    let max_rating: u32 = params.get("max_rating");
+    let rating_range: Range<u32> = 1..max_rating + 1;
+    let mut stack = vec![(start_workflow, std::array::from_fn(|_| rating_range.clone()))];
 
     // dfs
     while let Some((workflow_index, mut ranges)) = stack.pop() {
@@ -99,10 +110,8 @@ fn unhash(mut workflow_index: usize) -> String {
     std::str::from_utf8(&bytes).unwrap().to_string()
 }
 
-fn get_condition(condition: &[u8]) -> Condition {
-    let value = condition[2..]
-        .iter()
-        .fold(0, |acc, d| (acc * 10) + (d - b'0') as u32);
+fn get_condition(condition: &[u8]) -> Result<Condition, AocError> {
+    let value = parse::decimal::<u32>(&condition[2..])?;
     let index = match condition[0] {
         b'x' => 0,
         b'm' => 1,
@@ -110,11 +119,11 @@ fn get_condition(condition: &[u8]) -> Condition {
         b's' => 3,
         _ => unreachable!("Must be in \"xmas\""),
     };
-    match condition[1] {
+    Ok(match condition[1] {
         b'<' => Condition::LessThan(index, value),
         b'>' => Condition::GreaterThan(index, value),
         _ => unreachable!("Must be either '>' or '<'"),
-    }
+    })
 }
 
 #[derive(Clone, Debug)]
@@ -198,6 +207,10 @@ hdj{m>838:A,pv}
 {x=2461,m=1339,a=466,s=291}
 {x=2127,m=1623,a=2188,s=1013}";
         assert_eq!("167409079868000", process(input)?);
+
+        // Only {x=1,m=1,a=1,s=1} is left, which `in` -> `px` -> `qkq` accepts
+        let params = Params::resolve(PARAMS, &[("max_rating".to_string(), "1".to_string())])?;
+        assert_eq!("1", process_with_params(input, &params)?);
         Ok(())
     }
 }
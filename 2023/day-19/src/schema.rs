@@ -0,0 +1,207 @@
+//! Shape of the workflows and part ratings. Workflows are looked up by hashing names of at most
+//! 3 lowercase letters.
+
+use std::collections::HashSet;
+
+use aoc_common::schema::{Checker, InvalidInput};
+
+pub const SHAPE: &str = "workflows like `px{a<2006:qkq,m>2090:A,rfg}` named by 1 to 3 lowercase \
+                         letters, forming a tree from `in`, a blank line, then parts like \
+                         `{x=787,m=2655,a=1222,s=2876}`";
+
+fn is_name(name: &str) -> bool {
+    (1..=3).contains(&name.len()) && name.bytes().all(|b| b.is_ascii_lowercase())
+}
+
+/// The solvers read ratings as u32, and `parse` would also take a leading `+`
+fn is_rating(value: &str) -> bool {
+    value.bytes().all(|b| b.is_ascii_digit()) && value.parse::<u32>().is_ok()
+}
+
+pub fn check(input: &str) -> Result<(), InvalidInput> {
+    let mut checker = Checker::new(input, SHAPE);
+    let Some((workflows, parts)) = checker.split(input, "\n\n") else {
+        return checker.finish();
+    };
+
+    let mut names = vec![];
+    let mut targets = vec![];
+    for line in workflows.lines() {
+        let Some((name, rules)) = checker.split(line, "{") else {
+            continue;
+        };
+        checker.expect(
+            is_name(name),
+            name,
+            "names should be 1 to 3 lowercase letters",
+        );
+        names.push(name);
+        let Some(rules) = rules.strip_suffix('}') else {
+            checker.expect(false, line, "the rules should end with `}`");
+            continue;
+        };
+        let mut rules = rules.split(',').peekable();
+        while let Some(rule) = rules.next() {
+            let target = match rule.split_once(':') {
+                Some((condition, target)) => {
+                    let bytes = condition.as_bytes();
+                    let ok = bytes.len() > 2
+                        && b"xmas".contains(&bytes[0])
+                        && b"<>".contains(&bytes[1])
+                        && is_rating(&condition[2..]);
+                    checker.expect(ok, condition, "conditions should look like `a<2006`");
+                    // Otherwise a part matching none of the rules has nowhere to go
+                    let last = rules.peek().is_none();
+                    checker.expect(!last, rule, "the last rule should be without a condition");
+                    target
+                }
+                None => {
+                    let last = rules.peek().is_none();
+                    checker.expect(last, rule, "only the last rule can be without a condition");
+                    rule
+                }
+            };
+            if !matches!(target, "A" | "R") {
+                targets.push((name, target));
+            }
+        }
+    }
+    checker.expect(
+        names.contains(&"in"),
+        workflows,
+        "there should be an `in` workflow",
+    );
+    for &(_, target) in &targets {
+        checker.expect(names.contains(&target), target, "no workflow has this name");
+    }
+
+    // Part 2 walks back up from each `A` to `in` and the others follow the rules down from `in`,
+    // so the workflows should form a tree: each named by exactly one rule, none cut off from `in`
+    let mut reachable = HashSet::from(["in"]);
+    let mut queue = vec!["in"];
+    while let Some(name) = queue.pop() {
+        for &(from, target) in &targets {
+            if from == name && reachable.insert(target) {
+                queue.push(target);
+            }
+        }
+    }
+    for (i, &name) in names.iter().enumerate() {
+        if !checker.expect(
+            !names[..i].contains(&name),
+            name,
+            "another workflow has this name",
+        ) {
+            continue;
+        }
+        let named = targets
+            .iter()
+            .filter(|&&(_, target)| target == name)
+            .count();
+        if name == "in" {
+            checker.expect(named == 0, name, "no rule should send parts to `in`");
+        } else if checker.expect(
+            named == 1,
+            name,
+            format!("exactly one rule should send parts here, found {named}"),
+        ) {
+            checker.expect(
+                reachable.contains(name),
+                name,
+                "parts from `in` never reach this workflow",
+            );
+        }
+    }
+
+    for part in parts.lines() {
+        let ratings = part
+            .strip_prefix('{')
+            .and_then(|part| part.strip_suffix('}'))
+            .map(|ratings| ratings.split(',').collect::<Vec<_>>());
+        let ok = ratings.is_some_and(|ratings| {
+            ratings.len() == 4
+                && ratings
+                    .iter()
+                    .zip(["x=", "m=", "a=", "s="])
+                    .all(|(rating, category)| rating.strip_prefix(category).is_some_and(is_rating))
+        });
+        checker.expect(
+            ok,
+            part,
+            "parts should look like `{x=787,m=2655,a=1222,s=2876}`",
+        );
+    }
+    checker.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check() {
+        let input = "\
+px{a<2006:qkq,m>2090:A,rfg}
+in{s<1351:px,qqz}
+qqz{s>2770:qs,m<1801:hdj,R}
+qkq{x<1416:A,crn}
+rfg{s<537:gd,x>2440:R,A}
+qs{s>3448:A,lnx}
+hdj{m>838:A,pv}
+crn{x>2662:A,R}
+gd{a>1716:R,A}
+lnx{m>1548:A,A}
+pv{a>1716:R,A}
+
+{x=787,m=2655,a=1222,s=2876}
+{x=1679,m=44,a=2067,s=496}
+";
+        assert!(check(input).is_ok());
+        let invalid = check("in{a<20:abcd,R,A}\n\n{x=1,m=2,a=3}").unwrap_err();
+        assert_eq!(
+            [
+                (13..14, "only the last rule can be without a condition"),
+                (8..12, "no workflow has this name"),
+                (
+                    19..32,
+                    "parts should look like `{x=787,m=2655,a=1222,s=2876}`"
+                ),
+            ]
+            .map(|(span, message)| (span, message.to_string())),
+            invalid.violations()
+        );
+        assert_eq!(
+            [(
+                3..11,
+                "the last rule should be without a condition".to_string()
+            )],
+            check("in{x<1416:A}\n\n{x=1,m=2,a=3,s=4}")
+                .unwrap_err()
+                .violations()
+        );
+        let invalid =
+            check("in{x<1:a,R}\na{x<1:b,R}\nb{x<1:a,in}\nc{x<1:c,R}\nc{A}\n\n").unwrap_err();
+        assert_eq!(
+            [
+                (0..2, "no rule should send parts to `in`"),
+                (12..13, "exactly one rule should send parts here, found 2"),
+                (35..36, "parts from `in` never reach this workflow"),
+                (46..47, "another workflow has this name"),
+            ]
+            .map(|(span, message)| (span, message.to_string())),
+            invalid.violations()
+        );
+        let invalid = check("in{x<4294967296:A,R}\n\n{x=1,m=2,a=3,s=+4}").unwrap_err();
+        assert_eq!(
+            [
+                (3..15, "conditions should look like `a<2006`"),
+                (
+                    22..40,
+                    "parts should look like `{x=787,m=2655,a=1222,s=2876}`"
+                ),
+            ]
+            .map(|(span, message)| (span, message.to_string())),
+            invalid.violations()
+        );
+    }
+}
@@ -0,0 +1,285 @@
+//! Every day's solutions behind a single dependency, with a cargo feature per day.
+//!
+//! Each enabled `dayNN` module re-exports the day crate (`part1`, `part2`, the variant modules and
+//! `AocError`) and adds `solve(part, input)`, which runs the part's default solver.
+//!
+//! The days with types worth reusing outside the puzzle also have a `models` module, and a
+//! `parse(input)` building them from a whole puzzle input where they come from one. It returns an
+//! error rather than panicking on a malformed input:
+//!
+//! | Day | `models` | `parse` |
+//! | --- | --- | --- |
+//! | 01 | `Dictionary` | none, the words aren't part of the input |
+//! | 05 | `Mapping` | the seeds and the mappings of each map |
+//! | 07 | `HandBid`, `Category` | the hands |
+//! | 14 | `Platform` | the platform |
+//! | 16 | `BeamMap`, `Direction`, `Photon` | the contraption |
+//! | 17 | `BucketQueue`, with the pathfinder in `min_heat_loss` | none, it runs on the raw grid |
+//! | 19 | `Workflows` | the workflows and the ratings of every part |
+//!
+//! The other days only have `solve`, their solvers work straight on the input text.
+//!
+//! Only pulling in the days you need:
+//!
+//! ```toml
+//! aoc2023 = { path = "../aoc2023", default-features = false, features = ["day-05", "day-17"] }
+//! ```
+//!
+//! The `parallel` feature adds the rayon `process_parallel*` variants of the enabled days that
+//! have them.
+
+/// Which half of a day's puzzle to solve
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+macro_rules! day {
+    ($feature:literal, $module:ident, $krate:ident, $part1:path, $part2:path $(, $extra:item)*) => {
+        #[doc = concat!("Enabled by the `", $feature, "` feature.")]
+        #[cfg(feature = $feature)]
+        pub mod $module {
+            pub use $krate::custom_error::AocError;
+            pub use $krate::*;
+
+            /// Runs the default solver of `part`
+            pub fn solve(part: $crate::Part, input: &str) -> miette::Result<String, AocError> {
+                match part {
+                    $crate::Part::One => $part1(input),
+                    $crate::Part::Two => $part2(input),
+                }
+            }
+
+            $($extra)*
+        }
+    };
+}
+
+day!(
+    "day-01",
+    day01,
+    aoc2023_day_01,
+    part1::process,
+    part2::process,
+    pub mod models {
+        /// Words standing for digits, e.g. in another language, for `part2_aho_corasick`
+        pub use aoc2023_day_01::part2_aho_corasick::Dictionary;
+    }
+);
+day!(
+    "day-02",
+    day02,
+    aoc2023_day_02,
+    part1::process,
+    part2::process
+);
+day!(
+    "day-03",
+    day03,
+    aoc2023_day_03,
+    part1::process,
+    part2::process
+);
+day!(
+    "day-04",
+    day04,
+    aoc2023_day_04,
+    part1::process,
+    part2::process
+);
+day!(
+    "day-05",
+    day05,
+    aoc2023_day_05,
+    part1::process,
+    part2::process,
+    pub mod models {
+        /// Range mapper, one per line of an almanac map
+        pub use aoc2023_day_05::part1::Mapping;
+    },
+    pub use aoc2023_day_05::part2::parse;
+);
+day!(
+    "day-06",
+    day06,
+    aoc2023_day_06,
+    part1::process,
+    part2::process
+);
+day!(
+    "day-07",
+    day07,
+    aoc2023_day_07,
+    part1::process,
+    part2::process,
+    pub mod models {
+        pub use aoc2023_day_07::part1::{Category, HandBid, ParseHandBidError};
+    },
+    /// One hand per input line, with part 1's card values
+    pub fn parse(input: &str) -> Result<Vec<models::HandBid>, models::ParseHandBidError> {
+        input.lines().map(str::parse).collect()
+    }
+);
+day!(
+    "day-08",
+    day08,
+    aoc2023_day_08,
+    part1::process,
+    part2::process
+);
+day!(
+    "day-09",
+    day09,
+    aoc2023_day_09,
+    part1::process,
+    part2::process
+);
+day!(
+    "day-10",
+    day10,
+    aoc2023_day_10,
+    part1::process,
+    part2::process
+);
+day!(
+    "day-11",
+    day11,
+    aoc2023_day_11,
+    part1::process,
+    part2::process
+);
+day!(
+    "day-12",
+    day12,
+    aoc2023_day_12,
+    part1::process,
+    part2::process
+);
+day!(
+    "day-13",
+    day13,
+    aoc2023_day_13,
+    part1::process,
+    part2::process
+);
+day!(
+    "day-14",
+    day14,
+    aoc2023_day_14,
+    part1::process,
+    part2::process,
+    pub mod models {
+        pub use aoc2023_day_14::part2::Platform;
+    },
+    pub use aoc2023_day_14::part2::parse;
+);
+day!(
+    "day-15",
+    day15,
+    aoc2023_day_15,
+    part1::process,
+    part2::process
+);
+day!(
+    "day-16",
+    day16,
+    aoc2023_day_16,
+    part1::process,
+    part2::process,
+    pub mod models {
+        pub use aoc2023_day_16::part2::{BeamMap, Direction, Photon};
+    },
+    pub use aoc2023_day_16::part2::parse;
+);
+day!(
+    "day-17",
+    day17,
+    aoc2023_day_17,
+    part1::process_bucket,
+    part2::process,
+    pub mod models {
+        /// Priority queue of the pathfinder, see [`super::min_heat_loss`]
+        pub use aoc2023_day_17::part2::BucketQueue;
+    },
+    /// Least heat lost between the top left and bottom right blocks of `grid`, laid out like the
+    /// puzzle input, with part 1's crucible. `None` if the crucible can't reach the bottom right
+    pub fn min_heat_loss(grid: &str) -> Option<usize> {
+        part1::solve_bucket(grid.as_bytes())
+    },
+    /// Same as [`min_heat_loss`] with part 2's ultra crucible
+    pub fn min_heat_loss_ultra(grid: &str) -> Option<usize> {
+        part2::solve_bucket(grid.as_bytes())
+    }
+);
+day!(
+    "day-18",
+    day18,
+    aoc2023_day_18,
+    part1::process,
+    part2::process
+);
+day!(
+    "day-19",
+    day19,
+    aoc2023_day_19,
+    part1::process,
+    part2::process,
+    pub mod models {
+        pub use aoc2023_day_19::part1::Workflows;
+    },
+    pub use aoc2023_day_19::part1::parse;
+);
+
+#[cfg(all(test, feature = "all"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve() -> miette::Result<()> {
+        let input = "1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet";
+        assert_eq!("142", day01::solve(Part::One, input)?);
+        let input = "2413432311323\n3215453535623\n3255245654254\n3446585845452\n\
+                     4546657867536\n1438598798454\n4457876987766\n3637877979653\n\
+                     4654967986887\n4564679986453\n1224686865563\n2546548887735\n\
+                     4322674655533";
+        assert_eq!("102", day17::solve(Part::One, input)?);
+        assert_eq!("94", day17::solve(Part::Two, input)?);
+        assert_eq!(Some(102), day17::min_heat_loss(input));
+        assert_eq!(Some(94), day17::min_heat_loss_ultra(input));
+        Ok(())
+    }
+
+    #[test]
+    fn test_models() -> miette::Result<()> {
+        let dictionary = day01::models::Dictionary::new([("un", 1), ("deux", 2)])?;
+        assert_eq!(Some(21), dictionary.calibration_value("deuxun"));
+
+        let mapping = day05::models::Mapping::new(52, 50, 48);
+        let (mut mapped, mut outside) = (79, 98);
+        assert!(mapping.map(&mut mapped));
+        assert!(!mapping.map(&mut outside));
+        assert_eq!((81, 98), (mapped, outside));
+        let mut input = "seeds: 79 14\n\nseed-to-soil map:\n52 50 48\n50 98 2\n".to_string();
+        for map in ["a-to-b", "b-to-c", "c-to-d", "d-to-e", "e-to-f", "f-to-g"] {
+            input += &format!("\n{map} map:\n0 0 1\n");
+        }
+        let (seeds, maps) = day05::parse(&input)?;
+        assert_eq!(vec![79, 14], seeds);
+        let other = day05::models::Mapping::new(50, 98, 2);
+        assert_eq!(vec![mapping, other], maps[0]);
+        assert_eq!(7, maps.len());
+        assert!(day05::parse("seeds: 79\n").is_err());
+
+        let mut platform = day14::parse("O.\n.O")?;
+        assert_eq!(3, platform.calculate_north_load());
+        platform.tilt_north();
+        assert_eq!(4, platform.calculate_north_load());
+
+        let (workflows, parts) = day19::parse("in{x>10:A,R}\n\n{x=787,m=2655,a=1222,s=2876}")?;
+        assert_eq!(vec![[787, 2655, 1222, 2876]], parts);
+        assert!(workflows.accepts(parts[0]));
+        assert!(day19::parse("in{x>10:A,R}").is_err());
+        Ok(())
+    }
+}
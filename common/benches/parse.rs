@@ -0,0 +1,84 @@
+//! The shared parsers against what the days did before them: `str::parse` on whitespace-split
+//! tokens, and a hand-rolled digit fold without overflow checks.
+//!
+//! The days migrated to them compare the same on their own inputs, in the `parse_*` benches of
+//! days 5, 6, 9, 12, 18 and 19.
+
+use aoc_common::parse;
+
+fn main() {
+    // Run registered benchmarks.
+    divan::main();
+}
+
+/// Numbers spread over a few orders of magnitude like the inputs', a tenth of them negative
+fn input() -> String {
+    let mut state = 0x2545_f491_4f6c_dd1du64;
+    (0..10_000)
+        .map(|i| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            let number = (state % 10u64.pow(1 + i % 9)) as i64;
+            if i % 10 == 0 {
+                -number
+            } else {
+                number
+            }
+        })
+        .map(|number| number.to_string())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[divan::bench]
+fn str_parse(bencher: divan::Bencher) {
+    let input = input();
+    bencher.bench(|| {
+        divan::black_box(&input)
+            .split_whitespace()
+            .map(|number| number.parse::<i64>().unwrap())
+            .sum::<i64>()
+    });
+}
+
+#[divan::bench]
+fn hand_rolled_fold(bencher: divan::Bencher) {
+    let input = input();
+    bencher.bench(|| {
+        divan::black_box(&input)
+            .as_bytes()
+            .split(|c| c.is_ascii_whitespace())
+            .map(|number| match number.split_first() {
+                Some((b'-', digits)) => -digits
+                    .iter()
+                    .fold(0, |acc, &d| acc * 10 + (d - b'0') as i64),
+                _ => number
+                    .iter()
+                    .fold(0, |acc, &d| acc * 10 + (d - b'0') as i64),
+            })
+            .sum::<i64>()
+    });
+}
+
+#[divan::bench]
+fn decimal(bencher: divan::Bencher) {
+    let input = input();
+    bencher.bench(|| {
+        divan::black_box(&input)
+            .as_bytes()
+            .split(|c| c.is_ascii_whitespace())
+            .map(|number| parse::decimal::<i64>(number).unwrap())
+            .sum::<i64>()
+    });
+}
+
+#[divan::bench]
+fn numbers(bencher: divan::Bencher) {
+    let input = input();
+    bencher.bench(|| {
+        parse::numbers::<i64>(divan::black_box(&input))
+            .map(Result::unwrap)
+            .sum::<i64>()
+    });
+}
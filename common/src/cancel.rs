@@ -0,0 +1,155 @@
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use miette::Diagnostic;
+use thiserror::Error;
+
+use crate::progress::Progress;
+
+/// Returned by a solver that stopped early because its token was cancelled.
+#[derive(Error, Diagnostic, Debug, Clone, Copy, PartialEq, Eq)]
+#[error("solver was cancelled")]
+#[diagnostic(code(aoc::cancelled))]
+pub struct Cancelled;
+
+/// Cooperative cancellation token with an optional time budget.
+///
+/// Long-running solvers poll [`CancelToken::check`] from their hot loops. Checking reads the clock,
+/// so loops should only poll every few thousand iterations (see [`CancelToken::should_poll`]).
+/// Clones share the same cancellation flag.
+///
+/// The token also carries the run's [`Progress`], if any, since the solvers long enough to report
+/// progress are the ones polling it.
+#[derive(Clone, Default)]
+pub struct CancelToken {
+    cancelled: Arc<AtomicBool>,
+    deadline: Option<Instant>,
+    progress: Option<Arc<dyn Progress>>,
+}
+
+impl CancelToken {
+    /// Token that is only cancelled by an explicit call to [`CancelToken::cancel`].
+    pub fn new() -> CancelToken {
+        CancelToken::default()
+    }
+
+    /// Token that also cancels itself once `budget` has elapsed from now.
+    pub fn with_timeout(budget: Duration) -> CancelToken {
+        CancelToken {
+            cancelled: Arc::default(),
+            deadline: Instant::now().checked_add(budget),
+            progress: None,
+        }
+    }
+
+    /// Hands what solvers report with [`CancelToken::progress`] to `progress`
+    pub fn with_progress(self, progress: Arc<dyn Progress>) -> CancelToken {
+        CancelToken {
+            progress: Some(progress),
+            ..self
+        }
+    }
+
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        if self.cancelled.load(Ordering::Relaxed) {
+            return true;
+        }
+        match self.deadline {
+            Some(deadline) if Instant::now() >= deadline => {
+                self.cancel();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub fn check(&self) -> Result<(), Cancelled> {
+        if self.is_cancelled() {
+            Err(Cancelled)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Reports that `done` of `total` items are finished. Meant to be called next to
+    /// [`CancelToken::check`], it does nothing when no [`Progress`] is attached.
+    #[inline]
+    pub fn progress(&self, done: u64, total: u64) {
+        if let Some(progress) = &self.progress {
+            progress.update(done, total);
+        }
+    }
+
+    /// Cheap filter for hot loops: true once every `POLL_INTERVAL` iterations.
+    #[inline(always)]
+    pub fn should_poll(iteration: u64) -> bool {
+        iteration & (Self::POLL_INTERVAL - 1) == 0
+    }
+
+    /// Must be a power of 2.
+    pub const POLL_INTERVAL: u64 = 1 << 14;
+}
+
+impl fmt::Debug for CancelToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CancelToken")
+            .field("cancelled", &self.cancelled)
+            .field("deadline", &self.deadline)
+            .field("progress", &self.progress.is_some())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cancel_shared_between_clones() {
+        let token = CancelToken::new();
+        let clone = token.clone();
+        assert_eq!(Ok(()), clone.check());
+        token.cancel();
+        assert_eq!(Err(Cancelled), clone.check());
+    }
+
+    #[test]
+    fn test_timeout() {
+        let token = CancelToken::with_timeout(Duration::ZERO);
+        assert!(token.is_cancelled());
+        assert!(!CancelToken::with_timeout(Duration::from_secs(60)).is_cancelled());
+    }
+
+    #[test]
+    fn test_progress() {
+        struct Last(std::sync::Mutex<Option<(u64, u64)>>);
+
+        impl Progress for Last {
+            fn update(&self, done: u64, total: u64) {
+                *self.0.lock().unwrap() = Some((done, total));
+            }
+        }
+
+        // Nothing to report to
+        CancelToken::new().progress(1, 2);
+
+        let last = Arc::new(Last(Default::default()));
+        let token = CancelToken::with_timeout(Duration::from_secs(60)).with_progress(last.clone());
+        token.clone().progress(3, 4);
+        assert_eq!(Some((3, 4)), *last.0.lock().unwrap());
+        assert!(!token.is_cancelled());
+    }
+
+    #[test]
+    fn test_should_poll() {
+        assert!(CancelToken::should_poll(0));
+        assert!(!CancelToken::should_poll(1));
+        assert!(CancelToken::should_poll(CancelToken::POLL_INTERVAL * 3));
+    }
+}
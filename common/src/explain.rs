@@ -0,0 +1,179 @@
+//! Intermediate values of a solver, printed by `aoc run --explain` instead of commenting `dbg!`
+//! lines back in.
+
+use std::fmt;
+
+/// A value worth showing. Ranges and coordinates are lists.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Value {
+    Int(i128),
+    Bool(bool),
+    Text(String),
+    List(Vec<Value>),
+}
+
+macro_rules! int_value {
+    ($($int:ty),*) => {
+        $(impl From<$int> for Value {
+            fn from(value: $int) -> Value {
+                Value::Int(value as i128)
+            }
+        })*
+    };
+}
+
+int_value!(i32, i64, i128, u8, u32, u64, usize);
+
+impl From<bool> for Value {
+    fn from(value: bool) -> Value {
+        Value::Bool(value)
+    }
+}
+
+impl From<&str> for Value {
+    fn from(value: &str) -> Value {
+        Value::Text(value.to_string())
+    }
+}
+
+impl From<String> for Value {
+    fn from(value: String) -> Value {
+        Value::Text(value)
+    }
+}
+
+impl<T: Into<Value>> From<Vec<T>> for Value {
+    fn from(values: Vec<T>) -> Value {
+        Value::List(values.into_iter().map(Into::into).collect())
+    }
+}
+
+impl<T: Into<Value>, const N: usize> From<[T; N]> for Value {
+    fn from(values: [T; N]) -> Value {
+        Value::List(values.into_iter().map(Into::into).collect())
+    }
+}
+
+/// Text is quoted. A precision, e.g. `{:.10}`, cuts lists short after that many items.
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Int(int) => write!(f, "{int}"),
+            Value::Bool(bool) => write!(f, "{bool}"),
+            Value::Text(text) => write!(f, "{text:?}"),
+            Value::List(values) => {
+                let max_len = f.precision().unwrap_or(usize::MAX);
+                write!(f, "[")?;
+                for (i, value) in values.iter().take(max_len).enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    match f.precision() {
+                        Some(precision) => write!(f, "{value:.precision$}")?,
+                        None => write!(f, "{value}")?,
+                    }
+                }
+                if values.len() > max_len {
+                    let separator = if max_len > 0 { ", " } else { "" };
+                    write!(f, "{separator}... {} more", values.len() - max_len)?;
+                }
+                write!(f, "]")
+            }
+        }
+    }
+}
+
+/// Named values about one thing, e.g. one input line
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    pub label: String,
+    pub fields: Vec<(&'static str, Value)>,
+}
+
+impl Record {
+    pub fn field(&mut self, name: &'static str, value: impl Into<Value>) -> &mut Record {
+        self.fields.push((name, value.into()));
+        self
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Value> {
+        self.fields
+            .iter()
+            .find(|(field, _)| *field == name)
+            .map(|(_, value)| value)
+    }
+}
+
+/// `label: name value, name value`, passing the precision on to the values
+impl fmt::Display for Record {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:", self.label)?;
+        for (i, (name, value)) in self.fields.iter().enumerate() {
+            let separator = if i == 0 { " " } else { ", " };
+            match f.precision() {
+                Some(precision) => write!(f, "{separator}{name} {value:.precision$}")?,
+                None => write!(f, "{separator}{name} {value}")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Everything a solver explains about one run, in the order it was found.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Explanation {
+    pub records: Vec<Record>,
+}
+
+impl Explanation {
+    pub fn new() -> Explanation {
+        Explanation::default()
+    }
+
+    /// Starts a new record, filled in with [`Record::field`]
+    pub fn record(&mut self, label: impl Into<String>) -> &mut Record {
+        self.records.push(Record {
+            label: label.into(),
+            fields: vec![],
+        });
+        self.records.last_mut().unwrap()
+    }
+}
+
+/// One record per line
+impl fmt::Display for Explanation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for record in &self.records {
+            writeln!(f, "{record}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_explanation() {
+        let mut explanation = Explanation::new();
+        explanation.record("line 1").field("value", 12).field("digits", [1, 2]);
+        explanation.record("line 2").field("valid", false).field("text", "a1");
+
+        let first = &explanation.records[0];
+        assert_eq!("line 1", first.label);
+        assert_eq!(Some(&Value::Int(12)), first.get("value"));
+        assert_eq!(
+            Some(&Value::List(vec![Value::Int(1), Value::Int(2)])),
+            first.get("digits")
+        );
+        assert_eq!(None, first.get("valid"));
+        assert_eq!(Some(&Value::Text("a1".into())), explanation.records[1].get("text"));
+
+        assert_eq!(
+            "line 1: value 12, digits [1, 2]\nline 2: valid false, text \"a1\"\n",
+            explanation.to_string()
+        );
+        assert_eq!("line 1: value 12, digits [1, ... 1 more]", format!("{first:.1}"));
+    }
+}
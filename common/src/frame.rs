@@ -0,0 +1,124 @@
+//! Grid snapshots that solvers hand to the `aoc-viz` terminal viewer.
+
+/// Colour of a cell. The viewer maps these to ANSI colours.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Colour {
+    #[default]
+    Default,
+    /// Background detail, e.g. tiles the solver hasn't reached
+    Dim,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cell {
+    pub glyph: char,
+    pub colour: Colour,
+}
+
+impl Cell {
+    pub fn new(glyph: char, colour: Colour) -> Cell {
+        Cell { glyph, colour }
+    }
+}
+
+/// One step of a solver, drawn as a `width` x `height` grid of cells.
+pub trait Frame {
+    fn width(&self) -> usize;
+
+    fn height(&self) -> usize;
+
+    /// Only called with `x < width()` and `y < height()`
+    fn cell(&self, x: usize, y: usize) -> Cell;
+
+    /// Shown in the viewer's status line, e.g. the load or cost so far
+    fn caption(&self) -> String {
+        String::new()
+    }
+}
+
+/// The glyph of every cell without colours, one line per row. Handy for snapshot tests.
+pub fn to_text(frame: &impl Frame) -> String {
+    let mut text = String::new();
+    for y in 0..frame.height() {
+        text.extend((0..frame.width()).map(|x| frame.cell(x, y).glyph));
+        text.push('\n');
+    }
+    text
+}
+
+/// Owned frame for solvers without a state type worth implementing [`Frame`] on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid {
+    width: usize,
+    cells: Vec<Cell>,
+    caption: String,
+}
+
+impl Grid {
+    /// Every character of `text` in the default colour. Shorter lines are padded with spaces.
+    pub fn from_text(text: &str) -> Grid {
+        let width = text.lines().map(|line| line.chars().count()).max().unwrap_or(0);
+        let mut cells = vec![];
+        for line in text.lines() {
+            let padded = line.chars().chain(std::iter::repeat(' ')).take(width);
+            cells.extend(padded.map(|glyph| Cell::new(glyph, Colour::Default)));
+        }
+        Grid {
+            width,
+            cells,
+            caption: String::new(),
+        }
+    }
+
+    pub fn set(&mut self, x: usize, y: usize, cell: Cell) {
+        self.cells[y * self.width + x] = cell;
+    }
+
+    pub fn set_caption(&mut self, caption: impl Into<String>) {
+        self.caption = caption.into();
+    }
+}
+
+impl Frame for Grid {
+    fn width(&self) -> usize {
+        self.width
+    }
+
+    fn height(&self) -> usize {
+        self.cells.len().checked_div(self.width).unwrap_or(0)
+    }
+
+    fn cell(&self, x: usize, y: usize) -> Cell {
+        self.cells[y * self.width + x]
+    }
+
+    fn caption(&self) -> String {
+        self.caption.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_grid_from_text() {
+        let mut grid = Grid::from_text("#.\n.\n");
+        assert_eq!((2, 2), (grid.width(), grid.height()));
+        assert_eq!(Cell::new(' ', Colour::Default), grid.cell(1, 1));
+
+        grid.set(1, 0, Cell::new('O', Colour::Red));
+        grid.set_caption("load 1");
+        assert_eq!(Cell::new('O', Colour::Red), grid.cell(1, 0));
+        assert_eq!("load 1", grid.caption());
+        assert_eq!("#O\n. \n", to_text(&grid));
+
+        assert_eq!(0, Grid::from_text("").height());
+    }
+}
@@ -0,0 +1,121 @@
+//! The hasher behind the days' hash maps and sets, picked with one of `aoc-common`'s cargo
+//! features:
+//!
+//! - `hasher-std`: std's SipHash-1-3, randomly keyed
+//! - `hasher-ahash`: aHash, also randomly keyed, and used when none of them is enabled
+//! - `hasher-fx`: FxHash, the compiler's hasher, fast on short keys
+//! - `hasher-sea`: SeaHash
+//!
+//! The keyed ones resist HashDoS, as colliding keys can't be crafted without knowing the map's
+//! keys. FxHash and SeaHash are unkeyed, so they hash the same everywhere and shouldn't see
+//! untrusted keys.
+//!
+//! e.g. `cargo bench -p aoc2023-day-08 --features aoc-common/hasher-fx`. Features are unified over
+//! everything being built, so if several are enabled the first one in this list wins. Every hasher
+//! can still be named, which lets benchmarks sweep all of them in one run.
+
+use std::collections::hash_map::{DefaultHasher, RandomState};
+use std::collections::{HashMap, HashSet};
+use std::hash::{BuildHasher, BuildHasherDefault};
+
+use fxhash::FxHasher;
+use seahash::SeaHasher;
+
+macro_rules! state {
+    ($(#[$doc:meta])* $name:ident($inner:ty) -> $hasher:ty) => {
+        $(#[$doc])*
+        #[derive(Debug, Clone, Default)]
+        pub struct $name($inner);
+
+        impl BuildHasher for $name {
+            type Hasher = $hasher;
+
+            #[inline]
+            fn build_hasher(&self) -> Self::Hasher {
+                self.0.build_hasher()
+            }
+        }
+    };
+}
+
+state!(
+    /// std's SipHash-1-3, keyed randomly per map
+    StdState(RandomState) -> DefaultHasher
+);
+state!(
+    /// aHash, keyed randomly per map
+    AHashState(ahash::RandomState) -> ahash::AHasher
+);
+state!(
+    /// FxHash, unkeyed
+    FxState(BuildHasherDefault<FxHasher>) -> FxHasher
+);
+state!(
+    /// SeaHash, unkeyed
+    SeaState(BuildHasherDefault<SeaHasher>) -> SeaHasher
+);
+
+/// The hasher selected with the `hasher-*` features
+#[cfg(feature = "hasher-std")]
+pub type FastState = StdState;
+/// The hasher selected with the `hasher-*` features
+#[cfg(all(
+    not(feature = "hasher-std"),
+    any(
+        feature = "hasher-ahash",
+        not(any(feature = "hasher-fx", feature = "hasher-sea"))
+    )
+))]
+pub type FastState = AHashState;
+/// The hasher selected with the `hasher-*` features
+#[cfg(all(
+    feature = "hasher-fx",
+    not(any(feature = "hasher-std", feature = "hasher-ahash"))
+))]
+pub type FastState = FxState;
+/// The hasher selected with the `hasher-*` features
+#[cfg(all(
+    feature = "hasher-sea",
+    not(any(
+        feature = "hasher-std",
+        feature = "hasher-ahash",
+        feature = "hasher-fx"
+    ))
+))]
+pub type FastState = SeaState;
+
+pub type FastMap<K, V> = HashMap<K, V, FastState>;
+pub type FastSet<T> = HashSet<T, FastState>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::hash::Hash;
+
+    fn hash_twice<S: BuildHasher + Default>(value: impl Hash) -> (u64, u64) {
+        let state = S::default();
+        (state.hash_one(&value), state.hash_one(&value))
+    }
+
+    #[test]
+    fn test_states() {
+        for (first, second) in [
+            hash_twice::<StdState>("AAA"),
+            hash_twice::<AHashState>("AAA"),
+            hash_twice::<FxState>("AAA"),
+            hash_twice::<SeaState>("AAA"),
+        ] {
+            assert_eq!(first, second);
+        }
+        // The unkeyed hashers agree between maps
+        assert_eq!(hash_twice::<FxState>(7), hash_twice::<FxState>(7));
+
+        let map = [("AAA", 1), ("BBB", 2)]
+            .into_iter()
+            .collect::<FastMap<_, _>>();
+        assert_eq!(2, map["BBB"]);
+        let set = ["AAA", "AAA"].into_iter().collect::<FastSet<_>>();
+        assert_eq!(1, set.len());
+    }
+}
@@ -0,0 +1,253 @@
+//! Runtime selection between builds of the days' SIMD variants for several x86-64 target levels.
+//!
+//! [`multiversion!`](crate::multiversion) compiles a kernel once per [`Isa`] with that level's
+//! target features enabled, so the compiler vectorizes it with the widest registers available, and
+//! runs the build for the [`Isa`] it is given, usually [`Isa::selected`]. Other architectures only
+//! have the scalar build.
+
+use std::fmt;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+use miette::Diagnostic;
+use thiserror::Error;
+
+/// Target levels, following the x86-64 microarchitecture levels
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Isa {
+    /// The baseline every x86-64 CPU, and every other architecture, supports
+    Scalar,
+    /// x86-64-v2: SSE4.2 and POPCNT
+    Sse42,
+    /// x86-64-v3: AVX2, BMI1, BMI2, FMA and LZCNT
+    Avx2,
+    /// x86-64-v4: AVX-512 F, BW, CD, DQ and VL
+    Avx512,
+}
+
+/// `0` when nothing is forced, otherwise the forced [`Isa`]'s index in [`Isa::ALL`] plus one
+static FORCED: AtomicU8 = AtomicU8::new(0);
+
+impl Isa {
+    /// From the most portable level to the widest
+    pub const ALL: [Isa; 4] = [Isa::Scalar, Isa::Sse42, Isa::Avx2, Isa::Avx512];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Isa::Scalar => "scalar",
+            Isa::Sse42 => "sse4.2",
+            Isa::Avx2 => "avx2",
+            Isa::Avx512 => "avx512",
+        }
+    }
+
+    /// Whether this CPU can run the builds for this level
+    pub fn is_supported(self) -> bool {
+        #[cfg(target_arch = "x86_64")]
+        {
+            use std::arch::is_x86_feature_detected as detected;
+
+            let v2 = || detected!("sse4.2") && detected!("popcnt");
+            let v3 = || {
+                v2() && detected!("avx2")
+                    && detected!("bmi1")
+                    && detected!("bmi2")
+                    && detected!("fma")
+                    && detected!("lzcnt")
+            };
+            let v4 = || {
+                v3() && detected!("avx512f")
+                    && detected!("avx512bw")
+                    && detected!("avx512cd")
+                    && detected!("avx512dq")
+                    && detected!("avx512vl")
+            };
+            match self {
+                Isa::Scalar => true,
+                Isa::Sse42 => v2(),
+                Isa::Avx2 => v3(),
+                Isa::Avx512 => v4(),
+            }
+        }
+        #[cfg(not(target_arch = "x86_64"))]
+        {
+            self == Isa::Scalar
+        }
+    }
+
+    /// Levels this CPU supports, starting with [`Isa::Scalar`]
+    pub fn supported() -> Vec<Isa> {
+        Isa::ALL
+            .into_iter()
+            .filter(|isa| isa.is_supported())
+            .collect()
+    }
+
+    /// The widest level this CPU supports
+    pub fn best() -> Isa {
+        Isa::ALL
+            .into_iter()
+            .rev()
+            .find(|isa| isa.is_supported())
+            .unwrap_or(Isa::Scalar)
+    }
+
+    /// The level the SIMD variants run: the one forced with [`Isa::force`], otherwise the best
+    pub fn selected() -> Isa {
+        match FORCED.load(Ordering::Relaxed) {
+            0 => Isa::best(),
+            forced => Isa::ALL[forced as usize - 1],
+        }
+    }
+
+    /// Makes [`Isa::selected`] return `isa` for the rest of the process, or the best level again
+    /// with `None`, e.g. to benchmark every build in turn
+    pub fn force(isa: Option<Isa>) -> Result<(), UnsupportedIsa> {
+        let forced = match isa {
+            Some(isa) if !isa.is_supported() => return Err(UnsupportedIsa { isa }),
+            Some(isa) => Isa::ALL.iter().position(|&level| level == isa).unwrap() as u8 + 1,
+            None => 0,
+        };
+        FORCED.store(forced, Ordering::Relaxed);
+        Ok(())
+    }
+}
+
+impl fmt::Display for Isa {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for Isa {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Isa, String> {
+        Isa::ALL
+            .into_iter()
+            .find(|isa| isa.name() == s)
+            .ok_or_else(|| {
+                let names = Isa::ALL.map(Isa::name).join(", ");
+                format!("`{s}` is not a target level, expected one of {names}")
+            })
+    }
+}
+
+/// A level was forced that this CPU can't run, which would be undefined behaviour
+#[derive(Debug, Error, Diagnostic)]
+#[error("this CPU doesn't support {isa}")]
+#[diagnostic(
+    code(aoc::isa::unsupported),
+    help("supported levels: {}", supported_names())
+)]
+pub struct UnsupportedIsa {
+    pub isa: Isa,
+}
+
+fn supported_names() -> String {
+    Isa::supported()
+        .iter()
+        .map(|isa| isa.name())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Defines `fn name(isa: Isa, args..) -> T` running the build of `kernel(args..)` for `isa`, which
+/// must be supported by this CPU. The kernel and everything it calls in its hot loop should be
+/// `#[inline(always)]`, so they're compiled into each build with its target features.
+///
+/// ```
+/// #[inline(always)]
+/// fn sum_kernel(values: &[u32]) -> u32 {
+///     values.iter().sum()
+/// }
+///
+/// aoc_common::multiversion! {
+///     /// Sum of `values`
+///     pub fn sum(values: &[u32]) -> u32 = sum_kernel;
+/// }
+///
+/// use aoc_common::isa::Isa;
+/// assert_eq!(6, sum(Isa::selected(), &[1, 2, 3]));
+/// ```
+#[macro_export]
+macro_rules! multiversion {
+    (
+        $(#[$attr:meta])*
+        $vis:vis fn $name:ident($($arg:ident: $ty:ty),* $(,)?) -> $ret:ty = $kernel:path;
+    ) => {
+        $(#[$attr])*
+        $vis fn $name(isa: $crate::isa::Isa, $($arg: $ty),*) -> $ret {
+            assert!(isa.is_supported(), "this CPU doesn't support {isa}");
+            #[cfg(target_arch = "x86_64")]
+            {
+                #[target_feature(enable = "sse4.2,popcnt")]
+                unsafe fn sse42($($arg: $ty),*) -> $ret {
+                    $kernel($($arg),*)
+                }
+
+                #[target_feature(enable = "avx2,bmi1,bmi2,fma,lzcnt,popcnt")]
+                unsafe fn avx2($($arg: $ty),*) -> $ret {
+                    $kernel($($arg),*)
+                }
+
+                #[target_feature(enable = "avx512f,avx512bw,avx512cd,avx512dq,avx512vl")]
+                #[target_feature(enable = "avx2,bmi1,bmi2,fma,lzcnt,popcnt")]
+                unsafe fn avx512($($arg: $ty),*) -> $ret {
+                    $kernel($($arg),*)
+                }
+
+                // SAFETY: the CPU supports the target features of `isa`, checked above
+                match isa {
+                    $crate::isa::Isa::Scalar => {}
+                    $crate::isa::Isa::Sse42 => return unsafe { sse42($($arg),*) },
+                    $crate::isa::Isa::Avx2 => return unsafe { avx2($($arg),*) },
+                    $crate::isa::Isa::Avx512 => return unsafe { avx512($($arg),*) },
+                }
+            }
+            $kernel($($arg),*)
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[inline(always)]
+    fn dot_kernel(a: &[i32], b: &[i32]) -> i32 {
+        a.iter().zip(b).map(|(a, b)| a * b).sum()
+    }
+
+    crate::multiversion! {
+        fn dot(a: &[i32], b: &[i32]) -> i32 = dot_kernel;
+    }
+
+    #[test]
+    fn test_multiversion() {
+        let a = (0..100).collect::<Vec<_>>();
+        let b = (0..100).rev().collect::<Vec<_>>();
+        let supported = Isa::supported();
+        assert_eq!(Isa::Scalar, supported[0]);
+        for isa in supported {
+            assert_eq!(dot_kernel(&a, &b), dot(isa, &a, &b), "{isa}");
+        }
+    }
+
+    #[test]
+    fn test_force() {
+        assert_eq!(Ok(Isa::Avx2), "avx2".parse());
+        assert!("avx"
+            .parse::<Isa>()
+            .unwrap_err()
+            .contains("scalar, sse4.2, avx2, avx512"));
+
+        Isa::force(Some(Isa::Scalar)).unwrap();
+        assert_eq!(Isa::Scalar, Isa::selected());
+        Isa::force(None).unwrap();
+        assert_eq!(Isa::best(), Isa::selected());
+        if let Some(&unsupported) = Isa::ALL.iter().find(|isa| !isa.is_supported()) {
+            assert!(Isa::force(Some(unsupported)).is_err());
+        }
+    }
+}
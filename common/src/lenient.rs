@@ -0,0 +1,209 @@
+//! Opt-in error recovery for line-oriented days: records that don't have the day's input shape
+//! are skipped instead of panicking the solver, and reported next to the answer over the others.
+
+use std::fmt;
+use std::ops::Range;
+
+use miette::{Diagnostic, LabeledSpan, SourceCode};
+use thiserror::Error;
+
+use crate::schema::InvalidInput;
+
+/// The records of an input a solver can use, and the ones it skips
+pub struct Lenient<'a> {
+    input: &'a str,
+    valid: Vec<&'a str>,
+    skipped: Vec<Skipped>,
+}
+
+impl<'a> Lenient<'a> {
+    /// No records yet, for days whose records aren't lines
+    pub fn new(input: &'a str) -> Lenient<'a> {
+        Lenient {
+            input,
+            valid: vec![],
+            skipped: vec![],
+        }
+    }
+
+    /// Checks every line on its own with `check`, usually the day's `schema::check`, and keeps the
+    /// lines that pass
+    pub fn lines(input: &'a str, check: impl Fn(&str) -> Result<(), InvalidInput>) -> Lenient<'a> {
+        let mut lenient = Lenient::new(input);
+        for (index, line) in input.lines().enumerate() {
+            let start = lenient.span(line).start;
+            let violations = if line.trim().is_empty() {
+                vec![(start..start + line.len(), "the line is empty".to_string())]
+            } else {
+                match check(line) {
+                    Ok(()) => {
+                        lenient.valid.push(line);
+                        continue;
+                    }
+                    Err(invalid) => invalid
+                        .violations()
+                        .iter()
+                        .map(|(span, message)| {
+                            (start + span.start..start + span.end, message.clone())
+                        })
+                        .collect(),
+                }
+            };
+            lenient.skip(format!("skipped line {}", index + 1), violations);
+        }
+        lenient
+    }
+
+    /// Byte range of `part`, which must be a slice of the input
+    pub fn span(&self, part: &str) -> Range<usize> {
+        let start = part.as_ptr() as usize - self.input.as_ptr() as usize;
+        start..start + part.len()
+    }
+
+    /// The lines kept by [`Lenient::lines`], joined back into an input the solvers can run on
+    pub fn valid_input(&self) -> String {
+        self.valid.join("\n")
+    }
+
+    /// Answer of `solve` over [`Lenient::valid_input`], or `0` if every line was skipped, since the
+    /// solvers expect at least one line
+    pub fn answer<E>(&self, solve: impl FnOnce(&str) -> Result<String, E>) -> Result<String, E> {
+        if self.valid.is_empty() {
+            return Ok("0".to_string());
+        }
+        solve(&self.valid_input())
+    }
+
+    /// Records that `record`, e.g. `skipped line 3`, was skipped. `violations` are byte ranges of
+    /// the whole input.
+    pub fn skip(&mut self, record: String, violations: Vec<(Range<usize>, String)>) {
+        self.skipped.push(Skipped { record, violations });
+    }
+
+    /// `answer` if nothing was skipped, otherwise a [`Partial`] report carrying it
+    pub fn finish(self, answer: String) -> Result<String, Partial> {
+        if self.skipped.is_empty() {
+            return Ok(answer);
+        }
+        Err(Partial {
+            answer,
+            input: self.input.to_string(),
+            skipped: self.skipped,
+        })
+    }
+}
+
+/// The answer over the records that could be used, with why the others were skipped as related
+/// diagnostics
+#[derive(Debug, Error)]
+#[error("skipped {} malformed record(s), the answer over the others is {answer}", .skipped.len())]
+pub struct Partial {
+    answer: String,
+    input: String,
+    skipped: Vec<Skipped>,
+}
+
+impl Partial {
+    pub fn answer(&self) -> &str {
+        &self.answer
+    }
+
+    pub fn skipped(&self) -> &[Skipped] {
+        &self.skipped
+    }
+}
+
+impl Diagnostic for Partial {
+    fn code<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        Some(Box::new("aoc::input::skipped"))
+    }
+
+    fn source_code(&self) -> Option<&dyn SourceCode> {
+        Some(&self.input)
+    }
+
+    fn related<'a>(&'a self) -> Option<Box<dyn Iterator<Item = &'a dyn Diagnostic> + 'a>> {
+        Some(Box::new(
+            self.skipped
+                .iter()
+                .map(|skipped| skipped as &dyn Diagnostic),
+        ))
+    }
+}
+
+/// One skipped record, labelled in the input of its [`Partial`]
+#[derive(Debug, Error)]
+#[error("{record}")]
+pub struct Skipped {
+    record: String,
+    violations: Vec<(Range<usize>, String)>,
+}
+
+impl Skipped {
+    pub fn violations(&self) -> &[(Range<usize>, String)] {
+        &self.violations
+    }
+}
+
+impl Diagnostic for Skipped {
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        let labels = self
+            .violations
+            .iter()
+            .map(|(span, message)| LabeledSpan::new_with_span(Some(message.clone()), span.clone()));
+        Some(Box::new(labels))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::schema::Checker;
+
+    fn check_number(line: &str) -> Result<(), InvalidInput> {
+        let mut checker = Checker::new(line, "numbers");
+        checker.number(line, "the line");
+        checker.finish()
+    }
+
+    #[test]
+    fn test_lines() {
+        let lenient = Lenient::lines("1\n2\n\nthree\n4\n", check_number);
+        assert_eq!("1\n2\n4", lenient.valid_input());
+        let partial = lenient.finish("7".to_string()).unwrap_err();
+        assert_eq!("7", partial.answer());
+        let skipped = partial
+            .skipped()
+            .iter()
+            .map(|skipped| (skipped.to_string(), skipped.violations()[0].clone()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                (
+                    "skipped line 3".to_string(),
+                    (4..4, "the line is empty".to_string())
+                ),
+                (
+                    "skipped line 4".to_string(),
+                    (5..10, "the line should be a number".to_string())
+                ),
+            ],
+            skipped
+        );
+        assert_eq!(2, partial.related().unwrap().count());
+
+        let solve = |input: &str| Ok::<_, ()>(input.lines().count().to_string());
+        let lenient = Lenient::lines("one\n\ntwo", check_number);
+        assert_eq!(Ok("0".to_string()), lenient.answer(solve));
+        assert_eq!(3, lenient.finish("0".to_string()).unwrap_err().skipped().len());
+        assert_eq!(Ok("2".to_string()), Lenient::lines("1\n2", check_number).answer(solve));
+
+        assert_eq!(
+            "3",
+            Lenient::lines("1\n2\n", check_number)
+                .finish("3".to_string())
+                .unwrap()
+        );
+    }
+}
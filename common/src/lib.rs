@@ -0,0 +1,26 @@
+//! Helpers shared by the day crates and the `aoc` runner.
+//!
+//! Without the default `std` feature only [`parse`] is built and the crate is `no_std`, for the
+//! days' allocation-free `bytes` cores.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "std")]
+pub mod cancel;
+#[cfg(feature = "std")]
+pub mod explain;
+#[cfg(feature = "std")]
+pub mod frame;
+#[cfg(feature = "std")]
+pub mod hash;
+#[cfg(feature = "std")]
+pub mod isa;
+#[cfg(feature = "std")]
+pub mod lenient;
+#[cfg(feature = "std")]
+pub mod params;
+pub mod parse;
+#[cfg(feature = "std")]
+pub mod progress;
+#[cfg(feature = "std")]
+pub mod schema;
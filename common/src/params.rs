@@ -0,0 +1,183 @@
+use miette::Diagnostic;
+use thiserror::Error;
+
+/// A named puzzle constant that can be overridden at runtime, e.g. day-14's number of spin cycles.
+///
+/// Every parameter is an unsigned integer limited to `min..=max`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParamSpec {
+    pub name: &'static str,
+    pub help: &'static str,
+    pub default: u64,
+    pub min: u64,
+    pub max: u64,
+}
+
+#[derive(Error, Diagnostic, Debug, Clone, PartialEq, Eq)]
+pub enum ParamError {
+    #[error("unknown parameter `{name}`")]
+    #[diagnostic(code(aoc::param::unknown), help("available parameters: {known}"))]
+    Unknown { name: String, known: String },
+
+    #[error("parameter `{name}` expects an unsigned integer, got `{value}`")]
+    #[diagnostic(code(aoc::param::invalid))]
+    Invalid { name: String, value: String },
+
+    #[error("parameter `{name}` must be between {min} and {max}, got {value}")]
+    #[diagnostic(code(aoc::param::out_of_range))]
+    OutOfRange {
+        name: String,
+        value: u64,
+        min: u64,
+        max: u64,
+    },
+}
+
+/// Resolved values for a set of [`ParamSpec`]s.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Params {
+    values: Vec<(&'static str, u64)>,
+}
+
+impl Params {
+    pub fn defaults(specs: &[ParamSpec]) -> Params {
+        Params {
+            values: specs.iter().map(|spec| (spec.name, spec.default)).collect(),
+        }
+    }
+
+    /// Applies `key=value` overrides on top of the defaults. Values may use `_` separators, e.g.
+    /// `1_000_000`.
+    pub fn resolve(
+        specs: &[ParamSpec],
+        overrides: &[(String, String)],
+    ) -> Result<Params, ParamError> {
+        let mut params = Params::defaults(specs);
+        for (name, value) in overrides {
+            let Some(spec) = specs.iter().find(|spec| spec.name == name) else {
+                let known = specs.iter().map(|spec| spec.name).collect::<Vec<_>>();
+                return Err(ParamError::Unknown {
+                    name: name.clone(),
+                    known: if known.is_empty() {
+                        "none".to_string()
+                    } else {
+                        known.join(", ")
+                    },
+                });
+            };
+            let parsed = value
+                .replace('_', "")
+                .parse::<u64>()
+                .map_err(|_| ParamError::Invalid {
+                    name: name.clone(),
+                    value: value.clone(),
+                })?;
+            if !(spec.min..=spec.max).contains(&parsed) {
+                return Err(ParamError::OutOfRange {
+                    name: name.clone(),
+                    value: parsed,
+                    min: spec.min,
+                    max: spec.max,
+                });
+            }
+            params.set(spec.name, parsed);
+        }
+        Ok(params)
+    }
+
+    fn set(&mut self, name: &str, value: u64) {
+        if let Some((_, current)) = self.values.iter_mut().find(|(key, _)| *key == name) {
+            *current = value;
+        }
+    }
+
+    /// Value of a declared parameter.
+    ///
+    /// # Panics
+    /// If `name` wasn't declared or its value doesn't fit in `T`. Both are bugs in the solver's
+    /// [`ParamSpec`]s, since values are range checked when they're resolved.
+    pub fn get<T: TryFrom<u64>>(&self, name: &str) -> T {
+        let value = self
+            .values
+            .iter()
+            .find(|(key, _)| *key == name)
+            .unwrap_or_else(|| panic!("parameter `{name}` is not declared"))
+            .1;
+        T::try_from(value)
+            .unwrap_or_else(|_| panic!("parameter `{name}` = {value} doesn't fit its type"))
+    }
+}
+
+/// Parses a `key=value` command line argument.
+pub fn parse_override(s: &str) -> Result<(String, String), String> {
+    match s.split_once('=') {
+        Some((key, value)) if !key.is_empty() => Ok((key.to_string(), value.to_string())),
+        _ => Err(format!("`{s}` is not a `key=value` pair")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SPECS: &[ParamSpec] = &[
+        ParamSpec {
+            name: "cycles",
+            help: "",
+            default: 1_000_000_000,
+            min: 1,
+            max: u64::MAX,
+        },
+        ParamSpec {
+            name: "limit",
+            help: "",
+            default: 12,
+            min: 0,
+            max: 255,
+        },
+    ];
+
+    fn overrides(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+        pairs
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_resolve() -> Result<(), ParamError> {
+        let params = Params::resolve(SPECS, &overrides(&[("limit", "20")]))?;
+        assert_eq!(20_u8, params.get("limit"));
+        assert_eq!(1_000_000_000_usize, params.get("cycles"));
+
+        let params = Params::resolve(SPECS, &overrides(&[("cycles", "1_000")]))?;
+        assert_eq!(1000_u64, params.get("cycles"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_resolve_errors() {
+        assert!(matches!(
+            Params::resolve(SPECS, &overrides(&[("speed", "1")])),
+            Err(ParamError::Unknown { .. })
+        ));
+        assert!(matches!(
+            Params::resolve(SPECS, &overrides(&[("limit", "-1")])),
+            Err(ParamError::Invalid { .. })
+        ));
+        assert!(matches!(
+            Params::resolve(SPECS, &overrides(&[("limit", "256")])),
+            Err(ParamError::OutOfRange { value: 256, .. })
+        ));
+    }
+
+    #[test]
+    fn test_parse_override() {
+        assert_eq!(
+            Ok(("limit".to_string(), "20".to_string())),
+            parse_override("limit=20")
+        );
+        assert!(parse_override("limit").is_err());
+        assert!(parse_override("=20").is_err());
+    }
+}
@@ -0,0 +1,271 @@
+//! Fast integer parsing for the solvers' hot loops, replacing `str::parse().unwrap()` and the
+//! digit folds some days wrote by hand.
+//!
+//! Unlike `str::parse` these take bytes, don't accept a leading `+` and only know bases 10 and 16,
+//! which keeps each digit down to a subtraction, a compare and a checked multiply-add. Overflow is
+//! still an error rather than a wrapped value. Built without `std` too, for the days' `bytes`
+//! cores.
+
+use core::fmt;
+use core::marker::PhantomData;
+
+/// Why a number couldn't be parsed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseIntError {
+    /// There were no digits, e.g. an empty token or a lone `-`
+    Empty,
+    /// The byte at this index isn't a digit of the base
+    InvalidDigit(usize),
+    /// The number doesn't fit in the integer type
+    Overflow,
+}
+
+impl fmt::Display for ParseIntError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseIntError::Empty => write!(f, "the number has no digits"),
+            ParseIntError::InvalidDigit(index) => write!(f, "invalid digit at byte {index}"),
+            ParseIntError::Overflow => write!(f, "the number doesn't fit in the integer type"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseIntError {}
+
+/// The primitive integers, which the parsers can produce
+pub trait Integer: Copy + 'static {
+    const ZERO: Self;
+    const SIGNED: bool;
+    /// Numbers with at most this many decimal digits always fit, so their digits are folded
+    /// without overflow checks
+    const SAFE_DECIMAL_DIGITS: usize;
+    /// The same for hexadecimal digits
+    const SAFE_HEX_DIGITS: usize;
+
+    /// `self * radix + digit`, or `- digit` if `negative`, and `None` on overflow. Negative
+    /// numbers are built down from zero so the type's minimum, which has no positive counterpart,
+    /// can be parsed.
+    fn push_digit(self, radix: u8, digit: u8, negative: bool) -> Option<Self>;
+
+    /// [`Integer::push_digit`] for numbers short enough not to overflow
+    fn push_digit_unchecked(self, radix: u8, digit: u8, negative: bool) -> Self;
+}
+
+macro_rules! integer {
+    ($signed:literal: $($int:ty),*) => {
+        $(
+            impl Integer for $int {
+                const ZERO: Self = 0;
+                const SIGNED: bool = $signed;
+                const SAFE_DECIMAL_DIGITS: usize = Self::MAX.ilog10() as usize;
+                const SAFE_HEX_DIGITS: usize = Self::MAX.ilog(16) as usize;
+
+                #[inline]
+                fn push_digit(self, radix: u8, digit: u8, negative: bool) -> Option<Self> {
+                    let shifted = self.checked_mul(radix as Self)?;
+                    if negative {
+                        shifted.checked_sub(digit as Self)
+                    } else {
+                        shifted.checked_add(digit as Self)
+                    }
+                }
+
+                #[inline]
+                fn push_digit_unchecked(self, radix: u8, digit: u8, negative: bool) -> Self {
+                    let shifted = self.wrapping_mul(radix as Self);
+                    if negative {
+                        shifted.wrapping_sub(digit as Self)
+                    } else {
+                        shifted.wrapping_add(digit as Self)
+                    }
+                }
+            }
+        )*
+    };
+}
+
+integer!(false: u8, u16, u32, u64, u128, usize);
+integer!(true: i8, i16, i32, i64, i128, isize);
+
+/// Parses a decimal number like `42`, or `-42` for signed types
+#[inline]
+pub fn decimal<T: Integer>(text: impl AsRef<[u8]>) -> Result<T, ParseIntError> {
+    let text = text.as_ref();
+    match text.split_first() {
+        Some((b'-', digits)) if T::SIGNED => fold(digits, 1, true, Radix::<T>::DECIMAL),
+        _ => fold(text, 0, false, Radix::<T>::DECIMAL),
+    }
+}
+
+/// Parses an unsigned hexadecimal number like `70c71` or `70C71`, without a `0x` prefix
+#[inline]
+pub fn hex<T: Integer>(text: impl AsRef<[u8]>) -> Result<T, ParseIntError> {
+    fold(text.as_ref(), 0, false, Radix::<T>::HEX)
+}
+
+/// Decimal numbers separated by any mix of ASCII whitespace and commas, e.g. `1,2 3\n4`
+pub fn numbers<T: Integer>(
+    text: &(impl AsRef<[u8]> + ?Sized),
+) -> impl Iterator<Item = Result<T, ParseIntError>> + Clone + '_ {
+    text.as_ref()
+        .split(|&byte| byte.is_ascii_whitespace() || byte == b',')
+        .filter(|number| !number.is_empty())
+        .map(decimal)
+}
+
+/// A base and how to read its digits
+struct Radix<T> {
+    radix: u8,
+    safe_digits: usize,
+    digit: fn(u8) -> Option<u8>,
+    integer: PhantomData<T>,
+}
+
+impl<T: Integer> Radix<T> {
+    const DECIMAL: Radix<T> = Radix {
+        radix: 10,
+        safe_digits: T::SAFE_DECIMAL_DIGITS,
+        digit: decimal_digit,
+        integer: PhantomData,
+    };
+    const HEX: Radix<T> = Radix {
+        radix: 16,
+        safe_digits: T::SAFE_HEX_DIGITS,
+        digit: hex_digit,
+        integer: PhantomData,
+    };
+}
+
+/// `offset` is the index of the first digit in the text, for errors
+#[inline]
+fn fold<T: Integer>(
+    digits: &[u8],
+    offset: usize,
+    negative: bool,
+    radix: Radix<T>,
+) -> Result<T, ParseIntError> {
+    if digits.is_empty() {
+        return Err(ParseIntError::Empty);
+    }
+    let checked = digits.len() > radix.safe_digits;
+    let mut value = T::ZERO;
+    for (index, &byte) in digits.iter().enumerate() {
+        let digit = (radix.digit)(byte).ok_or(ParseIntError::InvalidDigit(offset + index))?;
+        value = if checked {
+            value
+                .push_digit(radix.radix, digit, negative)
+                .ok_or(ParseIntError::Overflow)?
+        } else {
+            value.push_digit_unchecked(radix.radix, digit, negative)
+        };
+    }
+    Ok(value)
+}
+
+#[inline]
+fn decimal_digit(byte: u8) -> Option<u8> {
+    let digit = byte.wrapping_sub(b'0');
+    (digit < 10).then_some(digit)
+}
+
+#[inline]
+fn hex_digit(byte: u8) -> Option<u8> {
+    match byte {
+        b'0'..=b'9' => Some(byte - b'0'),
+        b'a'..=b'f' => Some(byte - b'a' + 10),
+        b'A'..=b'F' => Some(byte - b'A' + 10),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("0", Ok(0))]
+    #[case("255", Ok(255))]
+    #[case("007", Ok(7))]
+    #[case("256", Err(ParseIntError::Overflow))]
+    #[case("", Err(ParseIntError::Empty))]
+    #[case("-1", Err(ParseIntError::InvalidDigit(0)))]
+    #[case("+1", Err(ParseIntError::InvalidDigit(0)))]
+    #[case("12a", Err(ParseIntError::InvalidDigit(2)))]
+    fn test_decimal_unsigned(#[case] text: &str, #[case] expected: Result<u8, ParseIntError>) {
+        assert_eq!(expected, decimal::<u8>(text));
+    }
+
+    #[rstest]
+    #[case("127", Ok(127))]
+    #[case("-128", Ok(-128))]
+    #[case("-0", Ok(0))]
+    #[case("128", Err(ParseIntError::Overflow))]
+    #[case("-129", Err(ParseIntError::Overflow))]
+    #[case("-", Err(ParseIntError::Empty))]
+    #[case("--1", Err(ParseIntError::InvalidDigit(1)))]
+    #[case("1 ", Err(ParseIntError::InvalidDigit(1)))]
+    fn test_decimal_signed(#[case] text: &str, #[case] expected: Result<i8, ParseIntError>) {
+        assert_eq!(expected, decimal::<i8>(text));
+    }
+
+    #[test]
+    fn test_decimal_matches_std() {
+        for text in [
+            "0",
+            "9",
+            "-7",
+            "2147483647",
+            "-2147483648",
+            "2147483648",
+            "x",
+            "",
+            "-",
+        ] {
+            assert_eq!(
+                text.parse::<i32>().ok(),
+                decimal::<i32>(text).ok(),
+                "{text:?}"
+            );
+        }
+        assert_eq!(Ok(u64::MAX), decimal(u64::MAX.to_string()));
+        assert_eq!(Ok(i128::MIN), decimal(i128::MIN.to_string()));
+        assert_eq!(
+            (2, 18, 19),
+            (
+                i8::SAFE_DECIMAL_DIGITS,
+                i64::SAFE_DECIMAL_DIGITS,
+                u64::SAFE_DECIMAL_DIGITS
+            )
+        );
+        assert_eq!((3, 15), (u16::SAFE_HEX_DIGITS, i64::SAFE_HEX_DIGITS));
+    }
+
+    #[test]
+    fn test_hex() {
+        assert_eq!(Ok(461_937), hex::<i64>("70c71"));
+        assert_eq!(Ok(461_937), hex::<u32>(b"70C71"));
+        assert_eq!(Ok(u16::MAX), hex::<u16>("ffff"));
+        assert_eq!(Err(ParseIntError::Overflow), hex::<u16>("10000"));
+        assert_eq!(Err(ParseIntError::InvalidDigit(0)), hex::<u32>("-1"));
+        assert_eq!(Err(ParseIntError::InvalidDigit(1)), hex::<u32>("0x1"));
+        assert_eq!(Err(ParseIntError::Empty), hex::<u32>(""));
+    }
+
+    #[test]
+    fn test_numbers() {
+        let parsed = numbers::<i64>(" 79 14,-55\n\n13,,0 ").collect::<Result<Vec<_>, _>>();
+        assert_eq!(Ok(vec![79, 14, -55, 13, 0]), parsed);
+        assert_eq!(0, numbers::<u32>(" , \n").count());
+        assert_eq!(
+            vec![
+                Ok(1),
+                Err(ParseIntError::Overflow),
+                Err(ParseIntError::InvalidDigit(0))
+            ],
+            numbers::<u8>(b"1 300 x").collect::<Vec<_>>()
+        );
+    }
+}
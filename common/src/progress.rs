@@ -0,0 +1,18 @@
+//! Progress reporting for the solvers that run long enough to need it, e.g. day-05 part 2 mapping
+//! every seed one at a time.
+//!
+//! Solvers report through [`CancelToken::progress`](crate::cancel::CancelToken::progress) where
+//! their hot loops already poll for cancellation, so reporting adds nothing to the loops
+//! themselves, and only a branch at each poll when no [`Progress`] is attached to the token.
+
+/// The `total` reported by solvers that can't tell how many items are left, e.g. day-14 spinning
+/// the platform until it repeats itself
+pub const UNKNOWN_TOTAL: u64 = 0;
+
+/// Told how far a solver got, e.g. to draw a progress bar
+pub trait Progress: Send + Sync {
+    /// `done` of `total` items, e.g. seeds or spin cycles, are finished, with `total` being
+    /// [`UNKNOWN_TOTAL`] if the solver can't tell. Called every few thousand items, so
+    /// implementations should throttle anything slow themselves.
+    fn update(&self, done: u64, total: u64);
+}
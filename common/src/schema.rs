@@ -0,0 +1,282 @@
+//! The shape each day's solvers assume their input has, e.g. day-18's fixed-width lines, checked
+//! by `aoc check` and before `aoc run` so a malformed input is reported instead of panicking or
+//! giving a wrong answer.
+
+use std::fmt;
+use std::ops::Range;
+
+use miette::{Diagnostic, LabeledSpan, SourceCode};
+use thiserror::Error;
+
+/// Labels shown in the report, the rest are only counted
+const MAX_LABELS: usize = 10;
+
+/// Collects the places an input breaks its shape, as byte ranges of the input.
+pub struct Checker<'a> {
+    input: &'a str,
+    shape: &'static str,
+    violations: Vec<(Range<usize>, String)>,
+}
+
+impl<'a> Checker<'a> {
+    /// `shape` describes the expected input in a sentence, shown as the report's help
+    pub fn new(input: &'a str, shape: &'static str) -> Checker<'a> {
+        Checker {
+            input,
+            shape,
+            violations: vec![],
+        }
+    }
+
+    pub fn input(&self) -> &'a str {
+        self.input
+    }
+
+    /// Lines of the input, recording a violation if there are none
+    pub fn lines(&mut self) -> std::str::Lines<'a> {
+        let input = self.input;
+        self.expect(!input.trim().is_empty(), input, "the input is empty");
+        input.lines()
+    }
+
+    /// Byte range of `part`, which must be a slice of the input
+    pub fn span(&self, part: &str) -> Range<usize> {
+        let start = part.as_ptr() as usize - self.input.as_ptr() as usize;
+        assert!(
+            start + part.len() <= self.input.len(),
+            "`part` should be a slice of the input"
+        );
+        start..start + part.len()
+    }
+
+    /// Records a violation at `part` unless `ok`, and returns `ok` to allow skipping checks that
+    /// depend on it
+    pub fn expect(&mut self, ok: bool, part: &str, message: impl fmt::Display) -> bool {
+        if !ok {
+            self.violations.push((self.span(part), message.to_string()));
+        }
+        ok
+    }
+
+    /// Checks that `text` is an unsigned integer, returning it
+    pub fn number(&mut self, text: &str, what: &str) -> Option<u64> {
+        let number = text.parse::<u64>().ok().filter(|_| !text.starts_with('+'));
+        self.expect(number.is_some(), text, format!("{what} should be a number"));
+        number
+    }
+
+    /// Checks that `text` is an unsigned integer no larger than `max`, e.g. the largest value the
+    /// solvers' integer type holds, returning it
+    pub fn bounded(&mut self, text: &str, what: &str, max: u64) -> Option<u64> {
+        let number = self.number(text, what)?;
+        let fits = self.expect(
+            number <= max,
+            text,
+            format!("{what} should be at most {max}"),
+        );
+        fits.then_some(number)
+    }
+
+    /// Checks that `text` is an integer, possibly negative, returning it
+    pub fn signed(&mut self, text: &str, what: &str) -> Option<i64> {
+        let number = text.parse::<i64>().ok().filter(|_| !text.starts_with('+'));
+        self.expect(number.is_some(), text, format!("{what} should be a number"));
+        number
+    }
+
+    /// Checks that `text` is `prefix` followed by something, returning the rest
+    pub fn prefixed(&mut self, text: &'a str, prefix: &str) -> Option<&'a str> {
+        let rest = text.strip_prefix(prefix);
+        // As much of `text` as the prefix would cover, without splitting a character
+        let mut end = prefix.len().min(text.len());
+        while !text.is_char_boundary(end) {
+            end -= 1;
+        }
+        let start = &text[..end];
+        self.expect(
+            rest.is_some(),
+            start,
+            format!("should start with `{prefix}`"),
+        );
+        rest
+    }
+
+    /// Checks that `text` contains `separator`, returning the parts before and after the first one
+    pub fn split(&mut self, text: &'a str, separator: &str) -> Option<(&'a str, &'a str)> {
+        let parts = text.split_once(separator);
+        self.expect(
+            parts.is_some(),
+            text,
+            format!("should contain `{separator}`"),
+        );
+        parts
+    }
+
+    /// Checks that `text` is a rectangular grid of at least two rows whose cells are all in
+    /// `cells`. The solvers find the width at the first line break, so a single row isn't enough,
+    /// and step between rows by its length, so every row should end like the first.
+    pub fn grid(&mut self, text: &'a str, cells: &str) {
+        let Some(first) = text.lines().next().filter(|row| !row.is_empty()) else {
+            self.expect(false, text, "the grid should have at least two rows");
+            return;
+        };
+        self.expect(
+            text.lines().nth(1).is_some(),
+            text,
+            "the grid should have at least two rows",
+        );
+        let crlf = text.starts_with(&format!("{first}\r\n"));
+        for row in text.split_inclusive('\n') {
+            self.expect(
+                !row.ends_with('\n') || row.ends_with("\r\n") == crlf,
+                row,
+                "every row should end in the same line break as the first",
+            );
+        }
+        let width = first.len();
+        for row in text.lines() {
+            if !self.expect(
+                row.len() == width,
+                row,
+                format!("every row should be {width} cells wide like the first"),
+            ) {
+                continue;
+            }
+            let message = format!("cells should be one of `{cells}`");
+            self.chars(row, |cell| cells.contains(cell), &message);
+        }
+    }
+
+    /// Records a violation at every character of `text` that isn't `allowed`
+    pub fn chars(&mut self, text: &str, allowed: impl Fn(char) -> bool, message: &str) {
+        for (i, c) in text.char_indices() {
+            if !allowed(c) {
+                self.expect(false, &text[i..i + c.len_utf8()], message);
+            }
+        }
+    }
+
+    pub fn finish(self) -> Result<(), InvalidInput> {
+        if self.violations.is_empty() {
+            return Ok(());
+        }
+        Err(InvalidInput {
+            input: self.input.to_string(),
+            shape: self.shape,
+            violations: self.violations,
+        })
+    }
+}
+
+/// The places an input breaks its shape, rendered as labels on the input
+#[derive(Debug, Error)]
+#[error("the input doesn't have the expected shape, {} problem(s) found", .violations.len())]
+pub struct InvalidInput {
+    input: String,
+    shape: &'static str,
+    violations: Vec<(Range<usize>, String)>,
+}
+
+impl InvalidInput {
+    /// Byte range and message of every violation, in input order
+    pub fn violations(&self) -> &[(Range<usize>, String)] {
+        &self.violations
+    }
+}
+
+impl Diagnostic for InvalidInput {
+    fn code<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        Some(Box::new("aoc::input::shape"))
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        let mut help = format!("expected {}", self.shape);
+        if self.violations.len() > MAX_LABELS {
+            let more = self.violations.len() - MAX_LABELS;
+            help = format!("{help}\nonly the first {MAX_LABELS} problems are shown, {more} more");
+        }
+        Some(Box::new(help))
+    }
+
+    fn source_code(&self) -> Option<&dyn SourceCode> {
+        Some(&self.input)
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        let labels = self
+            .violations
+            .iter()
+            .take(MAX_LABELS)
+            .map(|(span, message)| LabeledSpan::new_with_span(Some(message.clone()), span.clone()));
+        Some(Box::new(labels))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checker() {
+        let input = "#.#\n.x\n12 -3";
+        let mut checker = Checker::new(input, "a grid");
+        let (grid, numbers) = input.rsplit_once('\n').unwrap();
+        checker.grid(grid, ".#");
+        let (a, b) = numbers.split_once(' ').unwrap();
+        assert_eq!(Some(12), checker.number(a, "a"));
+        assert_eq!(None, checker.number(b, "b"));
+        assert_eq!(Some(-3), checker.signed(b, "b"));
+
+        let invalid = checker.finish().unwrap_err();
+        assert_eq!(
+            [
+                (
+                    4..6,
+                    "every row should be 3 cells wide like the first".to_string()
+                ),
+                (10..12, "b should be a number".to_string()),
+            ],
+            invalid.violations()
+        );
+        assert_eq!(2, invalid.labels().unwrap().count());
+        assert_eq!("expected a grid", invalid.help().unwrap().to_string());
+
+        assert!(Checker::new("..\n..", "a grid").finish().is_ok());
+        let mut checker = Checker::new("O.#.O\n", "a grid");
+        checker.grid(checker.input(), "O#.");
+        assert_eq!(
+            [(0..6, "the grid should have at least two rows".to_string())],
+            checker.finish().unwrap_err().violations()
+        );
+        let mut checker = Checker::new("O.\r\n#.\r\n.O", "a grid");
+        checker.grid(checker.input(), "O#.");
+        assert!(checker.finish().is_ok());
+        let mut checker = Checker::new("O.\n#.\r\n.O\n", "a grid");
+        checker.grid(checker.input(), "O#.");
+        assert_eq!(
+            [(
+                3..7,
+                "every row should end in the same line break as the first".to_string()
+            )],
+            checker.finish().unwrap_err().violations()
+        );
+        let mut checker = Checker::new("\n", "a grid");
+        assert_eq!(1, checker.lines().count());
+        assert_eq!(None, checker.split(checker.input(), ": "));
+        assert_eq!(2, checker.finish().unwrap_err().violations().len());
+
+        let mut checker = Checker::new("300 20", "numbers");
+        let (a, b) = checker.input().split_once(' ').unwrap();
+        assert_eq!(None, checker.bounded(a, "a", 255));
+        assert_eq!(Some(20), checker.bounded(b, "b", 255));
+        assert_eq!(
+            [(0..3, "a should be at most 255".to_string())],
+            checker.finish().unwrap_err().violations()
+        );
+
+        // Byte 7 is inside the second `Î`
+        let mut checker = Checker::new("seedÎÎ", "seeds");
+        assert_eq!(None, checker.prefixed(checker.input(), "seeds: "));
+        assert_eq!((0..6), checker.finish().unwrap_err().violations()[0].0);
+    }
+}
@@ -1,4 +1,4 @@
-use {{crate_name}}::*;
+use aoc{{year}}_{{crate_name}}::*;
 
 fn main() {
     // Run registered benchmarks.
@@ -1,4 +1,4 @@
-use {{crate_name}}::part1::*;
+use aoc{{year}}_{{crate_name}}::part1::*;
 use miette::Context;
 
 #[cfg(feature = "dhat-heap")]
@@ -1,4 +1,4 @@
-use {{crate_name}}::part2::*;
+use aoc{{year}}_{{crate_name}}::part2::*;
 use miette::Context;
 
 #[cfg(feature = "dhat-heap")]
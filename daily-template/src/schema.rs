@@ -0,0 +1,13 @@
+//! Shape of the input, see `aoc check`
+
+use aoc_common::schema::{Checker, InvalidInput};
+
+pub const SHAPE: &str = "lines of text";
+
+pub fn check(input: &str) -> Result<(), InvalidInput> {
+    let mut checker = Checker::new(input, SHAPE);
+    for line in checker.lines() {
+        checker.expect(!line.is_empty(), line, "lines shouldn't be empty");
+    }
+    checker.finish()
+}
@@ -1,4 +0,0 @@
-pub mod custom_error;
-
-pub mod part1;
-pub mod part2;
@@ -1,5 +0,0 @@
-pub mod custom_error;
-
-pub mod part1;
-pub mod part2;
-pub mod part2_dfs;
\ No newline at end of file
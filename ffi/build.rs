@@ -0,0 +1,19 @@
+//! Regenerates `include/aoc.h` from the `extern "C"` items in `src/lib.rs`.
+
+use std::path::PathBuf;
+
+fn main() {
+    let crate_dir = PathBuf::from(std::env::var("CARGO_MANIFEST_DIR").unwrap());
+    println!("cargo:rerun-if-changed=src");
+    println!("cargo:rerun-if-changed=cbindgen.toml");
+
+    let config = cbindgen::Config::from_file(crate_dir.join("cbindgen.toml")).unwrap();
+    // Parsing just the one file avoids running `cargo metadata` from the build script
+    cbindgen::Builder::new()
+        .with_config(config)
+        .with_src(crate_dir.join("src/lib.rs"))
+        .generate()
+        .expect("src/lib.rs should be parseable by cbindgen")
+        // Only rewrites the header when its contents change
+        .write_to_file(crate_dir.join("include/aoc.h"));
+}
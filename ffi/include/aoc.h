@@ -0,0 +1,79 @@
+#ifndef AOC_H
+#define AOC_H
+
+/* Generated by cbindgen from ffi/src/lib.rs, do not edit. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * Result of [`aoc_solve`]. Solver errors are told apart by the diagnostic code of the day's
+ * `AocError`.
+ */
+typedef enum AocStatus {
+  /**
+   * The answer was written to the output buffer
+   */
+  AOC_STATUS_OK = 0,
+  /**
+   * No solver is registered for the day and part
+   */
+  AOC_STATUS_UNKNOWN_SOLVER = 1,
+  /**
+   * `out_buf` or `out_len` is null, or `input_ptr` is null with a non-zero `input_len`
+   */
+  AOC_STATUS_NULL_POINTER = 2,
+  /**
+   * The input isn't valid UTF-8
+   */
+  AOC_STATUS_INVALID_UTF8 = 3,
+  /**
+   * The answer and its NUL terminator don't fit in the output buffer. `*out_len` is set to the
+   * length of the answer.
+   */
+  AOC_STATUS_BUFFER_TOO_SMALL = 4,
+  /**
+   * `AocError::IoError`
+   */
+  AOC_STATUS_IO = 5,
+  /**
+   * `AocError::Cancelled`
+   */
+  AOC_STATUS_CANCELLED = 6,
+  /**
+   * Any other error returned by the solver
+   */
+  AOC_STATUS_SOLVER_ERROR = 7,
+  /**
+   * The solver panicked, which is a bug: malformed input is a `SolverError`
+   */
+  AOC_STATUS_PANICKED = 8,
+} AocStatus;
+
+/**
+ * Solves `part` of `day` of the latest year with its default variant.
+ *
+ * On entry `*out_len` is the capacity of `out_buf`. On success the answer is written to `out_buf`
+ * followed by a NUL terminator, and `*out_len` is set to the answer's length without it.
+ *
+ * # Safety
+ * `input_ptr` must point to `input_len` readable bytes (or be null if `input_len` is 0), `out_len`
+ * must point to a writable `size_t` and `out_buf` to `*out_len` writable bytes.
+ */
+enum AocStatus aoc_solve(uint32_t day,
+                         uint32_t part,
+                         const uint8_t *input_ptr,
+                         size_t input_len,
+                         char *out_buf,
+                         size_t *out_len);
+
+/**
+ * Static, NUL terminated description of `status`, one of the [`AocStatus`] values. Any other
+ * value gets "unknown status".
+ */
+const char *aoc_status_message(uint32_t status);
+
+#endif /* AOC_H */
@@ -0,0 +1,216 @@
+//! C ABI over the solver registry, for harnesses that can't link Rust code directly.
+//!
+//! `build.rs` generates the matching header into `include/aoc.h`.
+
+use std::ffi::c_char;
+
+use aoc_common::params::Params;
+use aoc_runner::execute::{self, Outcome};
+use aoc_runner::registry;
+
+/// Result of [`aoc_solve`]. Solver errors are told apart by the diagnostic code of the day's
+/// `AocError`.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AocStatus {
+    /// The answer was written to the output buffer
+    Ok = 0,
+    /// No solver is registered for the day and part
+    UnknownSolver = 1,
+    /// `out_buf` or `out_len` is null, or `input_ptr` is null with a non-zero `input_len`
+    NullPointer = 2,
+    /// The input isn't valid UTF-8
+    InvalidUtf8 = 3,
+    /// The answer and its NUL terminator don't fit in the output buffer. `*out_len` is set to the
+    /// length of the answer.
+    BufferTooSmall = 4,
+    /// `AocError::IoError`
+    Io = 5,
+    /// `AocError::Cancelled`
+    Cancelled = 6,
+    /// Any other error returned by the solver
+    SolverError = 7,
+    /// The solver panicked, which is a bug: malformed input is a `SolverError`
+    Panicked = 8,
+}
+
+impl AocStatus {
+    const ALL: [AocStatus; 9] = [
+        AocStatus::Ok,
+        AocStatus::UnknownSolver,
+        AocStatus::NullPointer,
+        AocStatus::InvalidUtf8,
+        AocStatus::BufferTooSmall,
+        AocStatus::Io,
+        AocStatus::Cancelled,
+        AocStatus::SolverError,
+        AocStatus::Panicked,
+    ];
+
+    fn message(self) -> &'static [u8] {
+        match self {
+            AocStatus::Ok => b"ok\0",
+            AocStatus::UnknownSolver => b"no solver for this day and part\0",
+            AocStatus::NullPointer => b"required pointer is null\0",
+            AocStatus::InvalidUtf8 => b"input is not valid UTF-8\0",
+            AocStatus::BufferTooSmall => b"output buffer is too small\0",
+            AocStatus::Io => b"solver hit an I/O error\0",
+            AocStatus::Cancelled => b"solver was cancelled\0",
+            AocStatus::SolverError => b"solver returned an error\0",
+            AocStatus::Panicked => b"solver panicked\0",
+        }
+    }
+
+    fn from_report(report: &miette::Report) -> AocStatus {
+        match report.code().map(|code| code.to_string()).as_deref() {
+            Some("aoc::io_error") => AocStatus::Io,
+            Some("aoc::cancelled") => AocStatus::Cancelled,
+            _ => AocStatus::SolverError,
+        }
+    }
+}
+
+/// Solves `part` of `day` of the latest year with its default variant.
+///
+/// On entry `*out_len` is the capacity of `out_buf`. On success the answer is written to `out_buf`
+/// followed by a NUL terminator, and `*out_len` is set to the answer's length without it.
+///
+/// # Safety
+/// `input_ptr` must point to `input_len` readable bytes (or be null if `input_len` is 0), `out_len`
+/// must point to a writable `size_t` and `out_buf` to `*out_len` writable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_solve(
+    day: u32,
+    part: u32,
+    input_ptr: *const u8,
+    input_len: usize,
+    out_buf: *mut c_char,
+    out_len: *mut usize,
+) -> AocStatus {
+    if out_buf.is_null() || out_len.is_null() || (input_ptr.is_null() && input_len > 0) {
+        return AocStatus::NullPointer;
+    }
+    let input = if input_len == 0 {
+        &[]
+    } else {
+        // SAFETY: checked for null above, the caller guarantees the length
+        unsafe { std::slice::from_raw_parts(input_ptr, input_len) }
+    };
+    let Ok(input) = std::str::from_utf8(input) else {
+        return AocStatus::InvalidUtf8;
+    };
+
+    let (Ok(day), Ok(part)) = (u8::try_from(day), u8::try_from(part)) else {
+        return AocStatus::UnknownSolver;
+    };
+    let Some(solver) = registry::default_variant(registry::DEFAULT_YEAR, day, part) else {
+        return AocStatus::UnknownSolver;
+    };
+
+    let params = Params::defaults(solver.params);
+    let answer = match execute::run(solver, input.into(), &params, None) {
+        Outcome::Solved { answer, .. } => answer,
+        Outcome::Failed(report) => return AocStatus::from_report(&report),
+        Outcome::Panicked(_) => return AocStatus::Panicked,
+        Outcome::TimedOut(_) => return AocStatus::Cancelled,
+    };
+
+    // SAFETY: the caller guarantees both pointers are valid
+    unsafe {
+        let capacity = *out_len;
+        *out_len = answer.len();
+        if answer.len() >= capacity {
+            return AocStatus::BufferTooSmall;
+        }
+        std::ptr::copy_nonoverlapping(answer.as_ptr(), out_buf.cast::<u8>(), answer.len());
+        *out_buf.add(answer.len()) = 0;
+    }
+    AocStatus::Ok
+}
+
+/// Static, NUL terminated description of `status`, one of the [`AocStatus`] values. Any other
+/// value gets "unknown status".
+#[no_mangle]
+pub extern "C" fn aoc_status_message(status: u32) -> *const c_char {
+    let message = AocStatus::ALL
+        .into_iter()
+        .find(|known| *known as u32 == status)
+        .map_or(b"unknown status\0".as_slice(), AocStatus::message);
+    message.as_ptr().cast()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::ffi::CStr;
+
+    fn solve(day: u32, part: u32, input: &str, capacity: usize) -> (AocStatus, String, usize) {
+        let mut out = vec![0 as c_char; capacity];
+        let mut out_len = capacity;
+        let status = unsafe {
+            aoc_solve(
+                day,
+                part,
+                input.as_ptr(),
+                input.len(),
+                out.as_mut_ptr(),
+                &mut out_len,
+            )
+        };
+        let answer = if status == AocStatus::Ok {
+            unsafe { CStr::from_ptr(out.as_ptr()) }
+                .to_str()
+                .unwrap()
+                .to_string()
+        } else {
+            String::new()
+        };
+        (status, answer, out_len)
+    }
+
+    #[test]
+    fn test_solve() {
+        let input = "1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet";
+        assert_eq!(
+            (AocStatus::Ok, "142".to_string(), 3),
+            solve(1, 1, input, 16)
+        );
+        // Room for the answer but not the terminator
+        assert_eq!(
+            (AocStatus::BufferTooSmall, String::new(), 3),
+            solve(1, 1, input, 3)
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(AocStatus::UnknownSolver, solve(26, 1, "", 16).0);
+        assert_eq!(AocStatus::UnknownSolver, solve(256 + 1, 1, "", 16).0);
+        assert_eq!(AocStatus::SolverError, solve(1, 1, "no digits", 16).0);
+
+        let (mut out, mut out_len) = ([0; 16], 16);
+        let status =
+            unsafe { aoc_solve(1, 1, std::ptr::null(), 4, out.as_mut_ptr(), &mut out_len) };
+        assert_eq!(AocStatus::NullPointer, status);
+    }
+
+    #[test]
+    fn test_status_message() {
+        let message = |status| unsafe { CStr::from_ptr(aoc_status_message(status)) };
+        assert_eq!(c"ok", message(AocStatus::Ok as u32));
+        assert_eq!(c"solver panicked", message(AocStatus::Panicked as u32));
+        assert_eq!(c"unknown status", message(9));
+        assert_eq!(c"unknown status", message(u32::MAX));
+    }
+
+    #[test]
+    fn test_status_from_report() {
+        let cancelled = miette::Report::new(aoc_common::cancel::Cancelled);
+        assert_eq!(AocStatus::Cancelled, AocStatus::from_report(&cancelled));
+        assert_eq!(
+            AocStatus::SolverError,
+            AocStatus::from_report(&miette::miette!("bad input"))
+        );
+    }
+}
@@ -0,0 +1,270 @@
+/* Calls every day through the C API. Built and run by tests/c_api.rs. */
+
+#include <stdio.h>
+#include <string.h>
+
+#include "aoc.h"
+
+struct example {
+    uint32_t day;
+    const char *expected;
+    const char *input;
+};
+
+/* The part 1 examples from each day's puzzle text */
+static const struct example EXAMPLES[] = {
+    {1, "142",
+        "1abc2\n"
+        "pqr3stu8vwx\n"
+        "a1b2c3d4e5f\n"
+        "treb7uchet"},
+    {2, "8",
+        "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green\n"
+        "Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue\n"
+        "Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red\n"
+        "Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red\n"
+        "Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green"},
+    {3, "4361",
+        "467..114..\n"
+        "...*......\n"
+        "..35..633.\n"
+        "......#...\n"
+        "617*......\n"
+        ".....+.58.\n"
+        "..592.....\n"
+        "......755.\n"
+        "...$.*....\n"
+        ".664.598.."},
+    {4, "13",
+        "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53\n"
+        "Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19\n"
+        "Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1\n"
+        "Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83\n"
+        "Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36\n"
+        "Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11"},
+    {5, "35",
+        "seeds: 79 14 55 13\n"
+        "\n"
+        "seed-to-soil map:\n"
+        "50 98 2\n"
+        "52 50 48\n"
+        "\n"
+        "soil-to-fertilizer map:\n"
+        "0 15 37\n"
+        "37 52 2\n"
+        "39 0 15\n"
+        "\n"
+        "fertilizer-to-water map:\n"
+        "49 53 8\n"
+        "0 11 42\n"
+        "42 0 7\n"
+        "57 7 4\n"
+        "\n"
+        "water-to-light map:\n"
+        "88 18 7\n"
+        "18 25 70\n"
+        "\n"
+        "light-to-temperature map:\n"
+        "45 77 23\n"
+        "81 45 19\n"
+        "68 64 13\n"
+        "\n"
+        "temperature-to-humidity map:\n"
+        "0 69 1\n"
+        "1 0 69\n"
+        "\n"
+        "humidity-to-location map:\n"
+        "60 56 37\n"
+        "56 93 4"},
+    {6, "288",
+        "Time:      7  15   30\n"
+        "Distance:  9  40  200"},
+    {7, "6440",
+        "32T3K 765\n"
+        "T55J5 684\n"
+        "KK677 28\n"
+        "KTJJT 220\n"
+        "QQQJA 483"},
+    {8, "2",
+        "RL\n"
+        "\n"
+        "AAA = (BBB, CCC)\n"
+        "BBB = (DDD, EEE)\n"
+        "CCC = (ZZZ, GGG)\n"
+        "DDD = (DDD, DDD)\n"
+        "EEE = (EEE, EEE)\n"
+        "GGG = (GGG, GGG)\n"
+        "ZZZ = (ZZZ, ZZZ)"},
+    {9, "114",
+        "0 3 6 9 12 15\n"
+        "1 3 6 10 15 21\n"
+        "10 13 16 21 30 45"},
+    {10, "4",
+        "-L|F7\n"
+        "7S-7|\n"
+        "L|7||\n"
+        "-L-J|\n"
+        "L|-JF"},
+    {11, "374",
+        "...#......\n"
+        ".......#..\n"
+        "#.........\n"
+        "..........\n"
+        "......#...\n"
+        ".#........\n"
+        ".........#\n"
+        "..........\n"
+        ".......#..\n"
+        "#...#....."},
+    {12, "1",
+        "???.### 1,1,3"},
+    {13, "405",
+        "#.##..##.\r\n"
+        "..#.##.#.\r\n"
+        "##......#\r\n"
+        "##......#\r\n"
+        "..#.##.#.\r\n"
+        "..##..##.\r\n"
+        "#.#.##.#.\r\n"
+        "\r\n"
+        "#...##..#\r\n"
+        "#....#..#\r\n"
+        "..##..###\r\n"
+        "#####.##.\r\n"
+        "#####.##.\r\n"
+        "..##..###\r\n"
+        "#....#..#"},
+    {14, "136",
+        "O....#....\n"
+        "O.OO#....#\n"
+        ".....##...\n"
+        "OO.#O....O\n"
+        ".O.....O#.\n"
+        "O.#..O.#.#\n"
+        "..O..#O..O\n"
+        ".......O..\n"
+        "#....###..\n"
+        "#OO..#...."},
+    {15, "1320",
+        "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7"},
+    {16, "46",
+        ".|...\\....\n"
+        "|.-.\\.....\n"
+        ".....|-...\n"
+        "........|.\n"
+        "..........\n"
+        ".........\\\n"
+        "..../.\\\\..\n"
+        ".-.-/..|..\n"
+        ".|....-|.\\\n"
+        "..//.|...."},
+    {17, "102",
+        "2413432311323\n"
+        "3215453535623\n"
+        "3255245654254\n"
+        "3446585845452\n"
+        "4546657867536\n"
+        "1438598798454\n"
+        "4457876987766\n"
+        "3637877979653\n"
+        "4654967986887\n"
+        "4564679986453\n"
+        "1224686865563\n"
+        "2546548887735\n"
+        "4322674655533"},
+    {18, "62",
+        "R 6 (#70c710)\n"
+        "D 5 (#0dc571)\n"
+        "L 2 (#5713f0)\n"
+        "D 2 (#d2c081)\n"
+        "R 2 (#59c680)\n"
+        "D 2 (#411b91)\n"
+        "L 5 (#8ceee2)\n"
+        "U 2 (#caa173)\n"
+        "L 1 (#1b58a2)\n"
+        "U 2 (#caa171)\n"
+        "R 2 (#7807d2)\n"
+        "U 3 (#a77fa3)\n"
+        "L 2 (#015232)\n"
+        "U 2 (#7a21e3)"},
+    {19, "19114",
+        "px{a<2006:qkq,m>2090:A,rfg}\n"
+        "pv{a>1716:R,A}\n"
+        "lnx{m>1548:A,A}\n"
+        "rfg{s<537:gd,x>2440:R,A}\n"
+        "qs{s>3448:A,lnx}\n"
+        "qkq{x<1416:A,crn}\n"
+        "crn{x>2662:A,R}\n"
+        "in{s<1351:px,qqz}\n"
+        "qqz{s>2770:qs,m<1801:hdj,R}\n"
+        "gd{a>3333:R,R}\n"
+        "hdj{m>838:A,pv}\n"
+        "\n"
+        "{x=787,m=2655,a=1222,s=2876}\n"
+        "{x=1679,m=44,a=2067,s=496}\n"
+        "{x=2036,m=264,a=79,s=2244}\n"
+        "{x=2461,m=1339,a=466,s=291}\n"
+        "{x=2127,m=1623,a=2188,s=1013}"},
+};
+
+static int failures = 0;
+
+static void expect_status(const char *what, AocStatus actual, AocStatus expected) {
+    if (actual != expected) {
+        fprintf(stderr, "%s: expected \"%s\", got \"%s\"\n", what, aoc_status_message(expected),
+                aoc_status_message(actual));
+        failures++;
+    }
+}
+
+int main(void) {
+    char answer[64];
+    size_t answer_len;
+
+    for (size_t i = 0; i < sizeof(EXAMPLES) / sizeof(EXAMPLES[0]); i++) {
+        const struct example *example = &EXAMPLES[i];
+        answer_len = sizeof(answer);
+        AocStatus status = aoc_solve(example->day, 1, (const uint8_t *)example->input,
+                                     strlen(example->input), answer, &answer_len);
+        char what[32];
+        snprintf(what, sizeof(what), "day %u part 1", example->day);
+        expect_status(what, status, AOC_STATUS_OK);
+        if (status == AOC_STATUS_OK && strcmp(answer, example->expected) != 0) {
+            fprintf(stderr, "%s: expected %s, got %s\n", what, example->expected, answer);
+            failures++;
+        }
+        if (status == AOC_STATUS_OK && answer_len != strlen(example->expected)) {
+            fprintf(stderr, "%s: reported length %zu for %s\n", what, answer_len, answer);
+            failures++;
+        }
+    }
+
+    const char *input = EXAMPLES[0].input;
+    answer_len = sizeof(answer);
+    expect_status("unknown day", aoc_solve(26, 1, (const uint8_t *)input, strlen(input), answer,
+                                           &answer_len),
+                  AOC_STATUS_UNKNOWN_SOLVER);
+
+    answer_len = 3;
+    expect_status("small buffer", aoc_solve(1, 1, (const uint8_t *)input, strlen(input), answer,
+                                            &answer_len),
+                  AOC_STATUS_BUFFER_TOO_SMALL);
+    if (answer_len != 3) {
+        fprintf(stderr, "small buffer: expected the required length 3, got %zu\n", answer_len);
+        failures++;
+    }
+
+    const uint8_t invalid[] = {0xff, 0xfe};
+    answer_len = sizeof(answer);
+    expect_status("invalid UTF-8", aoc_solve(1, 1, invalid, sizeof(invalid), answer, &answer_len),
+                  AOC_STATUS_INVALID_UTF8);
+    expect_status("null output", aoc_solve(1, 1, NULL, 0, NULL, &answer_len),
+                  AOC_STATUS_NULL_POINTER);
+
+    if (failures > 0) {
+        fprintf(stderr, "%d check(s) failed\n", failures);
+        return 1;
+    }
+    printf("all checks passed\n");
+    return 0;
+}
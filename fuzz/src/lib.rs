@@ -0,0 +1,52 @@
+//! Shared harness of the per-day fuzz targets, run with e.g. `just fuzz 08`.
+//!
+//! Every variant gets every UTF-8 input. One that passes its day's shape check, see `aoc check`,
+//! may get an answer or an error, while one that doesn't must get an error: a variant that panics
+//! or answers malformed input is a bug. The lenient variants exist to take anything, so they only
+//! must not panic.
+//!
+//! `seeds/day_NN` holds an input of each day's shape, mostly the puzzle's example, for the fuzzer
+//! to mutate into inputs on both sides of the check. `just fuzz-seeds` replays them against every
+//! target, CI does so on every push.
+
+use std::time::Duration;
+
+use aoc_common::cancel::CancelToken;
+use aoc_common::params::Params;
+use aoc_runner::check;
+use aoc_runner::registry::{self, Solver};
+
+/// Cancellable solvers give up after this long, so inputs that never converge (e.g. a day-08 map
+/// without `ZZZ`) aren't reported as hangs. The others are left to libFuzzer's `-timeout`.
+const BUDGET: Duration = Duration::from_millis(100);
+
+/// Feeds `data` to every variant of both parts of `day` of `year`.
+///
+/// Any panic, e.g. an `unwrap` or out-of-bounds indexing, aborts the run and is reported as a
+/// crash, as is an answer to input that fails the check. Run with `-O` so arithmetic wraps as in
+/// the release `aoc`: the solvers use machine integers, so a large enough input overflows any of
+/// them without that being worth reporting.
+pub fn fuzz_day(year: u16, day: u8, data: &[u8]) {
+    // Solvers take `&str`, so only valid UTF-8 can reach them
+    let Ok(input) = std::str::from_utf8(data) else {
+        return;
+    };
+    for solver in registry::select_lenient(Some(year), Some(day), None) {
+        // Both an answer and an error are fine
+        let _ = solve(solver, input);
+    }
+    let valid = check::check(year, day, input).is_ok();
+    for solver in registry::select(Some(year), Some(day), None, None) {
+        let result = solve(solver, input);
+        assert!(
+            valid || result.is_err(),
+            "{solver} answered {result:?} to input that fails the check"
+        );
+    }
+}
+
+fn solve(solver: &Solver, input: &str) -> miette::Result<String> {
+    let token = CancelToken::with_timeout(BUDGET);
+    let params = Params::defaults(solver.params);
+    (solver.solve)(input, &token, &params)
+}
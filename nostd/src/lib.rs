@@ -0,0 +1,61 @@
+//! The allocation-free `bytes` cores of the days, built the way a `no_std` target would: without
+//! the days' `std` feature. Neither this crate nor the cores link `alloc`, so they can't allocate.
+//!
+//! `cargo test` checks them on the host, `cargo build --target thumbv7em-none-eabihf` on a
+//! target without `std` at all.
+
+#![no_std]
+
+pub use aoc2023_day_09::bytes as day09;
+pub use aoc2023_day_10::bytes as day10;
+pub use aoc2023_day_15::bytes as day15;
+pub use aoc2023_day_18::bytes as day18;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_day09() {
+        // 3x for x from 0 to 20, the real input's length
+        let input = b"0 3 6 9 12 15 18 21 24 27 30 33 36 39 42 45 48 51 54 57 60";
+        assert_eq!(63, day09::next_values_sum(input));
+        assert_eq!(-3, day09::previous_values_sum(input));
+    }
+
+    #[test]
+    fn test_day10() {
+        assert_eq!(
+            4,
+            day10::farthest_steps(b"-L|F7\n7S-7|\nL|7||\n-L-J|\nL|-JF")
+        );
+        let input = b"\
+..........
+.S------7.
+.|F----7|.
+.||....||.
+.||....||.
+.|L-7F-J|.
+.|..||..|.
+.L--JL--J.
+..........";
+        assert_eq!(4, day10::enclosed_tiles(input));
+    }
+
+    #[test]
+    fn test_day15() {
+        assert_eq!(
+            1320,
+            day15::hash_sum(b"rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7")
+        );
+    }
+
+    #[test]
+    fn test_day18() {
+        let input = b"R 6 (#70c710)\nD 5 (#0dc571)\nL 2 (#5713f0)\nD 2 (#d2c081)\nR 2 (#59c680)\n\
+                      D 2 (#411b91)\nL 5 (#8ceee2)\nU 2 (#caa173)\nL 1 (#1b58a2)\nU 2 (#caa171)\n\
+                      R 2 (#7807d2)\nU 3 (#a77fa3)\nL 2 (#015232)\nU 2 (#7a21e3)";
+        assert_eq!(62, day18::lagoon_size(input));
+        assert_eq!(952408144115, day18::hex_lagoon_size(input));
+    }
+}
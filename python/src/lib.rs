@@ -0,0 +1,322 @@
+//! Python bindings for the solvers, built with maturin into the `aoc2023` module.
+//!
+//! Every registered variant is exposed as `aoc2023.dayNN.partN.process[_variant](input, **params)`,
+//! e.g. `aoc2023.day12.part2.process(text, unfold=2)`. Some days also expose their parsed model:
+//! `aoc2023.day07.Hand`, `aoc2023.day14.Platform`, `aoc2023.day16.BeamMap` and
+//! `aoc2023.day19.Workflows`.
+
+use std::panic::AssertUnwindSafe;
+use std::sync::Arc;
+
+use aoc_common::frame::{self, Frame};
+use aoc_common::params::Params;
+use aoc_runner::execute::{self, Outcome};
+use aoc_runner::registry::{self, Solver};
+use pyo3::create_exception;
+use pyo3::exceptions::{PyException, PyValueError};
+use pyo3::prelude::*;
+use pyo3::pyclass::CompareOp;
+use pyo3::types::{PyCFunction, PyDict, PyTuple};
+
+/// The module only exposes this year's solvers
+const YEAR: u16 = 2023;
+
+create_exception!(
+    aoc2023,
+    AocError,
+    PyException,
+    "A solver failed. `code` and `help` hold the diagnostic's code and help text."
+);
+create_exception!(
+    aoc2023,
+    AocIoError,
+    AocError,
+    "`AocError::IoError` of a day crate."
+);
+create_exception!(
+    aoc2023,
+    CancelledError,
+    AocError,
+    "`AocError::Cancelled` of a day crate."
+);
+create_exception!(
+    aoc2023,
+    SolverPanicked,
+    AocError,
+    "The solver panicked, which is a bug: malformed input raises ValueError."
+);
+
+/// Raises the exception matching the report's diagnostic code, with `code` and `help` attached.
+fn raise(py: Python<'_>, report: &dyn miette::Diagnostic) -> PyErr {
+    let code = report.code().map(|code| code.to_string());
+    let message = report.to_string();
+    let error = match code.as_deref() {
+        Some("aoc::io_error") => AocIoError::new_err(message),
+        Some("aoc::cancelled") => CancelledError::new_err(message),
+        Some(code) if code.starts_with("aoc::param::") || code.starts_with("aoc::input::") => {
+            PyValueError::new_err(message)
+        }
+        _ => AocError::new_err(message),
+    };
+    let help = report.help().map(|help| help.to_string());
+    let value = error.value(py);
+    // Exception instances always have a `__dict__`, so setting attributes can't fail
+    value
+        .setattr("code", code)
+        .and(value.setattr("help", help))
+        .ok();
+    error
+}
+
+fn solve(
+    py: Python<'_>,
+    solver: &'static Solver,
+    input: &str,
+    kwargs: Option<&PyDict>,
+) -> PyResult<String> {
+    let mut overrides = vec![];
+    for (name, value) in kwargs.into_iter().flatten() {
+        overrides.push((name.extract::<String>()?, value.str()?.to_string()));
+    }
+    let params = Params::resolve(solver.params, &overrides).map_err(|error| raise(py, &error))?;
+
+    let input: Arc<str> = input.into();
+    match py.allow_threads(|| execute::run(solver, input, &params, None)) {
+        Outcome::Solved { answer, .. } => Ok(answer),
+        Outcome::Failed(report) => Err(raise(py, report.as_ref())),
+        Outcome::Panicked(message) => Err(SolverPanicked::new_err(message)),
+        Outcome::TimedOut(budget) => Err(CancelledError::new_err(format!(
+            "timed out after {budget:.2?}"
+        ))),
+    }
+}
+
+/// Runs any registered variant, `aoc2023.solve(19, 2, text, variant="dfs", max_rating=10)`.
+#[pyfunction]
+#[pyo3(name = "solve", signature = (day, part, input, variant = "default", **params))]
+fn solve_any(
+    py: Python<'_>,
+    day: u8,
+    part: u8,
+    input: &str,
+    variant: &str,
+    params: Option<&PyDict>,
+) -> PyResult<String> {
+    let Some(solver) = registry::select(Some(YEAR), Some(day), Some(part), Some(variant)).next()
+    else {
+        return Err(PyValueError::new_err(format!(
+            "no solver for day {day} part {part} variant `{variant}`"
+        )));
+    };
+    solve(py, solver, input, params)
+}
+
+/// `(day, part, variant)` of every registered solver of the year.
+#[pyfunction]
+fn variants() -> Vec<(u8, u8, &'static str)> {
+    registry::select(Some(YEAR), None, None, None)
+        .map(|solver| (solver.day, solver.part, solver.variant))
+        .collect()
+}
+
+/// The workflows section of a day 19 input.
+#[pyclass(name = "Workflows", module = "aoc2023.day19")]
+struct Workflows(aoc2023_day_19::part1::Workflows);
+
+#[pymethods]
+impl Workflows {
+    #[staticmethod]
+    fn parse(py: Python<'_>, text: &str) -> PyResult<Workflows> {
+        let parse = || aoc2023_day_19::part1::Workflows::parse(text);
+        match std::panic::catch_unwind(AssertUnwindSafe(parse)) {
+            Ok(Ok(workflows)) => Ok(Workflows(workflows)),
+            Ok(Err(error)) => Err(raise(py, &error)),
+            Err(_) => Err(SolverPanicked::new_err("malformed workflows")),
+        }
+    }
+
+    /// Whether a part with these ratings ends up accepted.
+    fn accepts(&self, x: u32, m: u32, a: u32, s: u32) -> bool {
+        self.0.accepts([x, m, a, s])
+    }
+}
+
+/// One line of a day 7 input, ranked with `J` as a jack like part 1 does.
+#[pyclass(name = "Hand", module = "aoc2023.day07")]
+struct Hand(aoc2023_day_07::part1::HandBid);
+
+#[pymethods]
+impl Hand {
+    #[staticmethod]
+    fn parse(line: &str) -> PyResult<Hand> {
+        line.parse()
+            .map(Hand)
+            .map_err(|_| PyValueError::new_err(format!("malformed hand `{line}`")))
+    }
+
+    #[getter]
+    fn bid(&self) -> u32 {
+        self.0.bid()
+    }
+
+    /// The hand's type, e.g. `"FullHouse"`.
+    #[getter]
+    fn category(&self) -> String {
+        format!("{:?}", self.0.category())
+    }
+
+    /// Hands compare by strength, so `sorted(hands)` ranks them.
+    fn __richcmp__(&self, other: &Hand, op: CompareOp) -> bool {
+        op.matches(self.0.cmp(&other.0))
+    }
+}
+
+/// The platform of a day 14 input, tilted in place.
+#[pyclass(name = "Platform", module = "aoc2023.day14")]
+struct Platform(aoc2023_day_14::part2::Platform);
+
+#[pymethods]
+impl Platform {
+    #[staticmethod]
+    fn parse(py: Python<'_>, grid: &str) -> PyResult<Platform> {
+        aoc2023_day_14::part2::parse(grid)
+            .map(Platform)
+            .map_err(|error| raise(py, &error))
+    }
+
+    fn tilt_north(&mut self) {
+        self.0.tilt_north();
+    }
+
+    fn tilt_west(&mut self) {
+        self.0.tilt_west();
+    }
+
+    fn tilt_south(&mut self) {
+        self.0.tilt_south();
+    }
+
+    fn tilt_east(&mut self) {
+        self.0.tilt_east();
+    }
+
+    /// Tilts north, west, south then east.
+    fn spin_cycle(&mut self) {
+        self.0.spin_cycle();
+    }
+
+    /// The total load on the north support beams.
+    fn north_load(&self) -> usize {
+        self.0.calculate_north_load()
+    }
+
+    fn __str__(&self) -> String {
+        frame::to_text(&self.0)
+    }
+}
+
+/// The contraption of a day 16 input.
+#[pyclass(name = "BeamMap", module = "aoc2023.day16")]
+struct BeamMap {
+    grid: String,
+    width: usize,
+    height: usize,
+}
+
+#[pymethods]
+impl BeamMap {
+    #[staticmethod]
+    fn parse(py: Python<'_>, grid: &str) -> PyResult<BeamMap> {
+        let beam_map = aoc2023_day_16::part2::parse(grid).map_err(|error| raise(py, &error))?;
+        let (width, height) = (beam_map.width(), beam_map.height());
+        Ok(BeamMap {
+            grid: grid.to_string(),
+            width,
+            height,
+        })
+    }
+
+    /// Tiles energized by a beam entering at `(x, y)`, heading `"up"`, `"down"`, `"left"` or
+    /// `"right"`.
+    fn energized(&self, x: usize, y: usize, heading: &str) -> PyResult<u32> {
+        use aoc2023_day_16::part2::{Direction, Photon};
+
+        let direction = match heading {
+            "up" => Direction::Up,
+            "down" => Direction::Down,
+            "left" => Direction::Left,
+            "right" => Direction::Right,
+            _ => {
+                return Err(PyValueError::new_err(format!(
+                    "unknown heading `{heading}`"
+                )))
+            }
+        };
+        if x >= self.width || y >= self.height {
+            return Err(PyValueError::new_err(format!(
+                "({x}, {y}) is outside the contraption"
+            )));
+        }
+        let mut beam_map = aoc2023_day_16::part2::BeamMap::init(self.grid.as_bytes());
+        beam_map.reset(Photon::new(y * (self.width + 1) + x, direction));
+        while beam_map.advance_photons() {}
+        Ok(beam_map.get_energized_count())
+    }
+
+    fn __str__(&self) -> String {
+        self.grid.clone()
+    }
+}
+
+fn function_name(solver: &Solver) -> &'static str {
+    if solver.variant == "default" {
+        "process"
+    } else {
+        // Created once per solver when the module is imported
+        Box::leak(format!("process_{}", solver.variant).into_boxed_str())
+    }
+}
+
+#[pymodule]
+fn aoc2023(py: Python<'_>, module: &PyModule) -> PyResult<()> {
+    module.add("AocError", py.get_type::<AocError>())?;
+    module.add("AocIoError", py.get_type::<AocIoError>())?;
+    module.add("CancelledError", py.get_type::<CancelledError>())?;
+    module.add("SolverPanicked", py.get_type::<SolverPanicked>())?;
+    module.add_function(wrap_pyfunction!(solve_any, module)?)?;
+    module.add_function(wrap_pyfunction!(variants, module)?)?;
+
+    let mut days = registry::select(Some(YEAR), None, None, None)
+        .map(|solver| solver.day)
+        .collect::<Vec<_>>();
+    days.dedup();
+    for day in days {
+        let day_module = PyModule::new(py, &format!("day{day:02}"))?;
+        for part in 1..=2 {
+            let part_module = PyModule::new(py, &format!("part{part}"))?;
+            for solver in registry::select(Some(YEAR), Some(day), Some(part), None) {
+                let name = function_name(solver);
+                let function = PyCFunction::new_closure(
+                    py,
+                    Some(name),
+                    Some("process(input, **params) -> str"),
+                    move |args: &PyTuple, kwargs: Option<&PyDict>| -> PyResult<String> {
+                        let input = args.get_item(0)?.extract::<&str>()?;
+                        solve(args.py(), solver, input, kwargs)
+                    },
+                )?;
+                part_module.add(name, function)?;
+            }
+            day_module.add_submodule(part_module)?;
+        }
+        match day {
+            7 => day_module.add_class::<Hand>()?,
+            14 => day_module.add_class::<Platform>()?,
+            16 => day_module.add_class::<BeamMap>()?,
+            19 => day_module.add_class::<Workflows>()?,
+            _ => {}
+        }
+        module.add_submodule(day_module)?;
+    }
+    Ok(())
+}
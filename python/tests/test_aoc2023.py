@@ -0,0 +1,363 @@
+"""Runs the extension module against the puzzle examples.
+
+`just test-python` builds and installs it into the active virtualenv with `maturin develop`, then
+runs this suite, as the python workflow does on every push.
+"""
+
+import pytest
+
+import aoc2023
+
+# The part 1 examples from each puzzle text, keyed by day
+EXAMPLES = {
+    1: """\
+1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet""",
+    2: """\
+Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green""",
+    3: """\
+467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..""",
+    4: """\
+Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11""",
+    5: """\
+seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4""",
+    6: """\
+Time:      7  15   30
+Distance:  9  40  200""",
+    7: """\
+32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483""",
+    8: """\
+RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)""",
+    9: """\
+0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45""",
+    10: """\
+-L|F7
+7S-7|
+L|7||
+-L-J|
+L|-JF""",
+    11: """\
+...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....""",
+    12: '???.### 1,1,3',
+    13: (
+        '#.##..##.\r\n'
+        '..#.##.#.\r\n'
+        '##......#\r\n'
+        '##......#\r\n'
+        '..#.##.#.\r\n'
+        '..##..##.\r\n'
+        '#.#.##.#.\r\n'
+        '\r\n'
+        '#...##..#\r\n'
+        '#....#..#\r\n'
+        '..##..###\r\n'
+        '#####.##.\r\n'
+        '#####.##.\r\n'
+        '..##..###\r\n'
+        '#....#..#'
+    ),
+    14: """\
+O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....""",
+    15: 'rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7',
+    16: (
+        '.|...\\....\n'
+        '|.-.\\.....\n'
+        '.....|-...\n'
+        '........|.\n'
+        '..........\n'
+        '.........\\\n'
+        '..../.\\\\..\n'
+        '.-.-/..|..\n'
+        '.|....-|.\\\n'
+        '..//.|....'
+    ),
+    17: """\
+2413432311323
+3215453535623
+3255245654254
+3446585845452
+4546657867536
+1438598798454
+4457876987766
+3637877979653
+4654967986887
+4564679986453
+1224686865563
+2546548887735
+4322674655533""",
+    18: """\
+R 6 (#70c710)
+D 5 (#0dc571)
+L 2 (#5713f0)
+D 2 (#d2c081)
+R 2 (#59c680)
+D 2 (#411b91)
+L 5 (#8ceee2)
+U 2 (#caa173)
+L 1 (#1b58a2)
+U 2 (#caa171)
+R 2 (#7807d2)
+U 3 (#a77fa3)
+L 2 (#015232)
+U 2 (#7a21e3)""",
+    19: """\
+px{a<2006:qkq,m>2090:A,rfg}
+pv{a>1716:R,A}
+lnx{m>1548:A,A}
+rfg{s<537:gd,x>2440:R,A}
+qs{s>3448:A,lnx}
+qkq{x<1416:A,crn}
+crn{x>2662:A,R}
+in{s<1351:px,qqz}
+qqz{s>2770:qs,m<1801:hdj,R}
+gd{a>3333:R,R}
+hdj{m>838:A,pv}
+
+{x=787,m=2655,a=1222,s=2876}
+{x=1679,m=44,a=2067,s=496}
+{x=2036,m=264,a=79,s=2244}
+{x=2461,m=1339,a=466,s=291}
+{x=2127,m=1623,a=2188,s=1013}""",
+}
+
+PART_1 = {
+    1: "142",
+    2: "8",
+    3: "4361",
+    4: "13",
+    5: "35",
+    6: "288",
+    7: "6440",
+    8: "2",
+    9: "114",
+    10: "4",
+    11: "374",
+    12: "1",
+    13: "405",
+    14: "136",
+    15: "1320",
+    16: "46",
+    17: "102",
+    18: "62",
+    19: "19114",
+}
+
+PART_2 = {
+    2: "2286",
+    3: "467835",
+    4: "30",
+    5: "46",
+    6: "71503",
+    7: "5905",
+    11: "82000210",
+    12: "1",
+    13: "400",
+    14: "64",
+    15: "145",
+    16: "51",
+    17: "94",
+    18: "952408144115",
+    19: "167409079868000",
+}
+
+
+def part(day, part):
+    return getattr(getattr(aoc2023, f"day{day:02}"), f"part{part}")
+
+
+# These assume the real input's 21 numbers per row
+REAL_INPUT_ONLY = {(9, 1, "optimized"), (9, 1, "optimized_dft"), (9, 1, "bc")}
+
+
+def processes(day, part_number):
+    """Every variant of a part that handles the example, as exposed on its module"""
+    for variant_day, variant_part, variant in aoc2023.variants():
+        if (variant_day, variant_part, variant) in REAL_INPUT_ONLY:
+            continue
+        if (variant_day, variant_part) == (day, part_number):
+            name = "process" if variant == "default" else f"process_{variant}"
+            yield getattr(part(day, part_number), name)
+
+
+@pytest.mark.parametrize("day", sorted(PART_1))
+def test_part_1(day):
+    for process in processes(day, 1):
+        assert process(EXAMPLES[day]) == PART_1[day], process.__name__
+
+
+@pytest.mark.parametrize("day", sorted(PART_2))
+def test_part_2(day):
+    for process in processes(day, 2):
+        assert process(EXAMPLES[day]) == PART_2[day], process.__name__
+
+
+def test_module():
+    for day, part_number, variant in aoc2023.variants():
+        name = "process" if variant == "default" else f"process_{variant}"
+        assert callable(getattr(part(day, part_number), name))
+    assert issubclass(aoc2023.AocIoError, aoc2023.AocError)
+
+
+def test_solve():
+    assert aoc2023.solve(19, 2, EXAMPLES[19], variant="dfs") == PART_2[19]
+    assert aoc2023.solve(19, 2, EXAMPLES[19], max_rating=1) == "1"
+
+
+def test_params():
+    assert part(11, 2).process(EXAMPLES[11], expansion=10) == "1030"
+    assert part(11, 2).process_fastest(EXAMPLES[11], expansion="100") == "8410"
+    assert aoc2023.solve(2, 1, EXAMPLES[2], red=20) == "11"
+
+
+def test_param_errors():
+    with pytest.raises(ValueError, match="unknown parameter `speed`") as error:
+        part(11, 2).process(EXAMPLES[11], speed=1)
+    assert error.value.code == "aoc::param::unknown"
+    assert "expansion" in error.value.help
+
+    with pytest.raises(ValueError, match="must be between"):
+        part(11, 2).process(EXAMPLES[11], expansion=0)
+
+
+def test_solver_errors():
+    with pytest.raises(ValueError, match="expected shape") as error:
+        part(1, 1).process("no digits")
+    assert error.value.code == "aoc::input::shape"
+    assert issubclass(aoc2023.SolverPanicked, aoc2023.AocError)
+    assert issubclass(aoc2023.CancelledError, aoc2023.AocError)
+
+    with pytest.raises(ValueError, match="no solver"):
+        aoc2023.solve(26, 1, "")
+
+
+def test_workflows():
+    workflows, parts = EXAMPLES[19].split("\n\n")
+    workflows = aoc2023.day19.Workflows.parse(workflows)
+    assert workflows.accepts(x=787, m=2655, a=1222, s=2876)
+    assert not workflows.accepts(1679, 44, 2067, 496)
+
+    with pytest.raises(aoc2023.SolverPanicked):
+        aoc2023.day19.Workflows.parse("not a workflow")
+
+
+def test_hands():
+    hands = [aoc2023.day07.Hand.parse(line) for line in EXAMPLES[7].splitlines()]
+    assert [hand.bid for hand in sorted(hands)] == [765, 220, 28, 684, 483]
+    assert hands[0].category == "OnePair"
+    assert hands[1] > hands[0]
+
+    with pytest.raises(ValueError, match="malformed hand"):
+        aoc2023.day07.Hand.parse("32T3 765")
+
+
+def test_platform():
+    platform = aoc2023.day14.Platform.parse(EXAMPLES[14])
+    assert str(platform) == EXAMPLES[14] + "\n"
+    platform.tilt_north()
+    assert platform.north_load() == 136
+    platform.spin_cycle()
+    assert platform.north_load() == 87
+
+    with pytest.raises(ValueError) as error:
+        aoc2023.day14.Platform.parse("O.#")
+    assert error.value.code == "aoc::input::shape"
+    # Ragged rows are reported too, rather than panicking
+    with pytest.raises(ValueError):
+        aoc2023.day14.Platform.parse("O.#\n.O\n")
+
+
+def test_beam_map():
+    beam_map = aoc2023.day16.BeamMap.parse(EXAMPLES[16])
+    assert beam_map.energized(0, 0, "right") == int(PART_1[16])
+    assert beam_map.energized(3, 0, "down") == int(PART_2[16])
+
+    with pytest.raises(ValueError, match="outside"):
+        beam_map.energized(10, 0, "left")
+    with pytest.raises(ValueError, match="unknown heading"):
+        beam_map.energized(0, 0, "north")
+    with pytest.raises(ValueError, match="expected shape"):
+        aoc2023.day16.BeamMap.parse(".|.\n.\n")
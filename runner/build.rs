@@ -0,0 +1,68 @@
+//! Hashes the sources of every day crate, with what they share, so cached answers are invalidated
+//! when a solver changes.
+
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+fn main() {
+    let manifest_dir = PathBuf::from(std::env::var("CARGO_MANIFEST_DIR").unwrap());
+    let root = manifest_dir.parent().unwrap();
+
+    // Every day links against the shared crate, so it's part of every day's hash. So are the
+    // registry, which picks the function behind each variant, and the lock file, which pins the
+    // dependencies' versions.
+    let shared = [
+        root.join("common/src"),
+        root.join("common/Cargo.toml"),
+        root.join("runner/src/registry.rs"),
+        root.join("Cargo.lock"),
+    ];
+
+    // `YEAR/day-NN`
+    let mut days = vec![];
+    for year_entry in fs::read_dir(root).unwrap().filter_map(Result::ok) {
+        let Some(year) = year_entry.file_name().to_str().and_then(|name| name.parse::<u16>().ok())
+        else {
+            continue;
+        };
+        for entry in fs::read_dir(year_entry.path()).unwrap().filter_map(Result::ok) {
+            let name = entry.file_name().into_string().unwrap_or_default();
+            if let Some(day) = name.strip_prefix("day-").and_then(|day| day.parse::<u8>().ok()) {
+                days.push((year, day, entry.path()));
+            }
+        }
+    }
+    days.sort();
+
+    let out_path = PathBuf::from(std::env::var("OUT_DIR").unwrap()).join("build_hashes.rs");
+    let mut out = fs::File::create(out_path).unwrap();
+    writeln!(out, "pub static BUILD_HASHES: &[(u16, u8, u64)] = &[").unwrap();
+    for (year, day, dir) in days {
+        let inputs = [dir.join("src"), dir.join("Cargo.toml")];
+        let mut files = vec![];
+        for path in inputs.iter().chain(&shared) {
+            println!("cargo:rerun-if-changed={}", path.display());
+            collect_files(path, &mut files);
+        }
+        files.sort();
+
+        let mut bytes = vec![];
+        for file in files {
+            bytes.extend(file.strip_prefix(root).unwrap().to_string_lossy().as_bytes());
+            bytes.extend(fs::read(&file).unwrap());
+        }
+        writeln!(out, "    ({year}, {day}, {:#018x}),", seahash::hash(&bytes)).unwrap();
+    }
+    writeln!(out, "];").unwrap();
+}
+
+fn collect_files(path: &Path, files: &mut Vec<PathBuf>) {
+    if path.is_dir() {
+        for entry in fs::read_dir(path).unwrap() {
+            collect_files(&entry.unwrap().path(), files);
+        }
+    } else if path.is_file() {
+        files.push(path.to_path_buf());
+    }
+}
@@ -0,0 +1,315 @@
+use std::collections::HashMap;
+use std::num::NonZeroUsize;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{mpsc, Arc};
+use std::time::Duration;
+
+use aoc_common::params::{ParamError, Params};
+use aoc_common::progress::Progress;
+
+use crate::cache::Cache;
+use crate::check;
+use crate::execute::{self, Outcome, Slots};
+use crate::input;
+use crate::progress::Reporter;
+use crate::registry::Solver;
+
+/// A solver paired with the input it should run on, or the reason the input couldn't be loaded.
+pub struct Job {
+    pub solver: &'static Solver,
+    pub input: Result<Arc<str>, String>,
+    pub params: Params,
+}
+
+/// Loads the input for every solver, reading each file only once. `input_override` replaces the
+/// per-day default input files and `param_overrides` are applied on top of each solver's defaults.
+pub fn jobs_for(
+    solvers: impl IntoIterator<Item = &'static Solver>,
+    input_override: Option<&Path>,
+    param_overrides: &[(String, String)],
+) -> Result<Vec<Job>, ParamError> {
+    let root = input::workspace_root();
+    let mut loaded: HashMap<PathBuf, Result<Arc<str>, String>> = HashMap::new();
+
+    solvers
+        .into_iter()
+        .map(|solver| {
+            let path = match input_override {
+                Some(path) => Ok(path.to_path_buf()),
+                None => input::default_input_path(root, solver.year, solver.day, solver.part),
+            };
+            let input = match path {
+                Ok(path) => loaded
+                    .entry(path)
+                    .or_insert_with_key(|path| {
+                        input::read_input(path)
+                            .map(Arc::from)
+                            .map_err(|report| {
+                                let chain = report.chain().map(ToString::to_string);
+                                chain.collect::<Vec<_>>().join(": ")
+                            })
+                    })
+                    .clone(),
+                Err(report) => Err(report.to_string()),
+            };
+            let params = Params::resolve(solver.params, param_overrides)?;
+            Ok(Job {
+                solver,
+                input,
+                params,
+            })
+        })
+        .collect()
+}
+
+pub fn default_jobs() -> NonZeroUsize {
+    std::thread::available_parallelism().unwrap_or(NonZeroUsize::MIN)
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Options<'a> {
+    /// Number of jobs to run at once
+    pub threads: NonZeroUsize,
+    /// Time budget per sample
+    pub timeout: Option<Duration>,
+    /// Number of times to run each job. The reported time is the median.
+    pub samples: NonZeroUsize,
+    /// Answers found here are served from it, new answers are stored in it
+    pub cache: Option<&'a Cache>,
+    /// Fail the jobs whose input doesn't have the shape of their day instead of running them
+    pub check: bool,
+    /// Show the progress of the solvers that report it on stderr
+    pub progress: bool,
+    /// Shared with other batches, to cap the solvers running at once across all of them
+    pub slots: Option<&'a Slots>,
+}
+
+impl Default for Options<'_> {
+    fn default() -> Self {
+        Options {
+            threads: NonZeroUsize::MIN,
+            timeout: None,
+            samples: NonZeroUsize::MIN,
+            cache: None,
+            check: false,
+            progress: false,
+            slots: None,
+        }
+    }
+}
+
+/// Runs every job on a pool of worker threads and returns the outcomes in job order.
+///
+/// `on_done` is called from the worker threads as soon as each job finishes.
+pub fn run(
+    jobs: Vec<Job>,
+    options: Options,
+    on_done: impl Fn(&Solver, &Outcome) + Sync,
+) -> Vec<(&'static Solver, Outcome)> {
+    let next_job = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    std::thread::scope(|scope| {
+        for _ in 0..options.threads.get().min(jobs.len()) {
+            let sender = sender.clone();
+            let (jobs, next_job, on_done) = (&jobs, &next_job, &on_done);
+            scope.spawn(move || {
+                loop {
+                    let index = next_job.fetch_add(1, Ordering::Relaxed);
+                    let Some(job) = jobs.get(index) else {
+                        break;
+                    };
+                    let outcome = match &job.input {
+                        Ok(input) if options.check => {
+                            let (year, day) = (job.solver.year, job.solver.day);
+                            match check::check(year, day, input) {
+                                Ok(()) => run_cached(job.solver, input, &job.params, &options),
+                                Err(invalid) => Outcome::Failed(invalid.into()),
+                            }
+                        }
+                        Ok(input) => run_cached(job.solver, input, &job.params, &options),
+                        Err(message) => Outcome::Failed(miette::miette!("{message}")),
+                    };
+                    on_done(job.solver, &outcome);
+                    sender.send((index, outcome)).unwrap();
+                }
+            });
+        }
+    });
+    drop(sender);
+
+    let mut outcomes = jobs.iter().map(|_| None).collect::<Vec<_>>();
+    for (index, outcome) in receiver {
+        outcomes[index] = Some(outcome);
+    }
+    jobs.iter()
+        .zip(outcomes)
+        .map(|(job, outcome)| {
+            let outcome = outcome.expect("every job should report an outcome");
+            (job.solver, outcome)
+        })
+        .collect()
+}
+
+/// Answers for overridden parameters are never cached, the cache is keyed by the defaults.
+fn run_cached(solver: &Solver, input: &Arc<str>, params: &Params, options: &Options) -> Outcome {
+    let run = || {
+        let (timeout, samples) = (options.timeout, options.samples);
+        let reporter = options.progress.then(|| Arc::new(Reporter::new(solver)));
+        let progress = reporter.clone().map(|r| r as Arc<dyn Progress>);
+        let input = input.clone();
+        let slots = options.slots;
+        let outcome =
+            execute::run_sampled(solver, input, params, timeout, samples, progress, slots);
+        if let Some(reporter) = reporter {
+            reporter.finish();
+        }
+        outcome
+    };
+    let cache = match options.cache {
+        Some(cache) if *params == Params::defaults(solver.params) => cache,
+        _ => return run(),
+    };
+    if let Some((answer, elapsed)) = cache.get(solver, input) {
+        return Outcome::Solved {
+            answer,
+            elapsed,
+            cached: true,
+        };
+    }
+    let outcome = run();
+    if let Outcome::Solved {
+        answer, elapsed, ..
+    } = &outcome
+    {
+        // A broken cache only costs a recompute next time
+        if let Err(report) = cache.put(solver, input, answer, *elapsed) {
+            eprintln!("warning: {report}");
+        }
+    }
+    outcome
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use aoc_common::params::ParamSpec;
+
+    use crate::registry::SolveFn;
+
+    static SOLVERS: [Solver; 3] = {
+        const fn solver(day: u8, solve: SolveFn) -> Solver {
+            Solver {
+                year: 2023,
+                day,
+                part: 1,
+                variant: "test",
+                cancellable: false,
+                params: &[],
+                solve,
+            }
+        }
+        [
+            solver(1, |input, _, _| {
+                std::thread::sleep(Duration::from_millis(20));
+                Ok(input.to_string())
+            }),
+            solver(2, |_, _, _| miette::bail!("bad input")),
+            solver(3, |input, _, _| Ok(input.len().to_string())),
+        ]
+    };
+
+    #[test]
+    fn test_run_keeps_job_order() {
+        let jobs = SOLVERS
+            .iter()
+            .map(|solver| Job {
+                solver,
+                input: Ok("abc".into()),
+                params: Params::default(),
+            })
+            .collect();
+        let done = AtomicUsize::new(0);
+        let options = Options {
+            threads: NonZeroUsize::new(3).unwrap(),
+            ..Options::default()
+        };
+        let outcomes = run(jobs, options, |_, _| {
+            done.fetch_add(1, Ordering::Relaxed);
+        });
+
+        assert_eq!(3, done.into_inner());
+        let days = outcomes.iter().map(|(solver, _)| solver.day).collect::<Vec<_>>();
+        assert_eq!(vec![1, 2, 3], days);
+        assert!(matches!(&outcomes[0].1, Outcome::Solved { answer, .. } if answer == "abc"));
+        assert!(matches!(&outcomes[1].1, Outcome::Failed(_)));
+        assert!(matches!(&outcomes[2].1, Outcome::Solved { answer, .. } if answer == "3"));
+    }
+
+    #[test]
+    fn test_missing_input_is_a_failure() {
+        let jobs = vec![Job {
+            solver: &SOLVERS[2],
+            input: Err("no input file found".to_string()),
+            params: Params::default(),
+        }];
+        let outcomes = run(jobs, Options::default(), |_, _| ());
+        assert!(matches!(&outcomes[0].1, Outcome::Failed(_)));
+    }
+
+    #[test]
+    fn test_check_fails_malformed_inputs() {
+        let jobs = ["1abc2", "abc"]
+            .map(|input| Job {
+                solver: &SOLVERS[0],
+                input: Ok(input.into()),
+                params: Params::default(),
+            })
+            .into();
+        let options = Options {
+            check: true,
+            ..Options::default()
+        };
+        let outcomes = run(jobs, options, |_, _| ());
+        assert!(matches!(&outcomes[0].1, Outcome::Solved { answer, .. } if answer == "1abc2"));
+        assert!(matches!(&outcomes[1].1, Outcome::Failed(report) if report.code().is_some()));
+    }
+
+    #[test]
+    fn test_params_reach_the_solver() -> Result<(), ParamError> {
+        static SCALED: Solver = Solver {
+            year: 2023,
+            day: 1,
+            part: 1,
+            variant: "scaled",
+            cancellable: false,
+            params: &[ParamSpec {
+                name: "factor",
+                help: "",
+                default: 2,
+                min: 1,
+                max: 10,
+            }],
+            solve: |input, _, params| Ok((input.len() * params.get::<usize>("factor")).to_string()),
+        };
+        let input = Path::new(env!("CARGO_MANIFEST_DIR")).join("Cargo.toml");
+
+        let overrides = [("factor".to_string(), "3".to_string())];
+        for (overrides, factor) in [(&overrides[..0], 2), (&overrides[..], 3)] {
+            let jobs = jobs_for([&SCALED], Some(&input), overrides)?;
+            let expected = (jobs[0].input.as_ref().unwrap().len() * factor).to_string();
+            let outcomes = run(jobs, Options::default(), |_, _| ());
+            let answer = match &outcomes[0].1 {
+                Outcome::Solved { answer, .. } => answer.as_str(),
+                outcome => panic!("{outcome}"),
+            };
+            assert_eq!(expected, answer);
+        }
+
+        let out_of_range = [("factor".to_string(), "11".to_string())];
+        assert!(jobs_for([&SCALED], Some(&input), &out_of_range).is_err());
+        Ok(())
+    }
+}
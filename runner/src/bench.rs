@@ -0,0 +1,205 @@
+//! Benchmark reports, i.e. `cargo bench` output below the [`Metadata`] header of the run, and
+//! comparisons of two reports' median times labelled by what changed between their runs.
+
+use std::fmt::Write;
+use std::time::Duration;
+
+use crate::machine::Metadata;
+
+/// One run of the benchmarks
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Report {
+    pub metadata: Metadata,
+    /// Median time of every benchmark, named `group/benchmark` in the order divan printed them
+    pub medians: Vec<(String, Duration)>,
+}
+
+impl Report {
+    /// The last run in `text`. Per-day report files get a header and a run appended each time.
+    pub fn parse_last(text: &str) -> Report {
+        let lines = text.lines().collect::<Vec<_>>();
+        let start = (0..lines.len())
+            .rev()
+            .find(|&i| lines[i].starts_with("# ") && (i == 0 || !lines[i - 1].starts_with("# ")))
+            .unwrap_or(0);
+        let run = lines[start..].join("\n");
+        Report {
+            metadata: Metadata::parse(&run),
+            medians: parse_medians(&run),
+        }
+    }
+}
+
+/// Reads divan's tables, e.g.
+///
+/// ```text
+/// day_01_bench       fastest       │ slowest       │ median        │ mean  ...
+/// ├─ part1           88.59 µs      │ 219.7 µs      │ 89.69 µs      │ 96.6 µs ...
+/// ```
+///
+/// Benchmarks with arguments are nested a level deeper and named `group/benchmark/argument`.
+fn parse_medians(text: &str) -> Vec<(String, Duration)> {
+    let mut medians = vec![];
+    let mut path: Vec<&str> = vec![];
+    for line in text.lines() {
+        let name_start = line
+            .find(|c| !matches!(c, '│' | '├' | '╰' | '─' | ' '))
+            .unwrap_or(line.len());
+        let (tree, row) = line.split_at(name_start);
+        let columns = row.split('│').collect::<Vec<_>>();
+        let Some(name) = columns[0].split_whitespace().next() else {
+            continue;
+        };
+        if tree.is_empty() {
+            // A group heading, or something that isn't part of a table at all
+            path.clear();
+            if columns[0].split_whitespace().nth(1) == Some("fastest") {
+                path.push(name);
+            }
+            continue;
+        }
+        if path.is_empty() {
+            continue;
+        }
+        // Each level of the tree is indented by 3 characters
+        let depth = tree.chars().count() / 3;
+        path.truncate(depth);
+        path.push(name);
+        if let Some(median) = columns.get(2).and_then(|median| parse_time(median)) {
+            medians.push((path.join("/"), median));
+        }
+    }
+    medians
+}
+
+/// Parses divan's times, e.g. `89.69 µs` or `1.531 m`
+fn parse_time(time: &str) -> Option<Duration> {
+    let (value, unit) = time.trim().split_once(' ')?;
+    let nanos_per_unit = match unit.trim() {
+        "ps" => 0.001,
+        "ns" => 1.0,
+        "µs" | "us" => 1e3,
+        "ms" => 1e6,
+        "s" => 1e9,
+        "m" => 60e9,
+        "h" => 3600e9,
+        _ => return None,
+    };
+    let nanos = value.parse::<f64>().ok()? * nanos_per_unit;
+    Some(Duration::from_nanos(nanos.round() as u64))
+}
+
+const HEADERS: [&str; 4] = ["Benchmark", "Old median", "New median", "Change"];
+
+/// What kind of comparison this is, the metadata that differs and a table of medians with the
+/// relative change. Benchmarks only one report has get `-` for the other.
+pub fn render_comparison(old: &Report, new: &Report) -> String {
+    let mut text = format!("{}\n", old.metadata.comparison_label(&new.metadata));
+    for (key, old_value, new_value) in old.metadata.differences(&new.metadata) {
+        writeln!(text, "  {key}: {old_value} -> {new_value}").unwrap();
+    }
+    text.push('\n');
+
+    let mut names = old.medians.iter().map(|(name, _)| name).collect::<Vec<_>>();
+    for (name, _) in &new.medians {
+        if !names.contains(&name) {
+            names.push(name);
+        }
+    }
+    let median = |report: &Report, name: &str| {
+        report
+            .medians
+            .iter()
+            .find(|(benchmark, _)| benchmark == name)
+            .map(|(_, median)| *median)
+    };
+    let rows = names
+        .into_iter()
+        .map(|name| {
+            let (old_median, new_median) = (median(old, name), median(new, name));
+            let format = |median: Option<Duration>| {
+                median.map_or("-".to_string(), |median| format!("{median:.2?}"))
+            };
+            let change = match (old_median, new_median) {
+                (Some(old), Some(new)) if !old.is_zero() => {
+                    let change = (new.as_secs_f64() / old.as_secs_f64() - 1.0) * 100.0;
+                    format!("{change:+.1}%")
+                }
+                _ => "-".to_string(),
+            };
+            [name.clone(), format(old_median), format(new_median), change]
+        })
+        .collect::<Vec<_>>();
+
+    let mut widths = HEADERS.map(str::len);
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+    for row in std::iter::once(HEADERS.map(String::from)).chain(rows) {
+        let line = row
+            .iter()
+            .zip(widths)
+            .map(|(cell, width)| format!("{cell:<width$}"))
+            .collect::<Vec<_>>()
+            .join("  ");
+        writeln!(text, "{}", line.trim_end()).unwrap();
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const OLD: &str = "\
+# cpu: AMD Ryzen 9 5900HX
+# rustc: rustc 1.74.0
+day_01_bench     fastest   │ slowest   │ median    │ mean      │ samples │ iters
+├─ part1         88.59 µs  │ 219.7 µs  │ 89.69 µs  │ 96.6 µs   │ 100     │ 100
+╰─ part2         398.4 µs  │ 473.4 µs  │ 400 µs    │ 408.2 µs  │ 100     │ 100
+# cpu: AMD Ryzen 9 5900HX
+# rustc: rustc 1.74.0
+
+day_01_bench     fastest   │ slowest   │ median    │ mean      │ samples │ iters
+├─ part1         88.59 µs  │ 219.7 µs  │ 90 µs     │ 96.6 µs   │ 100     │ 100
+├─ spin                    │           │           │           │         │
+│  ╰─ 1000       1.531 m   │ 1.531 m   │ 1.531 m   │ 1.531 m   │ 1       │ 1
+╰─ part2         398.4 µs  │ 473.4 µs  │ 400 µs    │ 408.2 µs  │ 100     │ 100
+";
+
+    #[test]
+    fn test_parse_last() {
+        let report = Report::parse_last(OLD);
+        assert_eq!(Some("rustc 1.74.0"), report.metadata.get("rustc"));
+        assert_eq!(
+            vec![
+                ("day_01_bench/part1".to_string(), Duration::from_micros(90)),
+                ("day_01_bench/spin/1000".to_string(), Duration::from_millis(91_860)),
+                ("day_01_bench/part2".to_string(), Duration::from_micros(400)),
+            ],
+            report.medians
+        );
+    }
+
+    #[test]
+    fn test_render_comparison() {
+        let old = Report::parse_last(OLD);
+        let new = Report::parse_last(
+            "# cpu: AMD Ryzen 9 5900HX\n# rustc: rustc 1.75.0\n\
+             day_01_bench   fastest │ slowest │ median   │ mean\n\
+             ╰─ part1       1 µs    │ 1 µs    │ 45 µs    │ 1 µs\n",
+        );
+        assert_eq!(
+            "machine unknown, build different, commit unknown\n\
+             \x20 rustc: rustc 1.74.0 -> rustc 1.75.0\n\
+             \n\
+             Benchmark               Old median  New median  Change\n\
+             day_01_bench/part1      90.00µs     45.00µs     -50.0%\n\
+             day_01_bench/spin/1000  91.86s      -           -\n\
+             day_01_bench/part2      400.00µs    -           -\n",
+            render_comparison(&old, &new)
+        );
+    }
+}
@@ -0,0 +1,41 @@
+use std::num::NonZeroUsize;
+use std::time::Duration;
+
+use aoc_runner::server::Limits;
+use clap::Parser;
+
+/// Serve the solvers over HTTP. See `aoc_runner::server` for the routes.
+#[derive(Parser, Debug)]
+#[command(name = "aoc-server")]
+struct Args {
+    /// Address to listen on
+    #[arg(long, default_value = "127.0.0.1:8023")]
+    addr: String,
+
+    /// Time budget per variant in seconds, 0 for none
+    #[arg(long, default_value = "30")]
+    timeout: u64,
+
+    /// Solvers running at once across all requests [default: available parallelism]
+    #[arg(long)]
+    max_solvers: Option<NonZeroUsize>,
+
+    /// Requests handled at once, the others queue up until a worker is free
+    #[arg(long, default_value = "16")]
+    workers: NonZeroUsize,
+}
+
+fn main() -> miette::Result<()> {
+    let args = Args::parse();
+    let server = tiny_http::Server::http(&args.addr)
+        .map_err(|error| miette::miette!("could not listen on {}: {error}", args.addr))?;
+    println!("listening on http://{}", server.server_addr());
+
+    let timeout = (args.timeout > 0).then(|| Duration::from_secs(args.timeout));
+    let max_solvers = args
+        .max_solvers
+        .unwrap_or_else(aoc_runner::batch::default_jobs);
+    let limits = Limits::new(timeout, max_solvers, args.workers);
+    aoc_runner::server::serve(&server, &limits);
+    Ok(())
+}
@@ -0,0 +1,160 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use miette::{Context, IntoDiagnostic};
+
+use crate::registry::Solver;
+
+include!(concat!(env!("OUT_DIR"), "/build_hashes.rs"));
+
+/// Hash of the day crate's sources at compile time. Changes whenever the solver code changes.
+pub fn build_hash(year: u16, day: u8) -> Option<u64> {
+    BUILD_HASHES
+        .iter()
+        .find(|(hash_year, hash_day, _)| (*hash_year, *hash_day) == (year, day))
+        .map(|(_, _, hash)| *hash)
+}
+
+/// Answers of previous runs, stored as one small file per
+/// (year, day, part, variant, input hash, build hash) key.
+///
+/// Each file holds the original solve time in nanoseconds on the first line and the answer after
+/// it.
+#[derive(Debug, Clone)]
+pub struct Cache {
+    dir: PathBuf,
+}
+
+impl Cache {
+    pub fn new(dir: impl Into<PathBuf>) -> Cache {
+        Cache { dir: dir.into() }
+    }
+
+    /// `.aoc-cache` in the workspace root
+    pub fn default_dir(root: &Path) -> PathBuf {
+        root.join(".aoc-cache")
+    }
+
+    fn entry_path(&self, solver: &Solver, input: &str) -> Option<PathBuf> {
+        let build_hash = build_hash(solver.year, solver.day)?;
+        let input_hash = seahash::hash(input.as_bytes());
+        let day_dir = self
+            .dir
+            .join(solver.year.to_string())
+            .join(format!("day-{:02}", solver.day));
+        Some(day_dir.join(format!(
+            "part{}-{}-{input_hash:016x}-{build_hash:016x}",
+            solver.part, solver.variant
+        )))
+    }
+
+    /// Cached answer and the time it originally took to compute
+    pub fn get(&self, solver: &Solver, input: &str) -> Option<(String, Duration)> {
+        let contents = fs::read_to_string(self.entry_path(solver, input)?).ok()?;
+        let (nanos, answer) = contents.split_once('\n')?;
+        let elapsed = Duration::from_nanos(nanos.parse().ok()?);
+        Some((answer.to_string(), elapsed))
+    }
+
+    pub fn put(
+        &self,
+        solver: &Solver,
+        input: &str,
+        answer: &str,
+        elapsed: Duration,
+    ) -> miette::Result<()> {
+        let Some(path) = self.entry_path(solver, input) else {
+            return Ok(());
+        };
+        let dir = path.parent().unwrap();
+        fs::create_dir_all(dir)
+            .into_diagnostic()
+            .wrap_err_with(|| format!("creating {}", dir.display()))?;
+
+        // Write then rename so parallel runs never read a half-written entry
+        let tmp_path = path.with_extension(format!("tmp{}", std::process::id()));
+        fs::write(&tmp_path, format!("{}\n{answer}", elapsed.as_nanos()))
+            .and_then(|_| fs::rename(&tmp_path, &path))
+            .into_diagnostic()
+            .wrap_err_with(|| format!("writing {}", path.display()))
+    }
+
+    /// Removes every entry and returns how many there were.
+    pub fn clear(&self) -> miette::Result<usize> {
+        let Ok(years) = fs::read_dir(&self.dir) else {
+            return Ok(0);
+        };
+        let mut removed = 0;
+        for year in years {
+            let year = year.into_diagnostic()?.path();
+            for day in fs::read_dir(&year)
+                .into_iter()
+                .flatten()
+                .filter_map(Result::ok)
+            {
+                removed += fs::read_dir(day.path()).map_or(0, |entries| entries.count());
+            }
+            fs::remove_dir_all(&year)
+                .into_diagnostic()
+                .wrap_err_with(|| format!("removing {}", year.display()))?;
+        }
+        Ok(removed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn solver(variant: &'static str) -> Solver {
+        Solver {
+            year: 2023,
+            day: 1,
+            part: 2,
+            variant,
+            cancellable: false,
+            params: &[],
+            solve: |_, _, _| Ok(String::new()),
+        }
+    }
+
+    fn temp_cache(name: &str) -> Cache {
+        let dir =
+            std::env::temp_dir().join(format!("aoc-cache-test-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        Cache::new(dir)
+    }
+
+    #[test]
+    fn test_every_day_has_a_build_hash() {
+        for day in 1..=19 {
+            assert!(build_hash(2023, day).is_some(), "day {day}");
+        }
+        assert_ne!(build_hash(2023, 1), build_hash(2023, 2));
+        assert_eq!(None, build_hash(2022, 1));
+    }
+
+    #[test]
+    fn test_round_trip() -> miette::Result<()> {
+        let cache = temp_cache("round-trip");
+        let (default, other) = (solver("default"), solver("other"));
+        let elapsed = Duration::from_micros(1234);
+
+        assert_eq!(None, cache.get(&default, "input"));
+        cache.put(&default, "input", "281", elapsed)?;
+        assert_eq!(
+            Some(("281".to_string(), elapsed)),
+            cache.get(&default, "input")
+        );
+
+        // Any change of key misses
+        assert_eq!(None, cache.get(&default, "other input"));
+        assert_eq!(None, cache.get(&other, "input"));
+
+        assert_eq!(1, cache.clear()?);
+        assert_eq!(None, cache.get(&default, "input"));
+        assert_eq!(0, cache.clear()?);
+        Ok(())
+    }
+}
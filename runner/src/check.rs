@@ -0,0 +1,90 @@
+//! `aoc check`: the shape every day declares for its input, also checked by `aoc run` before the
+//! day's variants run on it.
+
+use aoc_common::schema::InvalidInput;
+
+/// Type-erased `schema::check` function of a day crate.
+pub type CheckFn = fn(&str) -> Result<(), InvalidInput>;
+
+/// The input shape of one day, shared by both parts.
+#[derive(Clone, Copy)]
+pub struct Schema {
+    pub year: u16,
+    pub day: u8,
+    /// The expected input in a sentence
+    pub shape: &'static str,
+    pub check: CheckFn,
+}
+
+macro_rules! schema {
+    ($year:literal, $day:literal, $krate:ident) => {
+        Schema {
+            year: $year,
+            day: $day,
+            shape: $krate::schema::SHAPE,
+            check: $krate::schema::check,
+        }
+    };
+}
+
+/// Sorted by year and day
+pub static SCHEMAS: &[Schema] = &[
+    schema!(2023, 1, aoc2023_day_01),
+    schema!(2023, 2, aoc2023_day_02),
+    schema!(2023, 3, aoc2023_day_03),
+    schema!(2023, 4, aoc2023_day_04),
+    schema!(2023, 5, aoc2023_day_05),
+    schema!(2023, 6, aoc2023_day_06),
+    schema!(2023, 7, aoc2023_day_07),
+    schema!(2023, 8, aoc2023_day_08),
+    schema!(2023, 9, aoc2023_day_09),
+    schema!(2023, 10, aoc2023_day_10),
+    schema!(2023, 11, aoc2023_day_11),
+    schema!(2023, 12, aoc2023_day_12),
+    schema!(2023, 13, aoc2023_day_13),
+    schema!(2023, 14, aoc2023_day_14),
+    schema!(2023, 15, aoc2023_day_15),
+    schema!(2023, 16, aoc2023_day_16),
+    schema!(2023, 17, aoc2023_day_17),
+    schema!(2023, 18, aoc2023_day_18),
+    schema!(2023, 19, aoc2023_day_19),
+];
+
+pub fn find(year: u16, day: u8) -> Option<&'static Schema> {
+    SCHEMAS
+        .iter()
+        .find(|schema| schema.year == year && schema.day == day)
+}
+
+/// Checks `input` against the shape of `day`. Days without a schema accept any input.
+pub fn check(year: u16, day: u8, input: &str) -> Result<(), InvalidInput> {
+    find(year, day).map_or(Ok(()), |schema| (schema.check)(input))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::registry::SOLVERS;
+
+    #[test]
+    fn test_every_day_has_a_schema() {
+        for pair in SCHEMAS.windows(2) {
+            assert!((pair[0].year, pair[0].day) < (pair[1].year, pair[1].day));
+        }
+        for solver in SOLVERS {
+            assert!(
+                find(solver.year, solver.day).is_some(),
+                "{solver} has no schema"
+            );
+        }
+    }
+
+    #[test]
+    fn test_check() {
+        assert!(check(2023, 18, "R 6 (#70c710)\n").is_ok());
+        let invalid = check(2023, 18, "R 100 (#70c710)\n").unwrap_err();
+        assert_eq!(1, invalid.violations().len());
+        assert!(check(2022, 18, "R 100 (#70c710)\n").is_ok());
+    }
+}
@@ -0,0 +1,265 @@
+use std::num::NonZeroUsize;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use aoc_common::isa::Isa;
+use aoc_common::params::parse_override;
+use aoc_runner::registry::DEFAULT_YEAR;
+use clap::{Args, Parser, Subcommand, ValueEnum};
+
+#[derive(Parser, Debug)]
+#[command(name = "aoc", about = "Run the Advent of Code solvers")]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Run one day's solvers, or every day with `--all`, against their inputs
+    Run(RunArgs),
+
+    /// Rerun a day's tests, lints and input whenever its sources or inputs change
+    Watch(WatchArgs),
+
+    /// Check that a day's input has the shape its solvers expect
+    Check {
+        /// Day to check, e.g. `5`, `05` or `day-05`
+        #[arg(value_parser = parse_day)]
+        day: u8,
+
+        /// Part whose input to check, e.g. `2` or `part2`. Checks both parts' inputs if omitted.
+        #[arg(value_parser = parse_part)]
+        part: Option<u8>,
+
+        /// Year of the puzzle
+        #[arg(long, default_value_t = DEFAULT_YEAR)]
+        year: u16,
+
+        /// Input file to check instead of the day's `input*.txt`
+        #[arg(long)]
+        input: Option<PathBuf>,
+    },
+
+    /// List the parameters each solver accepts with `aoc run --param`
+    Params {
+        /// Only list this day's solvers
+        #[arg(value_parser = parse_day)]
+        day: Option<u8>,
+
+        /// Year of `day`. Lists every year if neither is given.
+        #[arg(long)]
+        year: Option<u16>,
+    },
+
+    /// Manage the cache of previously computed answers
+    Cache {
+        #[command(subcommand)]
+        command: CacheCommand,
+    },
+
+    /// Record and compare benchmark reports
+    Bench {
+        #[command(subcommand)]
+        command: BenchCommand,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum CacheCommand {
+    /// Remove every cached answer
+    Clear,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum BenchCommand {
+    /// Print this machine, toolchain and commit as the `# key: value` header of a benchmark report
+    Header {
+        /// Cargo profile the benchmarks are built with
+        #[arg(long, default_value = "bench")]
+        profile: String,
+    },
+
+    /// Compare the median times of the last runs in two benchmark reports
+    Compare { old: PathBuf, new: PathBuf },
+}
+
+#[derive(Args, Debug)]
+pub struct RunArgs {
+    /// Year of the puzzles. Defaults to the latest year, or every year with `--all`.
+    #[arg(long)]
+    pub year: Option<u16>,
+
+    /// Day to run, e.g. `5`, `05` or `day-05`
+    #[arg(value_parser = parse_day, required_unless_present = "all")]
+    pub day: Option<u8>,
+
+    /// Part to run, e.g. `2` or `part2`. Runs both parts if omitted.
+    #[arg(value_parser = parse_part)]
+    pub part: Option<u8>,
+
+    /// Only run this variant, e.g. `optimized`. Runs every variant if omitted.
+    #[arg(long)]
+    pub variant: Option<String>,
+
+    /// Input file to use instead of the day's `input*.txt`
+    #[arg(long)]
+    pub input: Option<PathBuf>,
+
+    /// Time budget per variant, e.g. `500ms`, `30s` or `2m`
+    #[arg(long, value_parser = parse_duration)]
+    pub timeout: Option<Duration>,
+
+    /// Override a puzzle constant, e.g. `spin_cycles=1000`. Only variants that declare every given
+    /// parameter are run. See `aoc params` for what's available.
+    #[arg(long = "param", value_name = "KEY=VALUE", value_parser = parse_override)]
+    pub params: Vec<(String, String)>,
+
+    /// Run every registered day, of every year unless `--year` is given, and print a summary table
+    #[arg(long, conflicts_with_all = ["day", "input"])]
+    pub all: bool,
+
+    /// Number of variants to run at once. Defaults to the number of CPUs with `--all`, 1 otherwise.
+    #[arg(long, short)]
+    pub jobs: Option<NonZeroUsize>,
+
+    /// Always run the solvers instead of serving answers cached by earlier runs
+    #[arg(long)]
+    pub no_cache: bool,
+
+    /// Run the lenient variants of the line-oriented days instead, which skip malformed lines and
+    /// report them along with the answer over the other lines
+    #[arg(long, conflicts_with_all = ["variant", "params"])]
+    pub lenient: bool,
+
+    /// Run the variants even if the input doesn't have the shape `aoc check` expects
+    #[arg(long)]
+    pub no_check: bool,
+
+    /// Don't show the progress of the long-running variants. It's only shown when they run one at
+    /// a time, without `--all`.
+    #[arg(long)]
+    pub no_progress: bool,
+
+    /// Run each variant this many times and report the median time
+    #[arg(long, default_value = "1")]
+    pub samples: NonZeroUsize,
+
+    /// Run the SIMD variants' build for this target level instead of the widest the CPU supports,
+    /// to time each build: `scalar`, `sse4.2`, `avx2` or `avx512`. Cached answers aren't served.
+    #[arg(long, value_name = "ISA")]
+    pub force_isa: Option<Isa>,
+
+    /// Print machine-readable tab-separated results
+    #[arg(long, hide = true)]
+    pub porcelain: bool,
+
+    /// Print the intermediate values behind the default solver's answer instead of running the
+    /// variants, e.g. the value of every line
+    #[arg(
+        long,
+        value_enum,
+        value_name = "FORMAT",
+        num_args = 0..=1,
+        default_missing_value = "text",
+        conflicts_with_all = ["all", "variant", "params", "porcelain", "lenient"]
+    )]
+    pub explain: Option<ExplainFormat>,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExplainFormat {
+    Text,
+    /// One object per part and line
+    Json,
+}
+
+#[derive(Args, Debug)]
+pub struct WatchArgs {
+    /// Year of the puzzle
+    #[arg(long, default_value_t = DEFAULT_YEAR)]
+    pub year: u16,
+
+    /// Day to watch, e.g. `5`, `05` or `day-05`
+    #[arg(value_parser = parse_day)]
+    pub day: u8,
+
+    /// Part to watch, e.g. `2` or `part2`
+    #[arg(value_parser = parse_part)]
+    pub part: u8,
+
+    /// Runs per variant; the median time is compared between changes
+    #[arg(long, default_value = "5")]
+    pub samples: NonZeroUsize,
+
+    /// Time budget per variant run, e.g. `500ms`, `30s` or `2m`
+    #[arg(long, value_parser = parse_duration)]
+    pub timeout: Option<Duration>,
+}
+
+pub fn parse_day(s: &str) -> Result<u8, String> {
+    let digits = s.strip_prefix("day-").unwrap_or(s);
+    match digits.parse::<u8>() {
+        Ok(day @ 1..=25) => Ok(day),
+        _ => Err(format!("`{s}` is not a day between 1 and 25")),
+    }
+}
+
+pub fn parse_part(s: &str) -> Result<u8, String> {
+    match s.strip_prefix("part").unwrap_or(s) {
+        "1" => Ok(1),
+        "2" => Ok(2),
+        _ => Err(format!("`{s}` is not part 1 or 2")),
+    }
+}
+
+/// Accepts `ms`, `s` and `m` suffixes. A bare number is in seconds.
+pub fn parse_duration(s: &str) -> Result<Duration, String> {
+    let (value, unit_seconds) = if let Some(ms) = s.strip_suffix("ms") {
+        (ms, 0.001)
+    } else if let Some(secs) = s.strip_suffix('s') {
+        (secs, 1.0)
+    } else if let Some(mins) = s.strip_suffix('m') {
+        (mins, 60.0)
+    } else {
+        (s, 1.0)
+    };
+    value
+        .parse::<f64>()
+        .ok()
+        .and_then(|value| Duration::try_from_secs_f64(value * unit_seconds).ok())
+        .ok_or_else(|| format!("`{s}` is not a duration like `500ms`, `30s` or `2m`"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("500ms", Duration::from_millis(500))]
+    #[case("30s", Duration::from_secs(30))]
+    #[case("1.5s", Duration::from_millis(1500))]
+    #[case("2m", Duration::from_secs(120))]
+    #[case("10", Duration::from_secs(10))]
+    fn test_duration(#[case] input: &str, #[case] expected: Duration) {
+        assert_eq!(Ok(expected), parse_duration(input));
+    }
+
+    #[rstest]
+    #[case("5", Ok(5))]
+    #[case("05", Ok(5))]
+    #[case("day-19", Ok(19))]
+    #[case("0", Err(()))]
+    #[case("day-26", Err(()))]
+    fn test_day(#[case] input: &str, #[case] expected: Result<u8, ()>) {
+        assert_eq!(expected, parse_day(input).map_err(|_| ()));
+    }
+
+    #[test]
+    fn test_invalid_duration() {
+        assert!(parse_duration("fast").is_err());
+        assert!(parse_duration("-1s").is_err());
+    }
+}
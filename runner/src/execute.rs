@@ -0,0 +1,341 @@
+use std::num::NonZeroUsize;
+use std::panic::AssertUnwindSafe;
+use std::sync::{mpsc, Arc, Condvar, Mutex};
+use std::time::{Duration, Instant};
+
+use aoc_common::cancel::CancelToken;
+use aoc_common::params::Params;
+use aoc_common::progress::Progress;
+
+use crate::registry::Solver;
+
+/// Some solvers recurse deeply, so don't rely on the 2 MiB default for spawned threads.
+const SOLVER_STACK_SIZE: usize = 64 * 1024 * 1024;
+
+#[derive(Debug)]
+pub enum Outcome {
+    /// `cached` answers were looked up instead of computed; `elapsed` is the original solve time.
+    Solved {
+        answer: String,
+        elapsed: Duration,
+        cached: bool,
+    },
+    Failed(miette::Report),
+    Panicked(String),
+    TimedOut(Duration),
+}
+
+impl Outcome {
+    pub fn is_success(&self) -> bool {
+        matches!(self, Outcome::Solved { .. })
+    }
+
+    /// Timeouts are reported but don't count as failures.
+    pub fn is_failure(&self) -> bool {
+        matches!(self, Outcome::Failed(_) | Outcome::Panicked(_))
+    }
+
+    pub fn elapsed(&self) -> Option<Duration> {
+        match self {
+            Outcome::Solved { elapsed, .. } => Some(*elapsed),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::Solved {
+                answer,
+                elapsed,
+                cached: false,
+            } => write!(f, "{answer} [{elapsed:.2?}]"),
+            Outcome::Solved {
+                answer,
+                elapsed,
+                cached: true,
+            } => write!(f, "{answer} [{elapsed:.2?}, cached]"),
+            Outcome::Failed(report) => write!(f, "error: {report}"),
+            Outcome::Panicked(message) => write!(f, "panicked: {message}"),
+            Outcome::TimedOut(budget) => write!(f, "timed out after {budget:.2?}"),
+        }
+    }
+}
+
+/// Caps how many solver threads run at once, across callers. A solver still running detached
+/// after a timeout keeps its slot until it finishes, so slow solvers can't pile up.
+#[derive(Debug, Clone)]
+pub struct Slots(Arc<SlotsState>);
+
+#[derive(Debug)]
+struct SlotsState {
+    free: Mutex<usize>,
+    freed: Condvar,
+}
+
+impl Slots {
+    pub fn new(count: NonZeroUsize) -> Slots {
+        Slots(Arc::new(SlotsState {
+            free: Mutex::new(count.get()),
+            freed: Condvar::new(),
+        }))
+    }
+
+    /// Waits for a free slot, held until the returned guard is dropped
+    fn take(&self) -> Slot {
+        let state = &self.0;
+        let free = state.free.lock().unwrap();
+        let mut free = state.freed.wait_while(free, |free| *free == 0).unwrap();
+        *free -= 1;
+        Slot(self.0.clone())
+    }
+}
+
+struct Slot(Arc<SlotsState>);
+
+impl Drop for Slot {
+    fn drop(&mut self) {
+        *self.0.free.lock().unwrap() += 1;
+        self.0.freed.notify_one();
+    }
+}
+
+/// Runs `solver` on its own thread with the given parameter values.
+///
+/// With a `timeout`, the solver's token is cancelled once the budget is spent and the call returns
+/// [`Outcome::TimedOut`] right away. Cancellable solvers then stop at their next poll; the others
+/// keep running detached until they finish or the process exits.
+pub fn run(
+    solver: &Solver,
+    input: Arc<str>,
+    params: &Params,
+    timeout: Option<Duration>,
+) -> Outcome {
+    run_with_progress(solver, input, params, timeout, None, None)
+}
+
+/// Same as [`run`], telling `progress` how far the solver got if it reports it. With `slots`,
+/// first waits for one to be free; the wait doesn't count towards the timeout.
+pub fn run_with_progress(
+    solver: &Solver,
+    input: Arc<str>,
+    params: &Params,
+    timeout: Option<Duration>,
+    progress: Option<Arc<dyn Progress>>,
+    slots: Option<&Slots>,
+) -> Outcome {
+    let slot = slots.map(Slots::take);
+    let mut token = timeout.map_or_else(CancelToken::new, CancelToken::with_timeout);
+    if let Some(progress) = progress {
+        token = token.with_progress(progress);
+    }
+    let (sender, receiver) = mpsc::channel();
+
+    let solve = solver.solve;
+    let solver_token = token.clone();
+    let params = params.clone();
+    let spawned = std::thread::Builder::new()
+        .name(solver.to_string())
+        .stack_size(SOLVER_STACK_SIZE)
+        .spawn(move || {
+            let start = Instant::now();
+            let result = std::panic::catch_unwind(AssertUnwindSafe(|| {
+                solve(&input, &solver_token, &params)
+            }));
+            let outcome = match result {
+                Ok(Ok(answer)) => Outcome::Solved {
+                    answer,
+                    elapsed: start.elapsed(),
+                    cached: false,
+                },
+                Ok(Err(report)) => Outcome::Failed(report),
+                Err(payload) => Outcome::Panicked(panic_message(payload.as_ref())),
+            };
+            // The receiver is gone if we already timed out
+            let _ = sender.send(outcome);
+            drop(slot);
+        });
+    if let Err(error) = spawned {
+        return Outcome::Failed(miette::miette!("could not spawn solver thread: {error}"));
+    }
+
+    match timeout {
+        None => receiver
+            .recv()
+            .unwrap_or_else(|_| Outcome::Panicked("solver thread vanished".to_string())),
+        Some(budget) => match receiver.recv_timeout(budget) {
+            // A cooperative solver may notice the deadline right before we do
+            Ok(Outcome::Failed(_)) if token.is_cancelled() => Outcome::TimedOut(budget),
+            Ok(outcome) => outcome,
+            Err(mpsc::RecvTimeoutError::Timeout) => {
+                token.cancel();
+                Outcome::TimedOut(budget)
+            }
+            Err(mpsc::RecvTimeoutError::Disconnected) => {
+                Outcome::Panicked("solver thread vanished".to_string())
+            }
+        },
+    }
+}
+
+/// Runs `solver` `samples` times and reports the median solve time. Stops at the first sample that
+/// doesn't produce an answer.
+pub fn run_sampled(
+    solver: &Solver,
+    input: Arc<str>,
+    params: &Params,
+    timeout: Option<Duration>,
+    samples: NonZeroUsize,
+    progress: Option<Arc<dyn Progress>>,
+    slots: Option<&Slots>,
+) -> Outcome {
+    let mut answer = None;
+    let mut times = Vec::with_capacity(samples.get());
+    for _ in 0..samples.get() {
+        let progress = progress.clone();
+        match run_with_progress(solver, input.clone(), params, timeout, progress, slots) {
+            Outcome::Solved {
+                answer: sample, elapsed, ..
+            } => {
+                if answer.as_ref().is_some_and(|answer| *answer != sample) {
+                    return Outcome::Failed(miette::miette!(
+                        "answer changed between samples: {} then {sample}",
+                        answer.unwrap()
+                    ));
+                }
+                answer = Some(sample);
+                times.push(elapsed);
+            }
+            outcome => return outcome,
+        }
+    }
+    times.sort_unstable();
+    Outcome::Solved {
+        answer: answer.unwrap(),
+        elapsed: times[times.len() / 2],
+        cached: false,
+    }
+}
+
+fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic payload".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    fn solver(solve: crate::registry::SolveFn) -> Solver {
+        Solver {
+            year: 2023,
+            day: 0,
+            part: 1,
+            variant: "test",
+            cancellable: true,
+            params: &[],
+            solve,
+        }
+    }
+
+    #[test]
+    fn test_solved() {
+        let solve = solver(|input, _, _| Ok(input.len().to_string()));
+        let outcome = run(&solve, "abc".into(), &Params::default(), None);
+        assert!(matches!(outcome, Outcome::Solved { answer, .. } if answer == "3"));
+    }
+
+    #[test]
+    fn test_failed_and_panicked() {
+        let params = Params::default();
+        let outcome = run(&solver(|_, _, _| miette::bail!("bad input")), "".into(), &params, None);
+        assert!(matches!(outcome, Outcome::Failed(_)));
+
+        let outcome = run(&solver(|_, _, _| panic!("boom")), "".into(), &params, None);
+        assert!(matches!(outcome, Outcome::Panicked(message) if message == "boom"));
+    }
+
+    #[test]
+    fn test_sampled() {
+        let (params, samples) = (Params::default(), NonZeroUsize::new(3).unwrap());
+        let answer = solver(|_, _, _| Ok("42".into()));
+        let outcome = run_sampled(&answer, "".into(), &params, None, samples, None, None);
+        assert!(matches!(outcome, Outcome::Solved { answer, .. } if answer == "42"));
+
+        let panics = solver(|_, _, _| panic!("boom"));
+        let outcome = run_sampled(&panics, "".into(), &params, None, samples, None, None);
+        assert!(matches!(outcome, Outcome::Panicked(_)));
+    }
+
+    #[test]
+    fn test_progress_reaches_the_solver() {
+        struct Sum(std::sync::atomic::AtomicU64);
+
+        impl Progress for Sum {
+            fn update(&self, done: u64, _total: u64) {
+                self.0.fetch_add(done, std::sync::atomic::Ordering::Relaxed);
+            }
+        }
+
+        let report: crate::registry::SolveFn = |_, token, _| {
+            token.progress(1, 2);
+            token.progress(2, 2);
+            Ok(String::new())
+        };
+        let sum = Arc::new(Sum(Default::default()));
+        let progress = Some(sum.clone() as Arc<dyn Progress>);
+        let params = Params::default();
+        run_with_progress(&solver(report), "".into(), &params, None, progress, None);
+        assert_eq!(3, sum.0.load(std::sync::atomic::Ordering::Relaxed));
+    }
+
+    #[test]
+    fn test_slots_outlast_a_timeout() {
+        static RELEASE: AtomicBool = AtomicBool::new(false);
+        let stuck: crate::registry::SolveFn = |_, _, _| {
+            while !RELEASE.load(Ordering::Relaxed) {
+                std::thread::sleep(Duration::from_millis(1));
+            }
+            Ok(String::new())
+        };
+        let (params, budget) = (Params::default(), Some(Duration::from_millis(20)));
+        let slots = Slots::new(NonZeroUsize::MIN);
+        let (stuck, held) = (solver(stuck), Some(&slots));
+        let outcome = run_with_progress(&stuck, "".into(), &params, budget, None, held);
+        assert!(matches!(outcome, Outcome::TimedOut(_)));
+
+        // The detached solver still holds the only slot
+        let waiting = {
+            let slots = slots.clone();
+            std::thread::spawn(move || {
+                let answer = solver(|_, _, _| Ok("next".into()));
+                let params = Params::default();
+                run_with_progress(&answer, "".into(), &params, None, None, Some(&slots))
+            })
+        };
+        std::thread::sleep(Duration::from_millis(50));
+        assert!(!waiting.is_finished());
+        RELEASE.store(true, Ordering::Relaxed);
+        let outcome = waiting.join().unwrap();
+        assert!(matches!(outcome, Outcome::Solved { answer, .. } if answer == "next"));
+    }
+
+    #[test]
+    fn test_timed_out() {
+        let spin: crate::registry::SolveFn = |_, token, _| loop {
+            token.check()?;
+            std::thread::sleep(Duration::from_millis(1));
+        };
+        let budget = Some(Duration::from_millis(20));
+        let outcome = run(&solver(spin), "".into(), &Params::default(), budget);
+        assert!(matches!(outcome, Outcome::TimedOut(budget) if budget == Duration::from_millis(20)));
+    }
+}
@@ -0,0 +1,180 @@
+//! `aoc run --explain`: the intermediate values of the days that can explain their answers, as
+//! text or JSON.
+
+use std::fmt::Write;
+
+use aoc_common::explain::{Explanation, Value};
+use serde::{Serialize, Serializer};
+
+/// Type-erased `explain` function of a day crate.
+pub type ExplainFn = fn(&str) -> miette::Result<Explanation>;
+
+/// Explains the default solver of one part.
+#[derive(Clone, Copy)]
+pub struct Explainer {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub explain: ExplainFn,
+}
+
+macro_rules! explainer {
+    ($year:literal, $day:literal, $part:literal, $explain:path) => {
+        Explainer {
+            year: $year,
+            day: $day,
+            part: $part,
+            explain: |input| Ok($explain(input)?),
+        }
+    };
+}
+
+/// Sorted by year, day and part
+pub static EXPLAINERS: &[Explainer] = &[
+    explainer!(2023, 1, 1, aoc2023_day_01::explain::part1),
+    explainer!(2023, 1, 2, aoc2023_day_01::explain::part2),
+    explainer!(2023, 2, 1, aoc2023_day_02::explain::part1),
+    explainer!(2023, 2, 2, aoc2023_day_02::explain::part2),
+    explainer!(2023, 4, 1, aoc2023_day_04::explain::part1),
+    explainer!(2023, 4, 2, aoc2023_day_04::explain::part2),
+    explainer!(2023, 9, 1, aoc2023_day_09::explain::part1),
+    explainer!(2023, 9, 2, aoc2023_day_09::explain::part2),
+    explainer!(2023, 10, 1, aoc2023_day_10::explain::found_loop),
+    explainer!(2023, 10, 2, aoc2023_day_10::explain::found_loop),
+    explainer!(2023, 12, 1, aoc2023_day_12::explain::part1),
+    explainer!(2023, 12, 2, aoc2023_day_12::explain::part2),
+    explainer!(2023, 14, 2, aoc2023_day_14::explain::part2),
+    explainer!(2023, 19, 2, aoc2023_day_19::explain::part2),
+];
+
+/// Explainers of `day`, or of only one of its parts
+pub fn select(year: u16, day: u8, part: Option<u8>) -> impl Iterator<Item = &'static Explainer> {
+    EXPLAINERS.iter().filter(move |explainer| {
+        explainer.year == year
+            && explainer.day == day
+            && (part.is_none() || part == Some(explainer.part))
+    })
+}
+
+/// Lists longer than this are cut short in the text form
+const MAX_LIST_LEN: usize = 10;
+
+/// One line per record, indented below a heading naming the part
+pub fn render(explainer: &Explainer, explanation: &Explanation) -> String {
+    let mut text = format!(
+        "{} day-{:02} part{}\n",
+        explainer.year, explainer.day, explainer.part
+    );
+    for record in &explanation.records {
+        writeln!(text, "  {record:.MAX_LIST_LEN$}").unwrap();
+    }
+    text
+}
+
+/// A single-line JSON object with the part and every record, fields keeping their order
+pub fn render_json(explainer: &Explainer, explanation: &Explanation) -> String {
+    let json = Json {
+        year: explainer.year,
+        day: explainer.day,
+        part: explainer.part,
+        records: explanation
+            .records
+            .iter()
+            .map(|record| JsonRecord {
+                label: &record.label,
+                fields: Fields(&record.fields),
+            })
+            .collect(),
+    };
+    serde_json::to_string(&json).expect("explanations always serialize")
+}
+
+#[derive(Serialize)]
+struct Json<'a> {
+    year: u16,
+    day: u8,
+    part: u8,
+    records: Vec<JsonRecord<'a>>,
+}
+
+#[derive(Serialize)]
+struct JsonRecord<'a> {
+    label: &'a str,
+    fields: Fields<'a>,
+}
+
+/// Serialized as an object in the order the fields were recorded
+struct Fields<'a>(&'a [(&'static str, Value)]);
+
+impl Serialize for Fields<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(self.0.iter().map(|(name, value)| (name, JsonValue(value))))
+    }
+}
+
+struct JsonValue<'a>(&'a Value);
+
+impl Serialize for JsonValue<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.0 {
+            Value::Int(int) => serializer.serialize_i128(*int),
+            Value::Bool(bool) => serializer.serialize_bool(*bool),
+            Value::Text(text) => serializer.serialize_str(text),
+            Value::List(values) => serializer.collect_seq(values.iter().map(JsonValue)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() -> miette::Result<()> {
+        let explainer = select(2023, 1, Some(2)).next().unwrap();
+        let explanation = (explainer.explain)("two1nine\n\"7\"")?;
+        assert_eq!(
+            "2023 day-01 part2\n\
+             \x20 line 1: text \"two1nine\", first 2, last 9, value 29\n\
+             \x20 line 2: text \"\\\"7\\\"\", first 7, last 7, value 77\n",
+            render(explainer, &explanation)
+        );
+        assert_eq!(
+            "{\"year\":2023,\"day\":1,\"part\":2,\"records\":[\
+             {\"label\":\"line 1\",\"fields\":\
+             {\"text\":\"two1nine\",\"first\":2,\"last\":9,\"value\":29}},\
+             {\"label\":\"line 2\",\"fields\":\
+             {\"text\":\"\\\"7\\\"\",\"first\":7,\"last\":7,\"value\":77}}]}",
+            render_json(explainer, &explanation)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_long_lists_are_cut_short() {
+        let mut explanation = Explanation::new();
+        explanation.record("loop").field("tiles", (0..25).collect::<Vec<_>>());
+        let text = render(&EXPLAINERS[0], &explanation);
+        assert!(text.ends_with("loop: tiles [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, ... 15 more]\n"));
+        assert!(render_json(&EXPLAINERS[0], &explanation).contains(",24]"));
+    }
+
+    #[test]
+    fn test_json_escapes() {
+        let mut explanation = Explanation::new();
+        explanation.record("line\t1").field("text", "a\u{1}\\b");
+        assert_eq!(
+            "{\"year\":2023,\"day\":1,\"part\":1,\"records\":[\
+             {\"label\":\"line\\t1\",\"fields\":{\"text\":\"a\\u0001\\\\b\"}}]}",
+            render_json(&EXPLAINERS[0], &explanation)
+        );
+    }
+
+    #[test]
+    fn test_select() {
+        assert_eq!(2, select(2023, 10, None).count());
+        assert_eq!(1, select(2023, 14, None).count());
+        assert_eq!(0, select(2023, 14, Some(1)).count());
+        assert_eq!(0, select(2023, 3, None).count());
+    }
+}
@@ -0,0 +1,31 @@
+use std::path::{Path, PathBuf};
+
+use miette::{Context, IntoDiagnostic};
+
+/// Workspace root, which contains a directory per year with its `day-NN` crates.
+pub fn workspace_root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("runner crate should live inside the workspace")
+}
+
+pub fn day_dir(root: &Path, year: u16, day: u8) -> PathBuf {
+    root.join(year.to_string()).join(format!("day-{day:02}"))
+}
+
+/// Input files are not committed. Days 1-7 of 2023 keep one file per part (`input1.txt`,
+/// `input2.txt`), later days share `input.txt`.
+pub fn default_input_path(root: &Path, year: u16, day: u8, part: u8) -> miette::Result<PathBuf> {
+    let dir = day_dir(root, year, day);
+    [format!("input{part}.txt"), "input.txt".to_string(), "input1.txt".to_string()]
+        .into_iter()
+        .map(|name| dir.join(name))
+        .find(|path| path.is_file())
+        .ok_or_else(|| miette::miette!("no input file found in {}", dir.display()))
+}
+
+pub fn read_input(path: &Path) -> miette::Result<String> {
+    std::fs::read_to_string(path)
+        .into_diagnostic()
+        .wrap_err_with(|| format!("reading {}", path.display()))
+}
@@ -0,0 +1,16 @@
+//! Library side of the `aoc` runner: the solver registry and the machinery to run solvers.
+
+pub mod batch;
+pub mod bench;
+pub mod cache;
+pub mod check;
+pub mod execute;
+pub mod explain;
+pub mod input;
+pub mod machine;
+pub mod progress;
+pub mod registry;
+#[cfg(feature = "server")]
+pub mod server;
+pub mod summary;
+pub mod watch;
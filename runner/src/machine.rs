@@ -0,0 +1,282 @@
+//! What a benchmark ran on and how it was built, written as `# key: value` lines at the top of
+//! every benchmark report so results from different machines or toolchains aren't mixed up.
+
+use std::fmt;
+use std::path::Path;
+use std::process::Command;
+
+/// Keys describing the hardware and OS
+const MACHINE_KEYS: [&str; 4] = ["cpu", "cores", "memory", "kernel"];
+/// Keys describing how the benchmarks were compiled
+const BUILD_KEYS: [&str; 4] = ["rustc", "profile", "rustflags", "target features"];
+
+const UNKNOWN: &str = "unknown";
+const DIRTY: &str = " (dirty)";
+
+/// Ordered `key: value` pairs. Reports written before these were recorded may only have some.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Metadata {
+    pub fields: Vec<(String, String)>,
+}
+
+impl Metadata {
+    /// Describes this machine, the toolchain cargo would use and the commit checked out in `root`.
+    /// `profile` is the cargo profile the benchmarks are built with. Anything that can't be found
+    /// out is `unknown` rather than an error, benchmarks are still worth keeping without it.
+    pub fn detect(root: &Path, profile: &str) -> Metadata {
+        let mut metadata = Metadata::default();
+        metadata.set("cpu", cpu_model());
+        metadata.set(
+            "cores",
+            std::thread::available_parallelism()
+                .map(|cores| cores.to_string())
+                .ok(),
+        );
+        metadata.set("memory", memory_bytes().map(format_gib));
+        metadata.set("kernel", command_output("uname", &["-sr"], root));
+        let rustc = std::env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string());
+        metadata.set("rustc", command_output(&rustc, &["-V"], root));
+        metadata.set("profile", Some(profile.to_string()));
+        let rustflags = std::env::var("RUSTFLAGS").unwrap_or_default();
+        let flags = if rustflags.is_empty() {
+            "none"
+        } else {
+            rustflags.as_str()
+        };
+        metadata.set("rustflags", Some(flags.to_string()));
+        metadata.set("target features", target_features(&rustc, &rustflags, root));
+        metadata.set("commit", commit(root));
+        metadata
+    }
+
+    /// Reads the `# key: value` lines at the start of `text`, stopping at the first other line
+    pub fn parse(text: &str) -> Metadata {
+        let fields = text
+            .lines()
+            .map_while(|line| line.strip_prefix("# ")?.split_once(": "))
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect();
+        Metadata { fields }
+    }
+
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.fields
+            .iter()
+            .find(|(field, _)| field == key)
+            .map(|(_, value)| value.as_str())
+    }
+
+    fn set(&mut self, key: &str, value: Option<String>) {
+        let value = value.unwrap_or_else(|| UNKNOWN.to_string());
+        self.fields.push((key.to_string(), value));
+    }
+
+    /// `(key, self's value, other's value)` of every key whose values differ, `unknown` where
+    /// only one side recorded the key
+    pub fn differences<'a>(&'a self, other: &'a Metadata) -> Vec<(&'a str, &'a str, &'a str)> {
+        let mut keys = self
+            .fields
+            .iter()
+            .map(|(key, _)| key.as_str())
+            .collect::<Vec<_>>();
+        for (key, _) in &other.fields {
+            if !keys.contains(&key.as_str()) {
+                keys.push(key);
+            }
+        }
+        keys.into_iter()
+            .filter_map(|key| {
+                let old = self.get(key).unwrap_or(UNKNOWN);
+                let new = other.get(key).unwrap_or(UNKNOWN);
+                (old != new).then_some((key, old, new))
+            })
+            .collect()
+    }
+
+    /// Whether the machine, the build and the commit are the same, different or unknown between
+    /// `self` and `other`, e.g. `machine same, build different, commit different`
+    pub fn comparison_label(&self, other: &Metadata) -> String {
+        [
+            ("machine", &MACHINE_KEYS[..]),
+            ("build", &BUILD_KEYS[..]),
+            ("commit", &["commit"][..]),
+        ]
+        .map(|(name, keys)| format!("{name} {}", self.compare(other, keys)))
+        .join(", ")
+    }
+
+    /// `different` if any of `keys` differs, otherwise `unknown` if any is missing or unknown on
+    /// either side. Uncommitted changes make a commit unknown too.
+    fn compare(&self, other: &Metadata, keys: &[&str]) -> &'static str {
+        let mut unknown = false;
+        for key in keys {
+            match (self.get(key), other.get(key)) {
+                (Some(old), Some(new)) if old != UNKNOWN && new != UNKNOWN => {
+                    if old.trim_end_matches(DIRTY) != new.trim_end_matches(DIRTY) {
+                        return "different";
+                    }
+                    unknown |= old.ends_with(DIRTY) || new.ends_with(DIRTY);
+                }
+                _ => unknown = true,
+            }
+        }
+        if unknown {
+            "unknown"
+        } else {
+            "same"
+        }
+    }
+}
+
+/// `# key: value` lines, one per field
+impl fmt::Display for Metadata {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (key, value) in &self.fields {
+            writeln!(f, "# {key}: {value}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Trimmed stdout of a successful command
+fn command_output(program: &str, args: &[&str], dir: &Path) -> Option<String> {
+    let output = Command::new(program)
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let stdout = String::from_utf8(output.stdout).ok()?;
+    Some(stdout.trim().to_string()).filter(|stdout| !stdout.is_empty())
+}
+
+fn cpu_model() -> Option<String> {
+    if let Ok(cpuinfo) = std::fs::read_to_string("/proc/cpuinfo") {
+        // `model name` on x86, `Model` on some ARM boards
+        return cpuinfo.lines().find_map(|line| {
+            let (key, value) = line.split_once(':')?;
+            matches!(key.trim(), "model name" | "Model").then(|| value.trim().to_string())
+        });
+    }
+    command_output(
+        "sysctl",
+        &["-n", "machdep.cpu.brand_string"],
+        Path::new("."),
+    )
+}
+
+fn memory_bytes() -> Option<u64> {
+    if let Ok(meminfo) = std::fs::read_to_string("/proc/meminfo") {
+        let kib = meminfo
+            .lines()
+            .find_map(|line| line.strip_prefix("MemTotal:"))?
+            .trim()
+            .strip_suffix("kB")?
+            .trim()
+            .parse::<u64>()
+            .ok()?;
+        return Some(kib * 1024);
+    }
+    command_output("sysctl", &["-n", "hw.memsize"], Path::new("."))?
+        .parse()
+        .ok()
+}
+
+fn format_gib(bytes: u64) -> String {
+    format!("{:.1} GiB", bytes as f64 / (1u64 << 30) as f64)
+}
+
+/// The target features rustc enables with `rustflags`, e.g. more of them with
+/// `-C target-cpu=native`
+fn target_features(rustc: &str, rustflags: &str, dir: &Path) -> Option<String> {
+    let mut args = vec!["--print", "cfg"];
+    args.extend(rustflags.split_whitespace());
+    let cfg = command_output(rustc, &args, dir)?;
+    let features = cfg
+        .lines()
+        .filter_map(|line| line.strip_prefix("target_feature=\""))
+        .map(|feature| feature.trim_end_matches('"'))
+        .collect::<Vec<_>>();
+    Some(features.join(","))
+}
+
+/// Short hash of `HEAD`, marked `dirty` when there are uncommitted changes
+fn commit(root: &Path) -> Option<String> {
+    let hash = command_output("git", &["rev-parse", "--short", "HEAD"], root)?;
+    let dirty = command_output("git", &["status", "--porcelain"], root).is_some();
+    Some(if dirty {
+        format!("{hash}{DIRTY}")
+    } else {
+        hash
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn metadata(fields: &[(&str, &str)]) -> Metadata {
+        Metadata {
+            fields: fields
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        let header = "# cpu: AMD Ryzen 9 5900HX\n# memory: 16.0 GiB\n\nday_01_bench  fastest";
+        let parsed = Metadata::parse(header);
+        assert_eq!(
+            metadata(&[("cpu", "AMD Ryzen 9 5900HX"), ("memory", "16.0 GiB")]),
+            parsed
+        );
+        assert_eq!(parsed, Metadata::parse(&parsed.to_string()));
+    }
+
+    #[test]
+    fn test_comparison_label() {
+        let laptop = metadata(&[
+            ("cpu", "AMD Ryzen 9 5900HX"),
+            ("cores", "16"),
+            ("memory", "16.0 GiB"),
+            ("kernel", "Linux 6.5.0"),
+            ("rustc", "rustc 1.74.0"),
+            ("profile", "bench"),
+            ("rustflags", "none"),
+            ("target features", "avx2"),
+            ("commit", "f04ab93"),
+        ]);
+        let mut newer_rustc = laptop.clone();
+        newer_rustc.fields[4].1 = "rustc 1.75.0".to_string();
+        newer_rustc.fields[8].1 = "3844297 (dirty)".to_string();
+        assert_eq!(
+            vec![
+                ("rustc", "rustc 1.74.0", "rustc 1.75.0"),
+                ("commit", "f04ab93", "3844297 (dirty)"),
+            ],
+            laptop.differences(&newer_rustc)
+        );
+        assert_eq!(
+            "machine same, build different, commit different",
+            laptop.comparison_label(&newer_rustc)
+        );
+
+        // Hand-written headers only name the machine
+        let old_report = metadata(&[("cpu", "AMD Ryzen 9 5900HX"), ("memory", "16.0 GiB")]);
+        assert_eq!(
+            "machine unknown, build unknown, commit unknown",
+            old_report.comparison_label(&laptop)
+        );
+        let mut desktop = laptop.clone();
+        desktop.fields[0].1 = "Intel Core i7-13700K".to_string();
+        desktop.fields[8].1 = "f04ab93 (dirty)".to_string();
+        assert_eq!(
+            "machine different, build same, commit unknown",
+            laptop.comparison_label(&desktop)
+        );
+    }
+}
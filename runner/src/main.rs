@@ -0,0 +1,236 @@
+mod cli;
+
+use std::path::PathBuf;
+
+use aoc_common::isa::Isa;
+use aoc_runner::bench::{self, Report};
+use aoc_runner::cache::Cache;
+use aoc_runner::execute::Outcome;
+use aoc_runner::machine::Metadata;
+use aoc_runner::registry::DEFAULT_YEAR;
+use aoc_runner::watch::{self, WatchOptions};
+use aoc_runner::{batch, check, explain, input, registry, summary};
+use clap::Parser;
+
+use crate::cli::{BenchCommand, CacheCommand, Cli, Command, ExplainFormat, RunArgs};
+
+fn main() -> miette::Result<()> {
+    match Cli::parse().command {
+        Command::Run(args) => run(args),
+        Command::Watch(args) => watch::watch(&WatchOptions {
+            year: args.year,
+            day: args.day,
+            part: args.part,
+            samples: args.samples.get(),
+            timeout: args.timeout,
+        }),
+        Command::Check {
+            day,
+            part,
+            year,
+            input,
+        } => check(year, day, part, input),
+        Command::Params { day, year } => {
+            print_params(year.or(day.map(|_| DEFAULT_YEAR)), day);
+            Ok(())
+        }
+        Command::Cache {
+            command: CacheCommand::Clear,
+        } => {
+            let cache = Cache::new(Cache::default_dir(input::workspace_root()));
+            println!("removed {} cached answer(s)", cache.clear()?);
+            Ok(())
+        }
+        Command::Bench {
+            command: BenchCommand::Header { profile },
+        } => {
+            print!("{}", Metadata::detect(input::workspace_root(), &profile));
+            Ok(())
+        }
+        Command::Bench {
+            command: BenchCommand::Compare { old, new },
+        } => {
+            let old = Report::parse_last(&input::read_input(&old)?);
+            let new = Report::parse_last(&input::read_input(&new)?);
+            print!("{}", bench::render_comparison(&old, &new));
+            Ok(())
+        }
+    }
+}
+
+fn run(args: RunArgs) -> miette::Result<()> {
+    if let Some(format) = args.explain {
+        return explain(&args, format);
+    }
+    let year = args.year.or((!args.all).then_some(DEFAULT_YEAR));
+    let solvers = if args.lenient {
+        registry::select_lenient(year, args.day, args.part).collect::<Vec<_>>()
+    } else {
+        registry::select(year, args.day, args.part, args.variant.as_deref()).collect::<Vec<_>>()
+    };
+    if solvers.is_empty() && args.lenient {
+        let mut days = registry::LENIENT_SOLVERS
+            .iter()
+            .map(|solver| format!("{} day-{:02}", solver.year, solver.day))
+            .collect::<Vec<_>>();
+        days.dedup();
+        miette::bail!(
+            help = format!("lenient variants are available for {}", days.join(", ")),
+            "no lenient variant for the given year, day and part"
+        );
+    }
+    if solvers.is_empty() {
+        miette::bail!("no solver registered for the given year, day, part and variant");
+    }
+    let solvers = solvers
+        .into_iter()
+        .filter(|solver| solver.declares_all(&args.params))
+        .collect::<Vec<_>>();
+    if solvers.is_empty() {
+        miette::bail!(
+            help = "`aoc params` lists the parameters of every solver",
+            "none of the selected solvers take all of the given parameters"
+        );
+    }
+
+    Isa::force(args.force_isa)?;
+
+    let jobs = batch::jobs_for(solvers, args.input.as_deref(), &args.params)?;
+    let check = !args.no_check && !args.lenient;
+    if check && !args.all && !args.porcelain {
+        // The summary table of the other modes lists the failed checks instead
+        check_inputs(&jobs)?;
+    }
+    // A cached answer wouldn't time the forced build
+    let cache = (!args.no_cache && args.force_isa.is_none())
+        .then(|| Cache::new(Cache::default_dir(input::workspace_root())));
+    let threads = args.jobs.unwrap_or(if args.all {
+        batch::default_jobs()
+    } else {
+        std::num::NonZeroUsize::MIN
+    });
+    let options = batch::Options {
+        threads,
+        timeout: args.timeout,
+        samples: args.samples,
+        cache: cache.as_ref(),
+        check,
+        // Bars of solvers running side by side would overwrite each other
+        progress: !args.no_progress && !args.all && !args.porcelain && threads.get() == 1,
+        slots: None,
+    };
+    let outcomes = if args.all || args.porcelain {
+        // Panic messages end up in the summary table instead
+        std::panic::set_hook(Box::new(|_| ()));
+        let outcomes = batch::run(jobs, options, |_, _| ());
+        if args.porcelain {
+            print!("{}", summary::render_porcelain(&outcomes));
+        } else {
+            print!("{}", summary::render(&outcomes));
+        }
+        outcomes
+    } else {
+        batch::run(jobs, options, |solver, outcome| {
+            if !solver.cancellable && matches!(outcome, Outcome::TimedOut(_)) {
+                println!("{solver}: {outcome} (not cancellable, left running)");
+            } else {
+                println!("{solver}: {outcome}");
+            }
+        })
+    };
+
+    if args.lenient {
+        // The skipped lines, labelled in the input
+        for (_, outcome) in &outcomes {
+            if let Outcome::Failed(report) = outcome {
+                eprintln!("{report:?}");
+            }
+        }
+    }
+
+    let failures = outcomes.iter().filter(|(_, outcome)| outcome.is_failure()).count();
+    if failures > 0 {
+        miette::bail!("{failures} variant(s) failed");
+    }
+    Ok(())
+}
+
+/// Reports the first input that doesn't have the shape of its day, before any variant runs
+fn check_inputs(jobs: &[batch::Job]) -> miette::Result<()> {
+    for job in jobs {
+        if let Ok(input) = &job.input {
+            check::check(job.solver.year, job.solver.day, input)?;
+        }
+    }
+    Ok(())
+}
+
+fn check(year: u16, day: u8, part: Option<u8>, input: Option<PathBuf>) -> miette::Result<()> {
+    let Some(schema) = check::find(year, day) else {
+        miette::bail!("no input shape declared for {year} day-{day:02}");
+    };
+    let paths = match input {
+        Some(path) => vec![path],
+        None => {
+            let root = input::workspace_root();
+            let mut paths = part
+                .map_or(vec![1, 2], |part| vec![part])
+                .into_iter()
+                .map(|part| input::default_input_path(root, year, day, part))
+                .collect::<miette::Result<Vec<_>>>()?;
+            // Later days share one input between both parts
+            paths.dedup();
+            paths
+        }
+    };
+    for path in paths {
+        (schema.check)(&input::read_input(&path)?).map_err(|invalid| {
+            miette::Report::new(invalid).wrap_err(format!("checking {}", path.display()))
+        })?;
+        println!("{}: ok", path.display());
+    }
+    Ok(())
+}
+
+fn explain(args: &RunArgs, format: ExplainFormat) -> miette::Result<()> {
+    let year = args.year.unwrap_or(DEFAULT_YEAR);
+    // `--explain` conflicts with `--all`, so a day is always given
+    let day = args.day.unwrap();
+    let explainers = explain::select(year, day, args.part).collect::<Vec<_>>();
+    if explainers.is_empty() {
+        let mut days = explain::EXPLAINERS
+            .iter()
+            .map(|explainer| format!("{} day-{:02}", explainer.year, explainer.day))
+            .collect::<Vec<_>>();
+        days.dedup();
+        miette::bail!(
+            help = format!("explanations are available for {}", days.join(", ")),
+            "no explanation for the given year, day and part"
+        );
+    }
+
+    for explainer in explainers {
+        let path = match &args.input {
+            Some(path) => path.clone(),
+            None => input::default_input_path(input::workspace_root(), year, day, explainer.part)?,
+        };
+        let explanation = (explainer.explain)(&input::read_input(&path)?)?;
+        match format {
+            ExplainFormat::Text => print!("{}", explain::render(explainer, &explanation)),
+            ExplainFormat::Json => println!("{}", explain::render_json(explainer, &explanation)),
+        }
+    }
+    Ok(())
+}
+
+fn print_params(year: Option<u16>, day: Option<u8>) {
+    for solver in registry::select(year, day, None, None) {
+        if solver.params.is_empty() {
+            continue;
+        }
+        println!("{solver}");
+        for spec in solver.params {
+            println!("  {}={}  {}", spec.name, spec.default, spec.help);
+        }
+    }
+}
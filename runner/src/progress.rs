@@ -0,0 +1,134 @@
+//! Shows the progress the long-running solvers report: a bar redrawn in place when stderr is a
+//! terminal, otherwise a log line every few seconds so CI logs show the run isn't stuck.
+
+use std::io::IsTerminal;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use aoc_common::progress::{Progress, UNKNOWN_TOTAL};
+
+use crate::registry::Solver;
+
+/// Time between redraws of the bar
+const BAR_INTERVAL: Duration = Duration::from_millis(100);
+/// Time between log lines. Also how long a solver runs before the first one, so the quick ones
+/// don't log anything.
+const LOG_INTERVAL: Duration = Duration::from_secs(5);
+const BAR_WIDTH: usize = 30;
+
+/// Reports one solver's progress on stderr
+pub struct Reporter {
+    label: String,
+    terminal: bool,
+    state: Mutex<State>,
+}
+
+struct State {
+    last_shown: Instant,
+    bar_drawn: bool,
+    finished: bool,
+}
+
+impl Reporter {
+    pub fn new(solver: &Solver) -> Reporter {
+        Reporter {
+            label: solver.to_string(),
+            terminal: std::io::stderr().is_terminal(),
+            state: Mutex::new(State {
+                last_shown: Instant::now(),
+                bar_drawn: false,
+                finished: false,
+            }),
+        }
+    }
+
+    /// Erases the bar so the outcome can be printed in its place. Updates from a solver still
+    /// running detached after a timeout are ignored from then on.
+    pub fn finish(&self) {
+        let mut state = self.state.lock().unwrap();
+        if state.bar_drawn {
+            eprint!("\r\x1b[2K");
+        }
+        state.finished = true;
+    }
+}
+
+impl Progress for Reporter {
+    fn update(&self, done: u64, total: u64) {
+        let mut state = self.state.lock().unwrap();
+        let interval = if self.terminal {
+            BAR_INTERVAL
+        } else {
+            LOG_INTERVAL
+        };
+        if state.finished || state.last_shown.elapsed() < interval {
+            return;
+        }
+        state.last_shown = Instant::now();
+        if self.terminal {
+            eprint!("\r\x1b[2K{}", render_bar(&self.label, done, total));
+            state.bar_drawn = true;
+        } else {
+            eprintln!("{}", render_line(&self.label, done, total));
+        }
+    }
+}
+
+/// `label [#####.....]  50.0% (5/10)`
+fn render_bar(label: &str, done: u64, total: u64) -> String {
+    let filled = (fraction(done, total) * BAR_WIDTH as f64) as usize;
+    format!(
+        "{label} [{}{}] {}",
+        "#".repeat(filled),
+        ".".repeat(BAR_WIDTH - filled),
+        render_count(done, total)
+    )
+}
+
+/// `label: 50.0% (5/10)`
+fn render_line(label: &str, done: u64, total: u64) -> String {
+    format!("{label}: {}", render_count(done, total))
+}
+
+/// `(5/?)` when the total is unknown
+fn render_count(done: u64, total: u64) -> String {
+    if total == UNKNOWN_TOTAL {
+        return format!("({done}/?)");
+    }
+    format!("{:5.1}% ({done}/{total})", fraction(done, total) * 100.0)
+}
+
+/// Clamped, solvers may report a little past the end. Nothing is filled when the total is unknown.
+fn fraction(done: u64, total: u64) -> f64 {
+    if total == UNKNOWN_TOTAL {
+        return 0.0;
+    }
+    (done as f64 / total as f64).min(1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        assert_eq!(
+            "day [###############...............]  50.0% (5/10)",
+            render_bar("day", 5, 10)
+        );
+        assert_eq!(
+            "day [..............................]   0.0% (0/3)",
+            render_bar("day", 0, 3)
+        );
+        assert_eq!(
+            "day [##############################] 100.0% (4/3)",
+            render_bar("day", 4, 3)
+        );
+        assert_eq!(
+            "day [..............................] (4/?)",
+            render_bar("day", 4, UNKNOWN_TOTAL)
+        );
+        assert_eq!("day:  12.5% (1/8)", render_line("day", 1, 8));
+        assert_eq!("day: (4/?)", render_line("day", 4, UNKNOWN_TOTAL));
+    }
+}
@@ -0,0 +1,675 @@
+use aoc_common::cancel::CancelToken;
+use aoc_common::params::{ParamSpec, Params};
+
+/// Type-erased entry point of one solver variant.
+pub type SolveFn = fn(&str, &CancelToken, &Params) -> miette::Result<String>;
+
+/// Year picked when none is given, e.g. by `aoc run 5`.
+pub const DEFAULT_YEAR: u16 = 2023;
+
+/// One `process*` function of a day crate.
+#[derive(Clone, Copy)]
+pub struct Solver {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    /// "default" for `partN::process`, otherwise the suffix of the function or module name
+    pub variant: &'static str,
+    /// Whether the solver polls the [`CancelToken`] it is given
+    pub cancellable: bool,
+    /// Puzzle constants that can be overridden with `--param`
+    pub params: &'static [ParamSpec],
+    pub solve: SolveFn,
+}
+
+impl Solver {
+    /// Whether every overridden parameter is one this solver declares
+    pub fn declares_all(&self, overrides: &[(String, String)]) -> bool {
+        overrides
+            .iter()
+            .all(|(name, _)| self.params.iter().any(|spec| spec.name == name))
+    }
+}
+
+impl std::fmt::Debug for Solver {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{self}")
+    }
+}
+
+impl std::fmt::Display for Solver {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} day-{:02} part{} ({})",
+            self.year, self.day, self.part, self.variant
+        )
+    }
+}
+
+macro_rules! solver {
+    ($year:literal, $day:literal, $part:literal, $variant:literal, $process:path) => {
+        Solver {
+            year: $year,
+            day: $day,
+            part: $part,
+            variant: $variant,
+            cancellable: false,
+            params: &[],
+            solve: |input, _token, _params| Ok($process(input)?),
+        }
+    };
+    ($year:literal, $day:literal, $part:literal, $variant:literal, $process:path, cancellable) => {
+        Solver {
+            year: $year,
+            day: $day,
+            part: $part,
+            variant: $variant,
+            cancellable: true,
+            params: &[],
+            solve: |input, token, _params| Ok($process(input, token)?),
+        }
+    };
+    (
+        $year:literal,
+        $day:literal,
+        $part:literal,
+        $variant:literal,
+        $process:path,
+        params = $specs:path
+    ) => {
+        Solver {
+            year: $year,
+            day: $day,
+            part: $part,
+            variant: $variant,
+            cancellable: false,
+            params: $specs,
+            solve: |input, _token, params| Ok($process(input, params)?),
+        }
+    };
+    (
+        $year:literal,
+        $day:literal,
+        $part:literal,
+        $variant:literal,
+        $process:path,
+        params = $specs:path,
+        cancellable
+    ) => {
+        Solver {
+            year: $year,
+            day: $day,
+            part: $part,
+            variant: $variant,
+            cancellable: true,
+            params: $specs,
+            solve: |input, token, params| Ok($process(input, params, token)?),
+        }
+    };
+}
+
+/// Every registered solver, sorted by year, day and part. The first variant of each part is the one
+/// the `partN` binary runs.
+pub static SOLVERS: &[Solver] = &[
+    solver!(2023, 1, 1, "default", aoc2023_day_01::part1::process),
+    solver!(2023, 1, 1, "simd", aoc2023_day_01::part1::process_simd),
+    solver!(2023, 1, 1, "stream", aoc2023_day_01::part1::process_stream),
+    #[cfg(feature = "parallel")]
+    solver!(
+        2023,
+        1,
+        1,
+        "parallel",
+        aoc2023_day_01::part1::process_parallel
+    ),
+    solver!(2023, 1, 2, "default", aoc2023_day_01::part2::process),
+    solver!(
+        2023,
+        1,
+        2,
+        "biscardi",
+        aoc2023_day_01::part2_biscardi::process
+    ),
+    solver!(
+        2023,
+        1,
+        2,
+        "aho-corasick",
+        aoc2023_day_01::part2_aho_corasick::process
+    ),
+    #[cfg(feature = "parallel")]
+    solver!(
+        2023,
+        1,
+        2,
+        "parallel",
+        aoc2023_day_01::part2::process_parallel
+    ),
+    solver!(
+        2023,
+        2,
+        1,
+        "default",
+        aoc2023_day_02::part1::process_with_params,
+        params = aoc2023_day_02::part1::PARAMS
+    ),
+    #[cfg(feature = "parallel")]
+    solver!(
+        2023,
+        2,
+        1,
+        "parallel",
+        aoc2023_day_02::part1::process_parallel_with_params,
+        params = aoc2023_day_02::part1::PARAMS
+    ),
+    solver!(2023, 2, 2, "default", aoc2023_day_02::part2::process),
+    #[cfg(feature = "parallel")]
+    solver!(
+        2023,
+        2,
+        2,
+        "parallel",
+        aoc2023_day_02::part2::process_parallel
+    ),
+    solver!(2023, 3, 1, "default", aoc2023_day_03::part1::process),
+    solver!(2023, 3, 2, "default", aoc2023_day_03::part2::process),
+    solver!(2023, 4, 1, "default", aoc2023_day_04::part1::process),
+    #[cfg(feature = "parallel")]
+    solver!(
+        2023,
+        4,
+        1,
+        "parallel",
+        aoc2023_day_04::part1::process_parallel
+    ),
+    solver!(2023, 4, 2, "default", aoc2023_day_04::part2::process),
+    #[cfg(feature = "parallel")]
+    solver!(
+        2023,
+        4,
+        2,
+        "parallel",
+        aoc2023_day_04::part2::process_parallel
+    ),
+    solver!(2023, 5, 1, "default", aoc2023_day_05::part1::process),
+    solver!(
+        2023,
+        5,
+        1,
+        "optimized",
+        aoc2023_day_05::part1::process_optimized
+    ),
+    solver!(
+        2023,
+        5,
+        2,
+        "default",
+        aoc2023_day_05::part2::process_cancellable,
+        cancellable
+    ),
+    solver!(
+        2023,
+        5,
+        2,
+        "optimized",
+        aoc2023_day_05::part2::process_optimized
+    ),
+    solver!(2023, 6, 1, "default", aoc2023_day_06::part1::process),
+    solver!(2023, 6, 2, "default", aoc2023_day_06::part2::process),
+    solver!(2023, 7, 1, "default", aoc2023_day_07::part1::process),
+    #[cfg(feature = "parallel")]
+    solver!(
+        2023,
+        7,
+        1,
+        "parallel",
+        aoc2023_day_07::part1::process_parallel
+    ),
+    solver!(2023, 7, 2, "default", aoc2023_day_07::part2::process),
+    #[cfg(feature = "parallel")]
+    solver!(
+        2023,
+        7,
+        2,
+        "parallel",
+        aoc2023_day_07::part2::process_parallel
+    ),
+    solver!(
+        2023,
+        8,
+        1,
+        "default",
+        aoc2023_day_08::part1::process_cancellable,
+        cancellable
+    ),
+    solver!(
+        2023,
+        8,
+        2,
+        "default",
+        aoc2023_day_08::part2::process_cancellable,
+        cancellable
+    ),
+    #[cfg(feature = "parallel")]
+    solver!(
+        2023,
+        8,
+        2,
+        "parallel",
+        aoc2023_day_08::part2::process_parallel_cancellable,
+        cancellable
+    ),
+    solver!(2023, 9, 1, "default", aoc2023_day_09::part1::process),
+    solver!(
+        2023,
+        9,
+        1,
+        "optimized",
+        aoc2023_day_09::part1::process_optimized
+    ),
+    solver!(
+        2023,
+        9,
+        1,
+        "optimized_dft",
+        aoc2023_day_09::part1::process_optimized_dft
+    ),
+    solver!(2023, 9, 1, "bc", aoc2023_day_09::part1::process_bc),
+    solver!(2023, 9, 1, "simd", aoc2023_day_09::part1::process_simd),
+    #[cfg(feature = "parallel")]
+    solver!(
+        2023,
+        9,
+        1,
+        "parallel",
+        aoc2023_day_09::part1::process_parallel
+    ),
+    solver!(2023, 9, 2, "default", aoc2023_day_09::part2::process),
+    solver!(2023, 9, 2, "dft", aoc2023_day_09::part2::process_dft),
+    solver!(2023, 9, 2, "bc", aoc2023_day_09::part2::process_bc),
+    solver!(2023, 9, 2, "simd", aoc2023_day_09::part2::process_simd),
+    #[cfg(feature = "parallel")]
+    solver!(
+        2023,
+        9,
+        2,
+        "parallel",
+        aoc2023_day_09::part2::process_parallel
+    ),
+    solver!(2023, 10, 1, "default", aoc2023_day_10::part1::process),
+    solver!(2023, 10, 1, "bits", aoc2023_day_10::part1::process_bits),
+    solver!(2023, 10, 2, "default", aoc2023_day_10::part2::process),
+    solver!(2023, 10, 2, "bits", aoc2023_day_10::part2::process_bits),
+    solver!(2023, 11, 1, "default", aoc2023_day_11::part1::process),
+    solver!(2023, 11, 1, "faster", aoc2023_day_11::part1::process_faster),
+    solver!(
+        2023,
+        11,
+        1,
+        "fastest",
+        aoc2023_day_11::part1::process_fastest
+    ),
+    #[cfg(feature = "parallel")]
+    solver!(
+        2023,
+        11,
+        1,
+        "parallel",
+        aoc2023_day_11::part1::process_parallel
+    ),
+    solver!(
+        2023,
+        11,
+        2,
+        "default",
+        aoc2023_day_11::part2::process_with_params,
+        params = aoc2023_day_11::part2::PARAMS
+    ),
+    solver!(
+        2023,
+        11,
+        2,
+        "fastest",
+        aoc2023_day_11::part2::process_fastest_with_params,
+        params = aoc2023_day_11::part2::PARAMS
+    ),
+    #[cfg(feature = "parallel")]
+    solver!(
+        2023,
+        11,
+        2,
+        "parallel",
+        aoc2023_day_11::part2::process_parallel_with_params,
+        params = aoc2023_day_11::part2::PARAMS
+    ),
+    solver!(2023, 12, 1, "default", aoc2023_day_12::part1::process),
+    solver!(
+        2023,
+        12,
+        1,
+        "naive",
+        aoc2023_day_12::part1::process_naive_cancellable,
+        cancellable
+    ),
+    #[cfg(feature = "parallel")]
+    solver!(
+        2023,
+        12,
+        1,
+        "parallel",
+        aoc2023_day_12::part1::process_parallel
+    ),
+    solver!(
+        2023,
+        12,
+        2,
+        "default",
+        aoc2023_day_12::part2::process_with_params,
+        params = aoc2023_day_12::part2::PARAMS
+    ),
+    #[cfg(feature = "parallel")]
+    solver!(
+        2023,
+        12,
+        2,
+        "parallel",
+        aoc2023_day_12::part2::process_parallel_with_params,
+        params = aoc2023_day_12::part2::PARAMS
+    ),
+    solver!(2023, 13, 1, "default", aoc2023_day_13::part1::process),
+    solver!(2023, 13, 1, "simd", aoc2023_day_13::part1::process_simd),
+    solver!(2023, 13, 2, "default", aoc2023_day_13::part2::process),
+    solver!(2023, 13, 2, "simd", aoc2023_day_13::part2::process_simd),
+    solver!(2023, 14, 1, "default", aoc2023_day_14::part1::process),
+    solver!(
+        2023,
+        14,
+        2,
+        "default",
+        aoc2023_day_14::part2::process_with_params,
+        params = aoc2023_day_14::part2::PARAMS,
+        cancellable
+    ),
+    solver!(2023, 15, 1, "default", aoc2023_day_15::part1::process),
+    solver!(2023, 15, 1, "simd", aoc2023_day_15::part1::process_simd),
+    solver!(2023, 15, 2, "default", aoc2023_day_15::part2::process),
+    solver!(2023, 16, 1, "default", aoc2023_day_16::part1::process),
+    solver!(2023, 16, 2, "default", aoc2023_day_16::part2::process),
+    #[cfg(feature = "parallel")]
+    solver!(
+        2023,
+        16,
+        2,
+        "parallel",
+        aoc2023_day_16::part2::process_parallel
+    ),
+    solver!(2023, 17, 1, "bucket", aoc2023_day_17::part1::process_bucket),
+    solver!(2023, 17, 1, "heap", aoc2023_day_17::part1::process_heap),
+    solver!(2023, 17, 2, "default", aoc2023_day_17::part2::process),
+    solver!(2023, 18, 1, "default", aoc2023_day_18::part1::process),
+    solver!(2023, 18, 1, "bytes", aoc2023_day_18::part1::process_bytes),
+    solver!(2023, 18, 2, "default", aoc2023_day_18::part2::process),
+    solver!(2023, 18, 2, "bytes", aoc2023_day_18::part2::process_bytes),
+    solver!(2023, 19, 1, "default", aoc2023_day_19::part1::process),
+    solver!(
+        2023,
+        19,
+        2,
+        "default",
+        aoc2023_day_19::part2::process_with_params,
+        params = aoc2023_day_19::part2::PARAMS
+    ),
+    solver!(
+        2023,
+        19,
+        2,
+        "dfs",
+        aoc2023_day_19::part2_dfs::process_with_params,
+        params = aoc2023_day_19::part2_dfs::PARAMS
+    ),
+];
+
+/// Variants of the line-oriented days that skip malformed lines instead of failing, run instead of
+/// [`SOLVERS`] by `aoc run --lenient`. When lines were skipped they fail with a report carrying the
+/// answer over the other lines and a related diagnostic per skipped line.
+pub static LENIENT_SOLVERS: &[Solver] = &[
+    solver!(
+        2023,
+        1,
+        1,
+        "lenient",
+        aoc2023_day_01::part1::process_lenient
+    ),
+    solver!(
+        2023,
+        1,
+        2,
+        "lenient",
+        aoc2023_day_01::part2::process_lenient
+    ),
+    solver!(
+        2023,
+        2,
+        1,
+        "lenient",
+        aoc2023_day_02::part1::process_lenient
+    ),
+    solver!(
+        2023,
+        2,
+        2,
+        "lenient",
+        aoc2023_day_02::part2::process_lenient
+    ),
+    solver!(
+        2023,
+        4,
+        1,
+        "lenient",
+        aoc2023_day_04::part1::process_lenient
+    ),
+    solver!(
+        2023,
+        4,
+        2,
+        "lenient",
+        aoc2023_day_04::part2::process_lenient
+    ),
+    solver!(
+        2023,
+        6,
+        1,
+        "lenient",
+        aoc2023_day_06::part1::process_lenient
+    ),
+    solver!(
+        2023,
+        6,
+        2,
+        "lenient",
+        aoc2023_day_06::part2::process_lenient
+    ),
+    solver!(
+        2023,
+        7,
+        1,
+        "lenient",
+        aoc2023_day_07::part1::process_lenient
+    ),
+    solver!(
+        2023,
+        7,
+        2,
+        "lenient",
+        aoc2023_day_07::part2::process_lenient
+    ),
+    solver!(
+        2023,
+        9,
+        1,
+        "lenient",
+        aoc2023_day_09::part1::process_lenient
+    ),
+    solver!(
+        2023,
+        9,
+        2,
+        "lenient",
+        aoc2023_day_09::part2::process_lenient
+    ),
+    solver!(
+        2023,
+        12,
+        1,
+        "lenient",
+        aoc2023_day_12::part1::process_lenient
+    ),
+    solver!(
+        2023,
+        12,
+        2,
+        "lenient",
+        aoc2023_day_12::part2::process_lenient
+    ),
+    solver!(
+        2023,
+        18,
+        1,
+        "lenient",
+        aoc2023_day_18::part1::process_lenient
+    ),
+    solver!(
+        2023,
+        18,
+        2,
+        "lenient",
+        aoc2023_day_18::part2::process_lenient
+    ),
+];
+
+/// Solvers matching the given filters. `None` matches everything.
+pub fn select(
+    year: Option<u16>,
+    day: Option<u8>,
+    part: Option<u8>,
+    variant: Option<&str>,
+) -> impl Iterator<Item = &'static Solver> + '_ {
+    filter(SOLVERS, year, day, part, variant)
+}
+
+/// The variant to run when none is asked for: the one named "default", or else the first one
+/// registered, e.g. for a day whose variants are all named after their approach
+pub fn default_variant(year: u16, day: u8, part: u8) -> Option<&'static Solver> {
+    let mut variants = select(Some(year), Some(day), Some(part), None).peekable();
+    let first = *variants.peek()?;
+    Some(
+        variants
+            .find(|solver| solver.variant == "default")
+            .unwrap_or(first),
+    )
+}
+
+/// Lenient solvers matching the given filters, see [`LENIENT_SOLVERS`]
+pub fn select_lenient(
+    year: Option<u16>,
+    day: Option<u8>,
+    part: Option<u8>,
+) -> impl Iterator<Item = &'static Solver> {
+    filter(LENIENT_SOLVERS, year, day, part, None)
+}
+
+fn filter<'a>(
+    solvers: &'static [Solver],
+    year: Option<u16>,
+    day: Option<u8>,
+    part: Option<u8>,
+    variant: Option<&'a str>,
+) -> impl Iterator<Item = &'static Solver> + 'a {
+    solvers.iter().filter(move |solver| {
+        (year.is_none() || year == Some(solver.year))
+            && (day.is_none() || day == Some(solver.day))
+            && (part.is_none() || part == Some(solver.part))
+            && (variant.is_none() || variant == Some(solver.variant))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registry_is_sorted_and_unique() {
+        for pair in SOLVERS.windows(2) {
+            assert!(
+                (pair[0].year, pair[0].day, pair[0].part)
+                    <= (pair[1].year, pair[1].day, pair[1].part),
+                "{pair:?} out of order"
+            );
+        }
+        let unique = SOLVERS
+            .iter()
+            .map(|solver| (solver.year, solver.day, solver.part, solver.variant))
+            .collect::<std::collections::HashSet<_>>();
+        assert_eq!(SOLVERS.len(), unique.len());
+    }
+
+    #[test]
+    fn test_lenient_solvers_have_a_default() {
+        for lenient in LENIENT_SOLVERS {
+            let default = select(
+                Some(lenient.year),
+                Some(lenient.day),
+                Some(lenient.part),
+                None,
+            );
+            assert!(default.count() > 0, "{lenient:?} has no default solver");
+        }
+        assert_eq!(2, select_lenient(Some(2023), Some(18), None).count());
+        assert_eq!(0, select_lenient(Some(2023), Some(19), None).count());
+    }
+
+    #[test]
+    fn test_select() {
+        assert_eq!(4, select(Some(2023), Some(5), None, None).count());
+        assert_eq!(2, select(Some(2023), Some(5), Some(2), None).count());
+        assert_eq!(
+            1,
+            select(Some(2023), Some(5), Some(2), Some("optimized")).count()
+        );
+        assert_eq!(0, select(Some(2023), Some(20), None, None).count());
+        assert_eq!(0, select(Some(2022), Some(5), None, None).count());
+        assert_eq!(4, select(None, Some(5), None, None).count());
+    }
+
+    #[test]
+    fn test_default_variant() {
+        let variant = |day, part| default_variant(2023, day, part).map(|solver| solver.variant);
+        assert_eq!(Some("default"), variant(5, 2));
+        // Its variants are `bucket` and `heap`
+        assert_eq!(Some("bucket"), variant(17, 1));
+        assert_eq!(None, variant(26, 1));
+    }
+
+    #[test]
+    fn test_declares_all() {
+        let overrides = |names: &[&str]| {
+            names
+                .iter()
+                .map(|name| (name.to_string(), "1".to_string()))
+                .collect::<Vec<_>>()
+        };
+        let day_2 = select(Some(2023), Some(2), Some(1), None).next().unwrap();
+        assert!(day_2.declares_all(&[]));
+        assert!(day_2.declares_all(&overrides(&["red", "blue"])));
+        assert!(!day_2.declares_all(&overrides(&["red", "unfold"])));
+
+        let takes_unfold = SOLVERS
+            .iter()
+            .filter(|solver| solver.declares_all(&overrides(&["unfold"])))
+            .count();
+        // Day 12 part 2, and its rayon variant
+        let expected = if cfg!(feature = "parallel") { 2 } else { 1 };
+        assert_eq!(expected, takes_unfold);
+    }
+}
@@ -0,0 +1,428 @@
+//! JSON over HTTP access to the solvers, for callers without a Rust toolchain.
+//!
+//! - `GET /days` lists the registered years, days, parts and variants.
+//! - `POST /{year}/days/{day}/parts/{part}?variant=…` runs a variant of the part on the request
+//!   body: the part's default one without `variant`, every one with `variant=all`. Without the
+//!   `/{year}` prefix the latest year is used.
+//!
+//! Requests are answered by a fixed pool of [`Limits::workers`] threads, and at most
+//! [`Limits::solvers`] solvers run at once across them. Anything more waits its turn.
+
+use std::io::Read;
+use std::num::NonZeroUsize;
+use std::sync::Arc;
+use std::time::Duration;
+
+use aoc_common::params::Params;
+use serde::Serialize;
+use tiny_http::{Header, Method, Request, Response, Server};
+
+use crate::batch::{self, Job};
+use crate::execute::{Outcome, Slots};
+use crate::registry::{self, DEFAULT_YEAR, SOLVERS};
+
+/// Real inputs are a few tens of KiB, anything much bigger is a mistake.
+const MAX_INPUT_BYTES: usize = 16 * 1024 * 1024;
+
+#[derive(Serialize, Debug, PartialEq, Eq)]
+pub struct PartListing {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub variants: Vec<&'static str>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct Solution {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub results: Vec<VariantResult>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct VariantResult {
+    pub variant: &'static str,
+    /// `ok`, `error`, `panicked` or `timed-out`
+    pub status: &'static str,
+    pub answer: Option<String>,
+    pub elapsed_ns: Option<u128>,
+    pub diagnostic: Option<Diagnostic>,
+}
+
+/// Why a variant didn't produce an answer.
+#[derive(Serialize, Debug)]
+pub struct Diagnostic {
+    pub message: String,
+    pub code: Option<String>,
+    pub help: Option<String>,
+}
+
+#[derive(Serialize)]
+struct ErrorBody {
+    error: String,
+}
+
+/// Status code and JSON body of a response.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Reply {
+    pub status: u16,
+    pub body: String,
+}
+
+impl Reply {
+    fn json(status: u16, body: &impl Serialize) -> Reply {
+        Reply {
+            status,
+            body: serde_json::to_string(body).expect("responses always serialize"),
+        }
+    }
+
+    fn error(status: u16, message: impl Into<String>) -> Reply {
+        Reply::json(
+            status,
+            &ErrorBody {
+                error: message.into(),
+            },
+        )
+    }
+}
+
+/// How much of the machine the requests may use
+#[derive(Debug, Clone)]
+pub struct Limits {
+    /// Time budget per variant
+    pub timeout: Option<Duration>,
+    /// Solvers allowed to run at once across requests, counting those still running after their
+    /// timeout
+    pub solvers: Slots,
+    /// Threads answering requests
+    pub workers: NonZeroUsize,
+}
+
+impl Limits {
+    pub fn new(timeout: Option<Duration>, solvers: NonZeroUsize, workers: NonZeroUsize) -> Limits {
+        Limits {
+            timeout,
+            solvers: Slots::new(solvers),
+            workers,
+        }
+    }
+}
+
+/// Answers requests on [`Limits::workers`] threads until the server is shut down.
+pub fn serve(server: &Server, limits: &Limits) {
+    std::thread::scope(|scope| {
+        for _ in 0..limits.workers.get() {
+            scope.spawn(|| {
+                for request in server.incoming_requests() {
+                    respond(request, limits);
+                }
+            });
+        }
+    });
+}
+
+fn respond(mut request: Request, limits: &Limits) {
+    let mut body = Vec::new();
+    let read = request
+        .as_reader()
+        .take(MAX_INPUT_BYTES as u64 + 1)
+        .read_to_end(&mut body);
+    let reply = match (read, String::from_utf8(body)) {
+        (Err(error), _) => Reply::error(400, format!("could not read the request body: {error}")),
+        (Ok(_), Err(_)) => Reply::error(400, "the input must be UTF-8"),
+        (Ok(length), _) if length > MAX_INPUT_BYTES => Reply::error(
+            413,
+            format!("inputs are limited to {MAX_INPUT_BYTES} bytes"),
+        ),
+        (Ok(_), Ok(input)) => handle(request.method(), request.url(), input, limits),
+    };
+    eprintln!("{} {} -> {}", request.method(), request.url(), reply.status);
+
+    let content_type = Header::from_bytes("Content-Type", "application/json").unwrap();
+    let response = Response::from_string(reply.body)
+        .with_status_code(reply.status)
+        .with_header(content_type);
+    if let Err(error) = request.respond(response) {
+        eprintln!("could not send the response: {error}");
+    }
+}
+
+/// Routes one request. Kept apart from the socket handling so it can be tested directly.
+pub fn handle(method: &Method, url: &str, input: String, limits: &Limits) -> Reply {
+    let (path, query) = url.split_once('?').unwrap_or((url, ""));
+    let mut segments = path.trim_matches('/').split('/').collect::<Vec<_>>();
+    let year = match segments[0].parse() {
+        Ok(year) if segments.len() > 1 => {
+            segments.remove(0);
+            year
+        }
+        _ => DEFAULT_YEAR,
+    };
+    match (method, &segments[..]) {
+        (Method::Get, ["days"]) => Reply::json(200, &list_days()),
+        (Method::Post, ["days", day, "parts", part]) => {
+            let (Ok(day), Ok(part)) = (day.parse(), part.parse()) else {
+                return Reply::error(404, format!("no such day or part: {path}"));
+            };
+            let variant = match query_value(query, "variant") {
+                Ok(variant) => variant,
+                Err(message) => return Reply::error(400, message),
+            };
+            solve(year, day, part, variant.as_deref(), input, limits)
+        }
+        (_, ["days"] | ["days", _, "parts", _]) => {
+            Reply::error(405, format!("{method} is not supported for {path}"))
+        }
+        _ => Reply::error(404, format!("no such resource: {path}")),
+    }
+}
+
+/// Value of the first `key` in a query like `a=1&b=2`, percent-decoded
+fn query_value(query: &str, key: &str) -> Result<Option<String>, String> {
+    for pair in query.split('&') {
+        let (name, value) = pair.split_once('=').unwrap_or((pair, ""));
+        if percent_decode(name)? == key {
+            return percent_decode(value).map(Some);
+        }
+    }
+    Ok(None)
+}
+
+/// `text` with each `%XX` replaced by the byte it stands for, and `+` by a space
+fn percent_decode(text: &str) -> Result<String, String> {
+    let invalid = || format!("invalid percent-encoding in the query: `{text}`");
+    let mut bytes = Vec::with_capacity(text.len());
+    let mut rest = text.as_bytes();
+    while let Some((&byte, tail)) = rest.split_first() {
+        rest = tail;
+        bytes.push(match byte {
+            b'%' => {
+                let hex = rest.get(..2).ok_or_else(invalid)?;
+                rest = &rest[2..];
+                let hex = std::str::from_utf8(hex).map_err(|_| invalid())?;
+                u8::from_str_radix(hex, 16).map_err(|_| invalid())?
+            }
+            b'+' => b' ',
+            byte => byte,
+        });
+    }
+    String::from_utf8(bytes).map_err(|_| invalid())
+}
+
+pub fn list_days() -> Vec<PartListing> {
+    let mut listings: Vec<PartListing> = vec![];
+    for solver in SOLVERS {
+        match listings.last_mut() {
+            Some(last)
+                if (last.year, last.day, last.part) == (solver.year, solver.day, solver.part) =>
+            {
+                last.variants.push(solver.variant);
+            }
+            _ => listings.push(PartListing {
+                year: solver.year,
+                day: solver.day,
+                part: solver.part,
+                variants: vec![solver.variant],
+            }),
+        }
+    }
+    listings
+}
+
+fn solve(
+    year: u16,
+    day: u8,
+    part: u8,
+    variant: Option<&str>,
+    input: String,
+    limits: &Limits,
+) -> Reply {
+    let solvers: Vec<_> = match variant {
+        None => registry::default_variant(year, day, part)
+            .into_iter()
+            .collect(),
+        Some("all") => registry::select(Some(year), Some(day), Some(part), None).collect(),
+        Some(variant) => {
+            registry::select(Some(year), Some(day), Some(part), Some(variant)).collect()
+        }
+    };
+    if solvers.is_empty() {
+        let variant = variant.map_or(String::new(), |variant| format!(" variant `{variant}`"));
+        return Reply::error(
+            404,
+            format!("no solver for {year} day {day} part {part}{variant}"),
+        );
+    }
+
+    let input: Arc<str> = input.into();
+    let jobs = solvers
+        .iter()
+        .map(|solver| Job {
+            solver,
+            input: Ok(input.clone()),
+            params: Params::defaults(solver.params),
+        })
+        .collect();
+    let options = batch::Options {
+        threads: NonZeroUsize::new(solvers.len()).unwrap(),
+        timeout: limits.timeout,
+        check: true,
+        slots: Some(&limits.solvers),
+        ..batch::Options::default()
+    };
+    let results = batch::run(jobs, options, |_, _| ())
+        .into_iter()
+        .map(|(solver, outcome)| variant_result(solver.variant, outcome))
+        .collect();
+    Reply::json(
+        200,
+        &Solution {
+            year,
+            day,
+            part,
+            results,
+        },
+    )
+}
+
+fn variant_result(variant: &'static str, outcome: Outcome) -> VariantResult {
+    let failed = |status, diagnostic| VariantResult {
+        variant,
+        status,
+        answer: None,
+        elapsed_ns: None,
+        diagnostic: Some(diagnostic),
+    };
+    let message = |message: String| Diagnostic {
+        message,
+        code: None,
+        help: None,
+    };
+    match outcome {
+        Outcome::Solved {
+            answer, elapsed, ..
+        } => VariantResult {
+            variant,
+            status: "ok",
+            answer: Some(answer),
+            elapsed_ns: Some(elapsed.as_nanos()),
+            diagnostic: None,
+        },
+        Outcome::Failed(report) => failed(
+            "error",
+            Diagnostic {
+                message: report
+                    .chain()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(": "),
+                code: report.code().map(|code| code.to_string()),
+                help: report.help().map(|help| help.to_string()),
+            },
+        ),
+        Outcome::Panicked(panic) => failed("panicked", message(panic)),
+        Outcome::TimedOut(budget) => failed(
+            "timed-out",
+            message(format!("timed out after {budget:.2?}")),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use serde_json::{json, Value};
+
+    fn body(reply: &Reply) -> Value {
+        serde_json::from_str(&reply.body).unwrap()
+    }
+
+    fn post(url: &str, input: &str) -> Reply {
+        let limits = Limits::new(None, NonZeroUsize::new(4).unwrap(), NonZeroUsize::MIN);
+        handle(&Method::Post, url, input.to_string(), &limits)
+    }
+
+    #[test]
+    fn test_list_days() {
+        let days = list_days();
+        let day_5_part_2 = days
+            .iter()
+            .find(|listing| (listing.year, listing.day, listing.part) == (2023, 5, 2))
+            .unwrap();
+        // A day without rayon variants, so this holds with the `parallel` feature too
+        assert_eq!(vec!["default", "optimized"], day_5_part_2.variants);
+        let variants = days.iter().map(|part| part.variants.len()).sum::<usize>();
+        assert_eq!(SOLVERS.len(), variants);
+    }
+
+    #[test]
+    fn test_solve() {
+        let reply = post(
+            "/days/1/parts/1",
+            "1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet",
+        );
+        assert_eq!(200, reply.status);
+        let body = body(&reply);
+        // Only the default variant
+        assert_eq!(1, body["results"].as_array().unwrap().len());
+        assert_eq!(json!("default"), body["results"][0]["variant"]);
+        assert_eq!(json!("142"), body["results"][0]["answer"]);
+        assert!(body["results"][0]["elapsed_ns"].is_u64());
+    }
+
+    #[test]
+    fn test_solve_by_year() {
+        let reply = post("/2023/days/1/parts/1", "1abc2");
+        assert_eq!(200, reply.status);
+        assert_eq!(json!(2023), body(&reply)["year"]);
+        assert_eq!(json!("12"), body(&reply)["results"][0]["answer"]);
+    }
+
+    #[test]
+    fn test_variants() {
+        let variants = |reply: &Reply| {
+            body(reply)["results"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|result| result["variant"].as_str().unwrap().to_string())
+                .collect::<Vec<_>>()
+        };
+        // Day 17 part 1 has no variant named `default`
+        assert_eq!(vec!["bucket"], variants(&post("/days/17/parts/1", "1")));
+        let all = post("/days/5/parts/2?variant=all", "");
+        assert_eq!(vec!["default", "optimized"], variants(&all));
+        let encoded = post("/days/1/parts/2?variant=aho%2Dcorasick", "one");
+        assert_eq!(vec!["aho-corasick"], variants(&encoded));
+        assert_eq!(json!("11"), body(&encoded)["results"][0]["answer"]);
+    }
+
+    #[test]
+    fn test_percent_decode() {
+        assert_eq!(Ok("a-b c".to_string()), percent_decode("a%2db+c"));
+        assert_eq!(Ok("Î".to_string()), percent_decode("%C3%8E"));
+        assert!(percent_decode("%2").is_err());
+        assert!(percent_decode("%zz").is_err());
+        assert!(percent_decode("%FF").is_err());
+        assert_eq!(
+            Ok(Some("b".to_string())),
+            query_value("a=1&vari%61nt=b", "variant")
+        );
+        assert_eq!(Ok(None), query_value("", "variant"));
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(404, post("/days/1/parts/3", "").status);
+        assert_eq!(404, post("/days/1/parts/1?variant=nope", "").status);
+        assert_eq!(404, post("/2022/days/1/parts/1", "").status);
+        assert_eq!(400, post("/days/1/parts/1?variant=%", "").status);
+        let limits = Limits::new(None, NonZeroUsize::MIN, NonZeroUsize::MIN);
+        let reply = handle(&Method::Get, "/days/1/parts/1", String::new(), &limits);
+        assert_eq!(405, reply.status);
+        assert!(body(&reply)["error"].is_string());
+    }
+}
@@ -0,0 +1,173 @@
+use std::fmt::Write;
+use std::time::Duration;
+
+use crate::execute::Outcome;
+use crate::registry::Solver;
+
+const HEADERS: [&str; 7] = ["Year", "Day", "Part", "Variant", "Answer", "Time", "Status"];
+
+/// Renders the outcomes as a table sorted by year, day and part, with a total-time row. Failure
+/// details are listed below the table so the rows stay one line each.
+pub fn render(outcomes: &[(&Solver, Outcome)]) -> String {
+    let mut sorted = outcomes.iter().collect::<Vec<_>>();
+    sorted.sort_by_key(|(solver, _)| (solver.year, solver.day, solver.part));
+
+    let mut rows = sorted
+        .iter()
+        .map(|(solver, outcome)| {
+            let (answer, time, status) = match outcome {
+                Outcome::Solved {
+                    answer,
+                    elapsed,
+                    cached,
+                } => {
+                    let status = if *cached { "cached" } else { "ok" };
+                    (answer.clone(), format!("{elapsed:.2?}"), status.to_string())
+                }
+                Outcome::Failed(_) => ("-".to_string(), "-".to_string(), "error".to_string()),
+                Outcome::Panicked(_) => ("-".to_string(), "-".to_string(), "panicked".to_string()),
+                Outcome::TimedOut(budget) => (
+                    "-".to_string(),
+                    format!("> {budget:.2?}"),
+                    "timed out".to_string(),
+                ),
+            };
+            [
+                solver.year.to_string(),
+                format!("day-{:02}", solver.day),
+                format!("part{}", solver.part),
+                solver.variant.to_string(),
+                answer,
+                time,
+                status,
+            ]
+        })
+        .collect::<Vec<_>>();
+
+    let total = outcomes
+        .iter()
+        .filter_map(|(_, outcome)| outcome.elapsed())
+        .sum::<Duration>();
+    let failures = outcomes.iter().filter(|(_, outcome)| outcome.is_failure()).count();
+    rows.push([
+        "Total".to_string(),
+        String::new(),
+        String::new(),
+        String::new(),
+        String::new(),
+        format!("{total:.2?}"),
+        format!("{failures} failed"),
+    ]);
+
+    let mut widths = HEADERS.map(str::len);
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let mut table = String::new();
+    let write_row = |table: &mut String, cells: &[String]| {
+        let line = cells
+            .iter()
+            .zip(widths)
+            .map(|(cell, width)| format!("{cell:<width$}"))
+            .collect::<Vec<_>>()
+            .join("  ");
+        writeln!(table, "{}", line.trim_end()).unwrap();
+    };
+    write_row(&mut table, &HEADERS.map(String::from));
+    let (total_row, rows) = rows.split_last().unwrap();
+    for row in rows {
+        write_row(&mut table, row);
+    }
+    write_row(&mut table, &widths.map(|width| "-".repeat(width)));
+    write_row(&mut table, total_row);
+
+    for (solver, outcome) in sorted {
+        if !outcome.is_success() {
+            writeln!(table, "\n{solver}: {outcome}").unwrap();
+        }
+    }
+    table
+}
+
+/// One tab-separated line per outcome: year, day, part, variant, status, answer and nanoseconds,
+/// with `-` for missing values. Read back by `aoc watch`.
+pub fn render_porcelain(outcomes: &[(&Solver, Outcome)]) -> String {
+    let mut lines = String::new();
+    for (solver, outcome) in outcomes {
+        let (status, answer, nanos) = match outcome {
+            Outcome::Solved {
+                answer,
+                elapsed,
+                cached,
+            } => {
+                let status = if *cached { "cached" } else { "ok" };
+                (status, answer.as_str(), elapsed.as_nanos().to_string())
+            }
+            Outcome::Failed(_) => ("error", "-", "-".to_string()),
+            Outcome::Panicked(_) => ("panicked", "-", "-".to_string()),
+            Outcome::TimedOut(_) => ("timed-out", "-", "-".to_string()),
+        };
+        writeln!(
+            lines,
+            "{}\t{}\t{}\t{}\t{status}\t{answer}\t{nanos}",
+            solver.year, solver.day, solver.part, solver.variant
+        )
+        .unwrap();
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn solver(day: u8, variant: &'static str) -> Solver {
+        Solver {
+            year: 2023,
+            day,
+            part: 1,
+            variant,
+            cancellable: false,
+            params: &[],
+            solve: |_, _, _| Ok(String::new()),
+        }
+    }
+
+    #[test]
+    fn test_render() {
+        let (day_1, day_2, day_3) = (solver(1, "default"), solver(2, "fast"), solver(3, "default"));
+        let outcomes = [
+            (&day_3, Outcome::TimedOut(Duration::from_secs(1))),
+            (
+                &day_1,
+                Outcome::Solved {
+                    answer: "142".to_string(),
+                    elapsed: Duration::from_millis(2),
+                    cached: false,
+                },
+            ),
+            (&day_2, Outcome::Panicked("boom".to_string())),
+        ];
+        let expected = "\
+Year   Day     Part   Variant  Answer  Time     Status
+2023   day-01  part1  default  142     2.00ms   ok
+2023   day-02  part1  fast     -       -        panicked
+2023   day-03  part1  default  -       > 1.00s  timed out
+-----  ------  -----  -------  ------  -------  ---------
+Total                                  2.00ms   1 failed
+
+2023 day-02 part1 (fast): panicked: boom
+
+2023 day-03 part1 (default): timed out after 1.00s
+";
+        assert_eq!(expected, render(&outcomes));
+
+        let expected = "2023\t3\t1\tdefault\ttimed-out\t-\t-\n\
+                        2023\t1\t1\tdefault\tok\t142\t2000000\n\
+                        2023\t2\t1\tfast\tpanicked\t-\t-\n";
+        assert_eq!(expected, render_porcelain(&outcomes));
+    }
+}
@@ -0,0 +1,299 @@
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+use std::time::{Duration, SystemTime};
+
+use miette::{Context, IntoDiagnostic};
+
+use crate::input;
+
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Editors often write a file in several steps, so wait this long before reacting to a change.
+const SETTLE_TIME: Duration = Duration::from_millis(200);
+
+#[derive(Debug, Clone)]
+pub struct WatchOptions {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub samples: usize,
+    pub timeout: Option<Duration>,
+}
+
+/// Reruns the example tests, clippy and the real input every time the day's sources or inputs
+/// change. Never returns unless `cargo` can't be started.
+///
+/// The solvers are run through a freshly built `aoc run` because this process still has the old
+/// code linked in.
+pub fn watch(options: &WatchOptions) -> miette::Result<()> {
+    let root = input::workspace_root();
+    let paths = watched_paths(root, options.year, options.day);
+    let package = format!("aoc{}-day-{:02}", options.year, options.day);
+    let part = format!("part{}", options.part);
+
+    let mut previous: Option<Vec<Sample>> = None;
+    let mut last_snapshot = snapshot(&paths);
+    loop {
+        println!("\n[{package} {part}] running");
+
+        let tests = cargo(root, &["test", "-q", "-p", &package, "--", &part])?;
+        println!(
+            "  tests:  {}",
+            if tests.status.success() {
+                "ok"
+            } else {
+                "FAILED"
+            }
+        );
+        if !tests.status.success() {
+            print!("{}", String::from_utf8_lossy(&tests.stdout));
+            eprint!("{}", String::from_utf8_lossy(&tests.stderr));
+        }
+
+        let lints = cargo(root, &["clippy", "-q", "-p", &package])?;
+        let warnings = String::from_utf8_lossy(&lints.stderr);
+        println!(
+            "  clippy: {}",
+            if warnings.trim().is_empty() {
+                "ok"
+            } else {
+                "warnings"
+            }
+        );
+        eprint!("{warnings}");
+
+        let mut run_args = vec![
+            "run".to_string(),
+            "--release".to_string(),
+            "-q".to_string(),
+            "-p".to_string(),
+            "aoc-runner".to_string(),
+            "--".to_string(),
+            "run".to_string(),
+            format!("--year={}", options.year),
+            options.day.to_string(),
+            options.part.to_string(),
+            "--no-cache".to_string(),
+            "--porcelain".to_string(),
+            format!("--samples={}", options.samples),
+        ];
+        if let Some(timeout) = options.timeout {
+            run_args.push(format!("--timeout={}ms", timeout.as_millis()));
+        }
+        let run = cargo(
+            root,
+            &run_args.iter().map(String::as_str).collect::<Vec<_>>(),
+        )?;
+        let samples = parse_porcelain(&String::from_utf8_lossy(&run.stdout));
+        if samples.is_empty() {
+            println!("  input:  no results");
+            eprint!("{}", String::from_utf8_lossy(&run.stderr));
+        } else {
+            println!("  input:");
+            for line in render_diff(previous.as_deref(), &samples).lines() {
+                println!("    {line}");
+            }
+            previous = Some(samples);
+        }
+
+        println!("[{package} {part}] watching for changes");
+        loop {
+            std::thread::sleep(POLL_INTERVAL);
+            let current = snapshot(&paths);
+            if current != last_snapshot {
+                std::thread::sleep(SETTLE_TIME);
+                last_snapshot = snapshot(&paths);
+                break;
+            }
+        }
+    }
+}
+
+fn cargo(root: &Path, args: &[&str]) -> miette::Result<Output> {
+    let cargo = std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+    Command::new(cargo)
+        .args(args)
+        .current_dir(root)
+        .output()
+        .into_diagnostic()
+        .wrap_err("running cargo")
+}
+
+/// One variant's result, as read back from `aoc run --porcelain`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sample {
+    pub variant: String,
+    pub status: String,
+    pub answer: Option<String>,
+    pub median: Option<Duration>,
+}
+
+pub fn parse_porcelain(output: &str) -> Vec<Sample> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let fields = line.split('\t').collect::<Vec<_>>();
+            let [_year, _day, _part, variant, status, answer, nanos] = fields[..] else {
+                return None;
+            };
+            Some(Sample {
+                variant: variant.to_string(),
+                status: status.to_string(),
+                answer: (answer != "-").then(|| answer.to_string()),
+                median: nanos.parse().ok().map(Duration::from_nanos),
+            })
+        })
+        .collect()
+}
+
+/// Compact before/after comparison of two runs, one line per variant. Without a previous run only
+/// the current values are shown.
+pub fn render_diff(before: Option<&[Sample]>, after: &[Sample]) -> String {
+    let before = before
+        .unwrap_or_default()
+        .iter()
+        .map(|sample| (sample.variant.as_str(), sample))
+        .collect::<HashMap<_, _>>();
+    let width = after
+        .iter()
+        .map(|sample| sample.variant.len())
+        .max()
+        .unwrap_or(0);
+
+    let mut diff = String::new();
+    for sample in after {
+        let value = |sample: &Sample| match &sample.answer {
+            Some(answer) => answer.clone(),
+            None => sample.status.clone(),
+        };
+        let time = |sample: &Sample| match sample.median {
+            Some(median) => format!("{median:.2?}"),
+            None => "-".to_string(),
+        };
+        write!(diff, "{:<width$}  ", sample.variant).unwrap();
+        match before.get(sample.variant.as_str()) {
+            None => write!(diff, "{}  {}", value(sample), time(sample)).unwrap(),
+            Some(previous) => {
+                let changed = if previous.answer != sample.answer {
+                    "  (changed)"
+                } else {
+                    ""
+                };
+                write!(diff, "{} -> {}{changed}", value(previous), value(sample)).unwrap();
+                write!(diff, "  {} -> {}", time(previous), time(sample)).unwrap();
+                if let (Some(old), Some(new)) = (previous.median, sample.median) {
+                    let percent = (new.as_secs_f64() / old.as_secs_f64() - 1.0) * 100.0;
+                    write!(diff, " ({percent:+.1}%)").unwrap();
+                }
+            }
+        }
+        diff.push('\n');
+    }
+    diff
+}
+
+/// The day crate's sources and inputs, plus the shared crate every day depends on.
+pub fn watched_paths(root: &Path, year: u16, day: u8) -> Vec<PathBuf> {
+    let day_dir = input::day_dir(root, year, day);
+    vec![
+        day_dir.join("src"),
+        day_dir.join("Cargo.toml"),
+        day_dir.join("input.txt"),
+        day_dir.join("input1.txt"),
+        day_dir.join("input2.txt"),
+        root.join("common/src"),
+    ]
+}
+
+/// Modification times of every file below `paths`. Two snapshots differ when a file was changed,
+/// added or removed.
+pub fn snapshot(paths: &[PathBuf]) -> Vec<(PathBuf, SystemTime)> {
+    fn visit(path: &Path, files: &mut Vec<(PathBuf, SystemTime)>) {
+        if path.is_dir() {
+            for entry in std::fs::read_dir(path).into_iter().flatten().flatten() {
+                visit(&entry.path(), files);
+            }
+        } else if let Ok(modified) = path.metadata().and_then(|metadata| metadata.modified()) {
+            files.push((path.to_path_buf(), modified));
+        }
+    }
+    let mut files = vec![];
+    for path in paths {
+        visit(path, &mut files);
+    }
+    files.sort();
+    files
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(variant: &str, answer: Option<&str>, micros: Option<u64>) -> Sample {
+        Sample {
+            variant: variant.to_string(),
+            status: if answer.is_some() { "ok" } else { "panicked" }.to_string(),
+            answer: answer.map(str::to_string),
+            median: micros.map(Duration::from_micros),
+        }
+    }
+
+    #[test]
+    fn test_parse_porcelain() {
+        let output = "2023\t5\t2\tdefault\ttimed-out\t-\t-\n\
+                      2023\t5\t2\toptimized\tok\t46\t17000\n";
+        let expected = vec![
+            Sample {
+                variant: "default".to_string(),
+                status: "timed-out".to_string(),
+                answer: None,
+                median: None,
+            },
+            sample("optimized", Some("46"), Some(17)),
+        ];
+        assert_eq!(expected, parse_porcelain(output));
+    }
+
+    #[test]
+    fn test_render_diff() {
+        let before = [
+            sample("default", Some("46"), Some(200)),
+            sample("optimized", Some("46"), Some(20)),
+        ];
+        let after = [
+            sample("default", None, None),
+            sample("optimized", Some("47"), Some(15)),
+            sample("new", Some("46"), Some(10)),
+        ];
+        let expected = "\
+default    panicked  -
+optimized  47  15.00µs
+new        46  10.00µs
+";
+        assert_eq!(expected, render_diff(None, &after));
+
+        let expected = "\
+default    46 -> panicked  (changed)  200.00µs -> -
+optimized  46 -> 47  (changed)  20.00µs -> 15.00µs (-25.0%)
+new        46  10.00µs
+";
+        assert_eq!(expected, render_diff(Some(&before), &after));
+    }
+
+    #[test]
+    fn test_snapshot_sees_changes() {
+        let dir = std::env::temp_dir().join(format!("aoc-watch-test-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let paths = [dir.clone()];
+
+        let empty = snapshot(&paths);
+        std::fs::write(dir.join("part1.rs"), "fn main() {}").unwrap();
+        let written = snapshot(&paths);
+        assert_ne!(empty, written);
+        assert_eq!(written, snapshot(&paths));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
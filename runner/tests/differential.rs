@@ -0,0 +1,80 @@
+//! Runs every variant of a day on the same inputs and compares them with the day's default
+//! variant: they should all answer the same, or all reject the input. Build with `--features
+//! parallel` to cover the rayon variants too, as `just test-parallel` and CI do.
+//!
+//! The inputs are the fuzz seeds, see `fuzz/seeds`, with their line endings varied.
+
+use std::path::Path;
+
+use aoc_common::cancel::CancelToken;
+use aoc_common::params::Params;
+use aoc_runner::check;
+use aoc_runner::registry::{self, Solver, DEFAULT_YEAR};
+
+/// Every seed of `day`, ending with and without a newline and with CRLF line endings
+fn inputs(day: u8) -> Vec<String> {
+    let seeds = Path::new(env!("CARGO_MANIFEST_DIR")).join(format!("../fuzz/seeds/day_{day:02}"));
+    let mut inputs = vec![];
+    for seed in std::fs::read_dir(&seeds).unwrap() {
+        let seed = std::fs::read_to_string(seed.unwrap().path()).unwrap();
+        let trimmed = seed.trim_end();
+        inputs.push(trimmed.to_string());
+        inputs.push(format!("{trimmed}\n"));
+        inputs.push(format!("{trimmed}\n").replace('\n', "\r\n"));
+    }
+    inputs
+}
+
+/// The answer of `solver`, `None` if it rejects the input
+fn answer(solver: &Solver, input: &str) -> Option<String> {
+    let params = Params::defaults(solver.params);
+    (solver.solve)(input, &CancelToken::new(), &params).ok()
+}
+
+#[test]
+fn test_variants_agree() {
+    let days = registry::select(Some(DEFAULT_YEAR), None, None, None).map(|solver| solver.day);
+    let mut days = days.collect::<Vec<_>>();
+    days.dedup();
+    for day in days {
+        for input in inputs(day) {
+            for part in [1, 2] {
+                let Some(default) = registry::default_variant(DEFAULT_YEAR, day, part) else {
+                    continue;
+                };
+                let expected = answer(default, &input);
+                for solver in registry::select(Some(DEFAULT_YEAR), Some(day), Some(part), None) {
+                    assert_eq!(
+                        expected,
+                        answer(solver, &input),
+                        "{solver} disagrees with {default} on {input:?}"
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_lenient_variants_agree_on_valid_input() {
+    let days = registry::select_lenient(Some(DEFAULT_YEAR), None, None).map(|solver| solver.day);
+    let mut days = days.collect::<Vec<_>>();
+    days.dedup();
+    for day in days {
+        let valid = inputs(day)
+            .into_iter()
+            .filter(|input| check::check(DEFAULT_YEAR, day, input).is_ok());
+        for input in valid {
+            for lenient in registry::select_lenient(Some(DEFAULT_YEAR), Some(day), None) {
+                let default = registry::default_variant(DEFAULT_YEAR, day, lenient.part).unwrap();
+                let expected = answer(default, &input);
+                assert!(expected.is_some(), "{default} rejects {input:?}");
+                assert_eq!(
+                    expected,
+                    answer(lenient, &input),
+                    "{lenient} disagrees with {default} on {input:?}"
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,81 @@
+//! Runs the HTTP server on a free localhost port and talks to it over a plain TCP socket.
+
+use std::io::{Read, Write};
+use std::net::{SocketAddr, TcpStream};
+use std::num::NonZeroUsize;
+
+use aoc_runner::server::Limits;
+use serde_json::Value;
+
+fn start_server() -> SocketAddr {
+    let server = tiny_http::Server::http("127.0.0.1:0").unwrap();
+    let addr = server.server_addr().to_ip().unwrap();
+    let two = NonZeroUsize::new(2).unwrap();
+    let limits = Limits::new(None, two, two);
+    std::thread::spawn(move || aoc_runner::server::serve(&server, &limits));
+    addr
+}
+
+/// Sends one request and returns the status code and the parsed JSON body.
+fn request(addr: SocketAddr, method: &str, path: &str, body: &str) -> (u16, Value) {
+    let mut stream = TcpStream::connect(addr).unwrap();
+    let head = format!("{method} {path} HTTP/1.1\r\nHost: {addr}\r\nConnection: close\r\n");
+    write!(stream, "{head}Content-Length: {}\r\n\r\n{body}", body.len()).unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+
+    let (head, body) = response.split_once("\r\n\r\n").unwrap();
+    let status = head.split(' ').nth(1).unwrap().parse().unwrap();
+    (status, serde_json::from_str(body).unwrap())
+}
+
+#[test]
+fn test_server() {
+    let addr = start_server();
+
+    let (status, days) = request(addr, "GET", "/days", "");
+    assert_eq!(200, status);
+    let day_5_part_2 = days
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|part| part["day"] == 5 && part["part"] == 2)
+        .unwrap();
+    assert_eq!(
+        serde_json::json!(["default", "optimized"]),
+        day_5_part_2["variants"]
+    );
+
+    let input = "\
+two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen";
+    let (status, solution) = request(addr, "POST", "/days/1/parts/2?variant=biscardi", input);
+    assert_eq!(200, status);
+    let results = solution["results"].as_array().unwrap();
+    assert_eq!(1, results.len());
+    assert_eq!("biscardi", results[0]["variant"]);
+    assert_eq!("ok", results[0]["status"]);
+    assert_eq!("281", results[0]["answer"]);
+
+    let (status, error) = request(addr, "POST", "/days/26/parts/1", input);
+    assert_eq!(404, status);
+    assert!(error["error"].is_string());
+}
+
+#[test]
+fn test_more_requests_than_workers() {
+    let addr = start_server();
+    let clients = (0..8)
+        .map(|_| std::thread::spawn(move || request(addr, "POST", "/days/1/parts/1", "1abc2")))
+        .collect::<Vec<_>>();
+    for client in clients {
+        let (status, solution) = client.join().unwrap();
+        assert_eq!(200, status);
+        assert_eq!("12", solution["results"][0]["answer"]);
+    }
+}
@@ -0,0 +1,40 @@
+#!/usr/bin/env bash
+# Moves the top-level `day-NN` crates into `YEAR/day-NN` and renames their packages to
+# `aocYEAR-day-NN`, so several years can share the workspace. Crates depending on them are updated
+# to the new paths and crate names. Untracked inputs move along with their day.
+#
+# Usage, from the workspace root: `scripts/migrate-year.sh 2023`, then `cargo test --workspace`.
+set -euo pipefail
+
+year=${1:?usage: scripts/migrate-year.sh YEAR}
+cd "$(dirname "$0")/.."
+
+days=(day-[0-9][0-9])
+if [[ ! -d ${days[0]} ]]; then
+    echo "no top-level day-NN crates to move" >&2
+    exit 1
+fi
+mkdir -p "$year"
+
+for day in "${days[@]}"; do
+    crate=${day//-/_}
+    package="aoc$year-$day"
+
+    mv "$day" "$year/$day"
+    sed -i "s/^name = \"$day\"$/name = \"$package\"/" "$year/$day/Cargo.toml"
+    grep -rlw "$crate" "$year/$day" --include='*.rs' | xargs -r sed -i "s/\b$crate\b/aoc${year}_$crate/g"
+
+    for manifest in */Cargo.toml; do
+        grep -q "path = \"../$day\"" "$manifest" || continue
+        sed -i \
+            -e "s|^$day = { path = \"../$day\"|$package = { path = \"../$year/$day\"|" \
+            -e "s|\"dep:$day\"|\"dep:$package\"|" \
+            "$manifest"
+        grep -rlw "$crate" "$(dirname "$manifest")" --include='*.rs' \
+            | xargs -r sed -i "s/\b$crate\b/aoc${year}_$crate/g"
+    done
+done
+
+# Pick up every year's days
+sed -i 's|"day-\*"|"20*/day-*"|' Cargo.toml
+echo "moved ${#days[@]} day(s) into $year/"
@@ -0,0 +1,346 @@
+//! Terminal viewer for the [`Frame`]s solvers produce, with stepping, playback and scrolling.
+//!
+//! Frames are pulled from the solver lazily, so endless animations such as day 14's spin cycles
+//! only compute as far as they're watched. The last [`HISTORY`] frames seen are kept to allow
+//! stepping back.
+
+use std::collections::VecDeque;
+use std::fmt::Write as _;
+use std::io::{self, Write};
+use std::time::{Duration, Instant};
+
+use aoc_common::frame::{Colour, Frame};
+use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use crossterm::{cursor, execute, terminal};
+
+const MAX_FPS: u32 = 480;
+/// Frames kept for stepping back, older ones are dropped so endless playback runs in bounded memory
+pub const HISTORY: usize = 1024;
+
+/// What the event loop should do after a key press.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Control {
+    Continue,
+    Quit,
+}
+
+/// Playback state over a lazily pulled sequence of frames.
+pub struct Viewer<F, I> {
+    /// The last [`HISTORY`] frames pulled from `source`
+    seen: VecDeque<F>,
+    /// Index of `seen`'s first frame in the whole sequence
+    first: usize,
+    source: std::iter::Fuse<I>,
+    current: usize,
+    playing: bool,
+    fps: u32,
+    /// Top-left cell of the grid shown in the top-left corner of the terminal
+    scroll: (usize, usize),
+}
+
+impl<F: Frame, I: Iterator<Item = F>> Viewer<F, I> {
+    pub fn new(frames: impl IntoIterator<IntoIter = I>, fps: u32) -> Viewer<F, I> {
+        let mut source = frames.into_iter().fuse();
+        Viewer {
+            seen: source.next().into_iter().collect(),
+            first: 0,
+            source,
+            current: 0,
+            playing: false,
+            fps: fps.clamp(1, MAX_FPS),
+            scroll: (0, 0),
+        }
+    }
+
+    pub fn frame(&self) -> Option<&F> {
+        self.seen.get(self.current - self.first)
+    }
+
+    pub fn index(&self) -> usize {
+        self.current
+    }
+
+    pub fn is_playing(&self) -> bool {
+        self.playing
+    }
+
+    /// Time between frames while playing
+    pub fn interval(&self) -> Duration {
+        Duration::from_secs(1) / self.fps
+    }
+
+    /// Moves to the next frame, pulling it from the solver if it wasn't seen yet. Returns false on
+    /// the last frame.
+    pub fn step_forward(&mut self) -> bool {
+        if self.current + 1 == self.first + self.seen.len() {
+            match self.source.next() {
+                Some(frame) => self.seen.push_back(frame),
+                None => return false,
+            }
+            if self.seen.len() > HISTORY {
+                self.seen.pop_front();
+                self.first += 1;
+            }
+        }
+        self.current += 1;
+        true
+    }
+
+    /// Moves to the previous frame, as far back as the history goes
+    pub fn step_back(&mut self) {
+        self.current = self.current.saturating_sub(1).max(self.first);
+    }
+
+    /// Scrolls by `dx` columns and `dy` rows, stopping once the edge of the grid is in view.
+    pub fn scroll_by(&mut self, dx: isize, dy: isize, viewport: (usize, usize)) {
+        let Some(frame) = self.frame() else {
+            return;
+        };
+        let max_x = frame.width().saturating_sub(viewport.0);
+        let max_y = frame.height().saturating_sub(viewport.1);
+        self.scroll.0 = self.scroll.0.saturating_add_signed(dx).min(max_x);
+        self.scroll.1 = self.scroll.1.saturating_add_signed(dy).min(max_y);
+    }
+
+    /// Applies a key press. `viewport` is the number of grid columns and rows on screen.
+    pub fn handle(&mut self, key: KeyCode, viewport: (usize, usize)) -> Control {
+        let page = viewport.1.max(1) as isize;
+        match key {
+            KeyCode::Char('q') | KeyCode::Esc => return Control::Quit,
+            KeyCode::Char(' ') => self.playing = !self.playing,
+            KeyCode::Char('n' | '.') => {
+                self.playing = false;
+                self.step_forward();
+            }
+            KeyCode::Char('p' | ',') => {
+                self.playing = false;
+                self.step_back();
+            }
+            KeyCode::Home => self.current = self.first,
+            KeyCode::Char('+' | '=') => self.fps = (self.fps * 2).min(MAX_FPS),
+            KeyCode::Char('-') => self.fps = (self.fps / 2).max(1),
+            KeyCode::Left => self.scroll_by(-1, 0, viewport),
+            KeyCode::Right => self.scroll_by(1, 0, viewport),
+            KeyCode::Up => self.scroll_by(0, -1, viewport),
+            KeyCode::Down => self.scroll_by(0, 1, viewport),
+            KeyCode::PageUp => self.scroll_by(0, -page, viewport),
+            KeyCode::PageDown => self.scroll_by(0, page, viewport),
+            _ => {}
+        }
+        Control::Continue
+    }
+
+    /// One line of frame number, caption, speed and key help
+    pub fn status(&self) -> String {
+        let total = if self.source.size_hint() == (0, Some(0)) {
+            format!("/{}", self.first + self.seen.len())
+        } else {
+            String::new()
+        };
+        let caption = self.frame().map(Frame::caption).unwrap_or_default();
+        let state = if self.playing { "playing" } else { "paused" };
+        format!(
+            "frame {}{total}  {caption}  |  {state} at {} fps  |  \
+             space play  n/p step  +/- speed  arrows scroll  q quit",
+            self.current + 1,
+            self.fps
+        )
+    }
+
+    /// The visible part of the current frame followed by the status line, as ANSI text
+    pub fn render(&self, viewport: (usize, usize)) -> String {
+        let mut screen = match self.frame() {
+            Some(frame) => render(frame, self.scroll, viewport),
+            None => "the solver produced no frames\r\n".to_string(),
+        };
+        let status = self.status().chars().take(viewport.0).collect::<String>();
+        write!(screen, "\x1b[J\x1b[7m{status}\x1b[0m").unwrap();
+        screen
+    }
+}
+
+fn sgr(colour: Colour) -> &'static str {
+    match colour {
+        Colour::Default => "\x1b[39m",
+        Colour::Dim => "\x1b[90m",
+        Colour::Red => "\x1b[31m",
+        Colour::Green => "\x1b[32m",
+        Colour::Yellow => "\x1b[33m",
+        Colour::Blue => "\x1b[34m",
+        Colour::Magenta => "\x1b[35m",
+        Colour::Cyan => "\x1b[36m",
+    }
+}
+
+/// Draws the `viewport` sized window of `frame` whose top-left cell is `scroll`, one line per grid
+/// row. Colour codes are only emitted when the colour changes.
+pub fn render(frame: &impl Frame, scroll: (usize, usize), viewport: (usize, usize)) -> String {
+    let columns = scroll.0..frame.width().min(scroll.0 + viewport.0);
+    let rows = scroll.1..frame.height().min(scroll.1 + viewport.1);
+
+    let mut screen = String::new();
+    for y in rows {
+        let mut colour = Colour::Default;
+        for x in columns.clone() {
+            let cell = frame.cell(x, y);
+            if cell.colour != colour {
+                screen.push_str(sgr(cell.colour));
+                colour = cell.colour;
+            }
+            screen.push(cell.glyph);
+        }
+        if colour != Colour::Default {
+            screen.push_str(sgr(Colour::Default));
+        }
+        screen.push_str("\x1b[K\r\n");
+    }
+    screen
+}
+
+/// Shows `frames` until the user quits, starting paused at the first frame.
+pub fn run<F: Frame>(frames: impl IntoIterator<Item = F>, fps: u32) -> io::Result<()> {
+    let mut viewer = Viewer::new(frames, fps);
+    let mut out = io::stdout();
+
+    terminal::enable_raw_mode()?;
+    let _restore = RestoreTerminal;
+    execute!(out, terminal::EnterAlternateScreen, cursor::Hide)?;
+    event_loop(&mut viewer, &mut out)
+}
+
+/// Leaves the alternate screen and raw mode when [`run`] returns, or unwinds because pulling a
+/// frame from the solver panicked
+struct RestoreTerminal;
+
+impl Drop for RestoreTerminal {
+    fn drop(&mut self) {
+        // Nothing left to report the errors to
+        execute!(io::stdout(), cursor::Show, terminal::LeaveAlternateScreen).ok();
+        terminal::disable_raw_mode().ok();
+    }
+}
+
+fn event_loop<F: Frame, I: Iterator<Item = F>>(
+    viewer: &mut Viewer<F, I>,
+    out: &mut impl Write,
+) -> io::Result<()> {
+    let mut next_frame = Instant::now();
+    loop {
+        let (columns, rows) = terminal::size()?;
+        // The last row is for the status line
+        let viewport = (columns as usize, rows.saturating_sub(1) as usize);
+        write!(out, "\x1b[H{}", viewer.render(viewport))?;
+        out.flush()?;
+
+        let timeout = if viewer.is_playing() {
+            next_frame.saturating_duration_since(Instant::now())
+        } else {
+            Duration::from_secs(60)
+        };
+        if event::poll(timeout)? {
+            // Anything else, e.g. a resize, just redraws
+            if let Event::Key(key) = event::read()? {
+                let was_playing = viewer.is_playing();
+                if key.kind != KeyEventKind::Release
+                    && viewer.handle(key.code, viewport) == Control::Quit
+                {
+                    return Ok(());
+                }
+                if !was_playing {
+                    next_frame = Instant::now() + viewer.interval();
+                }
+            }
+        } else if viewer.is_playing() {
+            if !viewer.step_forward() {
+                viewer.playing = false;
+            }
+            next_frame += viewer.interval();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use aoc_common::frame::{Cell, Grid};
+
+    fn frames(count: usize) -> impl Iterator<Item = Grid> {
+        (0..count).map(|index| {
+            let mut grid = Grid::from_text("....\n....\n....");
+            grid.set(index % 4, 0, Cell::new('#', Colour::Red));
+            grid.set_caption(format!("step {index}"));
+            grid
+        })
+    }
+
+    #[test]
+    fn test_render() {
+        let grid = frames(2).nth(1).unwrap();
+        assert_eq!(
+            ".\x1b[31m#\x1b[39m\x1b[K\r\n..\x1b[K\r\n",
+            render(&grid, (0, 0), (2, 2))
+        );
+        // Scrolled to the bottom-right, the window is cut off at the grid's edges
+        assert_eq!("..\x1b[K\r\n", render(&grid, (2, 2), (5, 5)));
+    }
+
+    #[test]
+    fn test_stepping() {
+        let mut viewer = Viewer::new(frames(3), 10);
+        assert_eq!("step 0", viewer.frame().unwrap().caption());
+        viewer.step_back();
+        assert_eq!(0, viewer.index());
+
+        assert!(viewer.step_forward());
+        assert!(viewer.step_forward());
+        assert!(!viewer.step_forward());
+        assert_eq!("step 2", viewer.frame().unwrap().caption());
+        assert!(viewer.status().starts_with("frame 3/3  step 2  |  paused at 10 fps"));
+
+        viewer.handle(KeyCode::Char('p'), (4, 3));
+        assert_eq!("step 1", viewer.frame().unwrap().caption());
+        viewer.handle(KeyCode::Home, (4, 3));
+        assert_eq!(0, viewer.index());
+    }
+
+    #[test]
+    fn test_history_is_bounded() {
+        let mut viewer = Viewer::new(frames(usize::MAX), 10);
+        for _ in 0..HISTORY + 5 {
+            assert!(viewer.step_forward());
+        }
+        assert_eq!(HISTORY, viewer.seen.len());
+        assert_eq!(HISTORY + 5, viewer.index());
+        let expected = format!("step {}", HISTORY + 5);
+        assert_eq!(expected, viewer.frame().unwrap().caption());
+
+        // Back to the oldest frame kept, and no further
+        viewer.handle(KeyCode::Home, (4, 3));
+        assert_eq!(6, viewer.index());
+        viewer.step_back();
+        assert_eq!("step 6", viewer.frame().unwrap().caption());
+    }
+
+    #[test]
+    fn test_controls() {
+        let mut viewer = Viewer::new(frames(usize::MAX), 10);
+        assert!(viewer.status().starts_with("frame 1  step 0"));
+
+        viewer.handle(KeyCode::Char(' '), (4, 3));
+        assert!(viewer.is_playing());
+        viewer.handle(KeyCode::Char('+'), (4, 3));
+        assert_eq!(Duration::from_millis(50), viewer.interval());
+        viewer.handle(KeyCode::Char('n'), (4, 3));
+        assert!(!viewer.is_playing());
+
+        // Scrolling stops once the last column and row are in view
+        viewer.handle(KeyCode::PageDown, (2, 2));
+        viewer.handle(KeyCode::PageDown, (2, 2));
+        for _ in 0..5 {
+            viewer.handle(KeyCode::Right, (2, 2));
+        }
+        assert_eq!((2, 1), viewer.scroll);
+        assert_eq!(Control::Quit, viewer.handle(KeyCode::Char('q'), (2, 2)));
+    }
+}
@@ -0,0 +1,67 @@
+use std::path::PathBuf;
+
+use aoc_runner::input;
+use aoc_runner::registry::DEFAULT_YEAR;
+use clap::Parser;
+use miette::IntoDiagnostic;
+
+/// Step through a solver's progress on a puzzle input in the terminal
+#[derive(Parser, Debug)]
+#[command(name = "aoc-viz")]
+struct Cli {
+    /// Day to show, e.g. `16` or `day-16`
+    #[arg(value_parser = parse_day)]
+    day: u8,
+
+    /// Part to show, e.g. `2` or `part2`
+    #[arg(value_parser = parse_part, default_value = "1")]
+    part: u8,
+
+    #[arg(long, default_value_t = DEFAULT_YEAR)]
+    year: u16,
+
+    /// Input file. Defaults to the day's input.
+    #[arg(long)]
+    input: Option<PathBuf>,
+
+    /// Playback speed in frames per second, changed with `+` and `-` while playing
+    #[arg(long, default_value_t = 10)]
+    fps: u32,
+}
+
+fn parse_day(s: &str) -> Result<u8, String> {
+    match s.strip_prefix("day-").unwrap_or(s).parse::<u8>() {
+        Ok(day @ 1..=25) => Ok(day),
+        _ => Err(format!("`{s}` is not a day between 1 and 25")),
+    }
+}
+
+fn parse_part(s: &str) -> Result<u8, String> {
+    match s.strip_prefix("part").unwrap_or(s) {
+        "1" => Ok(1),
+        "2" => Ok(2),
+        _ => Err(format!("`{s}` is not part 1 or 2")),
+    }
+}
+
+fn main() -> miette::Result<()> {
+    let cli = Cli::parse();
+    let path = match cli.input {
+        Some(path) => path,
+        None => input::default_input_path(input::workspace_root(), cli.year, cli.day, cli.part)?,
+    };
+    let input = input::read_input(&path)?;
+    let input = input.trim_end();
+
+    let (part, fps) = (cli.part, cli.fps);
+    match (cli.year, cli.day) {
+        (2023, 10) => aoc_viz::run(aoc2023_day_10::viz::frames(input, part), fps),
+        (2023, 14) => aoc_viz::run(aoc2023_day_14::viz::frames(input, part), fps),
+        (2023, 16) => aoc_viz::run(aoc2023_day_16::viz::frames(input, part), fps),
+        (2023, 17) => aoc_viz::run(aoc2023_day_17::viz::frames(input, part), fps),
+        (year, day) => miette::bail!(
+            "{year} day {day} has no visualization, try 2023 days 10, 14, 16 or 17"
+        ),
+    }
+    .into_diagnostic()
+}